@@ -0,0 +1,57 @@
+//! Compares the sequential and the parallel loader of [OptolithData]. The data
+//! directory is taken from the `OPTOLITH_DATA_DIR` environment variable or the
+//! first argument, e.g.
+//!
+//! `cargo bench --features parallel -- path/to/optolith-data/Data`
+
+use optolith_rs::data::OptolithData;
+use optolith_rs::error::OptolithDataResult;
+
+use std::env;
+use std::time::{Duration, Instant};
+
+const ITERATIONS: u32 = 10;
+
+fn data_dir() -> Option<String> {
+    env::var("OPTOLITH_DATA_DIR").ok()
+        .or_else(|| env::args().skip(1).find(|arg| !arg.starts_with("--")))
+}
+
+fn measure(name: &str, path: &str,
+        load: impl Fn(&str) -> OptolithDataResult<OptolithData>)
+        -> OptolithData {
+    let mut data = load(path).expect("data directory could not be loaded");
+    let mut total = Duration::default();
+
+    for _ in 0..ITERATIONS {
+        let start = Instant::now();
+        data = load(path).unwrap();
+        total += start.elapsed();
+    }
+
+    println!("{:<12} {:>10.2?} per load ({} iterations)", name,
+        total / ITERATIONS, ITERATIONS);
+    data
+}
+
+fn main() {
+    let path = match data_dir() {
+        Some(path) => path,
+        None => {
+            eprintln!("No data directory given, set OPTOLITH_DATA_DIR or \
+                pass it as an argument.");
+            return;
+        }
+    };
+
+    let sequential =
+        measure("sequential", &path, OptolithData::from_directory);
+    let parallel =
+        measure("parallel", &path, OptolithData::from_directory_parallel);
+
+    let sequential = serde_json::to_vec(&sequential).unwrap();
+    let parallel = serde_json::to_vec(&parallel).unwrap();
+
+    assert!(sequential == parallel,
+        "sequential and parallel loader produced different data");
+}
@@ -0,0 +1,192 @@
+//! A compact binary snapshot format for loaded data, which is considerably
+//! faster to read than the YAML data files or a JSON dump. Each snapshot
+//! starts with a header that records the version of this crate, a hash of the
+//! directory the data was loaded from and the time the snapshot was built.
+
+use crate::compatibility::Compatibility;
+use crate::data::OptolithData;
+use crate::error::{self, OptolithDataErrorKind, OptolithDataResult};
+use crate::util;
+
+use serde::{Deserialize, Serialize};
+use serde::de::DeserializeOwned;
+
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const MAGIC: &[u8; 8] = b"OPTOLITH";
+
+/// The version of the snapshot layout. It must be increased whenever the
+/// layout of the header changes.
+const FORMAT_VERSION: u32 = 1;
+
+const CRATE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// The header of a snapshot file.
+#[derive(Clone, Deserialize, Serialize)]
+pub struct CacheHeader {
+    format_version: u32,
+    crate_version: String,
+    content_hash: u64,
+    build_time: u64
+}
+
+impl CacheHeader {
+
+    /// The version of this crate that built the snapshot.
+    pub fn crate_version(&self) -> &str {
+        &self.crate_version
+    }
+
+    /// The hash of the data directory the snapshot was built from, as
+    /// computed by [util::hash_directory].
+    pub fn content_hash(&self) -> u64 {
+        self.content_hash
+    }
+
+    /// The time at which the snapshot was built.
+    pub fn build_time(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(self.build_time)
+    }
+
+    /// Indicates whether the snapshot was built by this version of the crate
+    /// from a directory with the given content hash, i.e. whether it can be
+    /// used instead of loading that directory.
+    pub fn is_up_to_date(&self, content_hash: u64) -> bool {
+        self.crate_version == CRATE_VERSION &&
+            self.content_hash == content_hash
+    }
+}
+
+/// A trait for data which can be stored in a snapshot and rebuilt from its
+/// data directory.
+pub trait Cacheable : Serialize + DeserializeOwned {
+
+    /// Loads the data from the directory with the given path.
+    fn build(dir: &str) -> OptolithDataResult<Self>;
+}
+
+impl Cacheable for OptolithData {
+    fn build(dir: &str) -> OptolithDataResult<OptolithData> {
+        OptolithData::from_directory(dir)
+    }
+}
+
+impl Cacheable for Compatibility {
+    fn build(dir: &str) -> OptolithDataResult<Compatibility> {
+        Compatibility::from_directory(dir)
+    }
+}
+
+fn read_header_from(reader: &mut impl Read)
+        -> OptolithDataResult<CacheHeader> {
+    let mut magic = [0u8; 8];
+    reader.read_exact(&mut magic)?;
+
+    if &magic != MAGIC {
+        return Err(OptolithDataErrorKind::InvalidCache.into());
+    }
+
+    let header: CacheHeader = rmp_serde::from_read(reader)?;
+
+    if header.format_version != FORMAT_VERSION {
+        return Err(OptolithDataErrorKind::InvalidCache.into());
+    }
+
+    Ok(header)
+}
+
+fn read_do<T>(path: &Path) -> OptolithDataResult<(CacheHeader, T)>
+where
+    T: DeserializeOwned
+{
+    let mut reader = BufReader::new(File::open(path)?);
+    let header = read_header_from(&mut reader)?;
+    let t = rmp_serde::from_read(reader)?;
+    Ok((header, t))
+}
+
+fn write_do<T>(t: &T, content_hash: u64, path: &Path) -> OptolithDataResult<()>
+where
+    T: Serialize
+{
+    let build_time = SystemTime::now().duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let header = CacheHeader {
+        format_version: FORMAT_VERSION,
+        crate_version: String::from(CRATE_VERSION),
+        content_hash,
+        build_time
+    };
+    let mut writer = BufWriter::new(File::create(path)?);
+    writer.write_all(MAGIC)?;
+    rmp_serde::encode::write_named(&mut writer, &header)?;
+    rmp_serde::encode::write_named(&mut writer, t)?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// Reads only the header of the snapshot file with the given path.
+pub fn read_header(path: &str) -> OptolithDataResult<CacheHeader> {
+    let path = Path::new(path);
+    let mut res = File::open(path)
+        .map_err(Into::into)
+        .and_then(|file| read_header_from(&mut BufReader::new(file)));
+    error::set_file(&mut res, path);
+    res
+}
+
+/// Reads the snapshot file with the given path, returning its header and its
+/// content.
+pub fn read<T>(path: &str) -> OptolithDataResult<(CacheHeader, T)>
+where
+    T: DeserializeOwned
+{
+    let path = Path::new(path);
+    let mut res = read_do(path);
+    error::set_file(&mut res, path);
+    res
+}
+
+/// Writes a snapshot of `t` to the file with the given path. `content_hash`
+/// is the hash of the directory `t` was loaded from, as computed by
+/// [util::hash_directory].
+pub fn write<T>(t: &T, content_hash: u64, path: &str) -> OptolithDataResult<()>
+where
+    T: Serialize
+{
+    let path = Path::new(path);
+    let mut res = write_do(t, content_hash, path);
+    error::set_file(&mut res, path);
+    res
+}
+
+/// Loads data from the snapshot file at `cache` if it is up to date with the
+/// directory `dir`. Otherwise, the data is loaded from `dir` and the snapshot
+/// is rebuilt. A missing or outdated snapshot, or one without a valid header,
+/// is never an error. If the header is up to date, but the content cannot be
+/// read, an error of kind [OptolithDataErrorKind::InvalidCache] is returned,
+/// since this version of the crate wrote a snapshot it cannot read.
+pub fn load_cached_or_build<T>(dir: &str, cache: &str) -> OptolithDataResult<T>
+where
+    T: Cacheable
+{
+    let content_hash = util::hash_directory(Path::new(dir))?;
+
+    if let Ok(header) = read_header(cache) {
+        if header.is_up_to_date(content_hash) {
+            let mut res = read(cache)
+                .map(|(_, t)| t)
+                .map_err(|_| OptolithDataErrorKind::InvalidCache.into());
+            error::set_file(&mut res, Path::new(cache));
+            return res;
+        }
+    }
+
+    let t = T::build(dir)?;
+    write(&t, content_hash, cache)?;
+    Ok(t)
+}
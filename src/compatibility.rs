@@ -1,5 +1,7 @@
+use crate::cache;
 use crate::error::OptolithDataResult;
 use crate::id::{Category, Id};
+use crate::source::{self, DataSource, FileSystem};
 use crate::util;
 
 use serde::{Deserialize, Serialize};
@@ -113,15 +115,17 @@ pub struct Compatibility {
     poisons_id_map: HashMap<u32, Id>
 }
 
-struct CompatibilityLoader {
+struct CompatibilityLoader<'a> {
     compat: Compatibility,
+    source: &'a dyn DataSource,
     path: String
 }
 
-fn load_u32_id_map(map: &mut HashMap<u32, Id>, path: &str, file: &str,
-        category: Category) -> OptolithDataResult<()> {
-    let file = util::join(path, file);
-    let vec: Vec<(u32, u32)> = util::deserialize_yaml_file(&file)?;
+fn load_u32_id_map(map: &mut HashMap<u32, Id>, source: &dyn DataSource,
+        path: &str, file: &str, category: Category)
+        -> OptolithDataResult<()> {
+    let file = source::join(path, file);
+    let vec: Vec<(u32, u32)> = source::deserialize_yaml_file(source, &file)?;
 
     for (old_id, new_id) in vec {
         let id = Id::new(category, new_id);
@@ -131,22 +135,24 @@ fn load_u32_id_map(map: &mut HashMap<u32, Id>, path: &str, file: &str,
     Ok(())
 }
 
-impl CompatibilityLoader {
-    fn new(path: &str) -> CompatibilityLoader {
+impl<'a> CompatibilityLoader<'a> {
+    fn new(source: &'a dyn DataSource, path: &str) -> CompatibilityLoader<'a> {
         CompatibilityLoader {
             compat: Compatibility {
                 str_id_map: HashMap::new(),
                 diseases_id_map: HashMap::new(),
                 poisons_id_map: HashMap::new()
             },
+            source,
             path: String::from(path)
         }
     }
 
     fn load_str_id_map(&mut self, file: &str, category: Category)
             -> OptolithDataResult<()> {
-        let file = util::join(&self.path, file);
-        let vec: Vec<(String, u32)> = util::deserialize_yaml_file(&file)?;
+        let file = source::join(&self.path, file);
+        let vec: Vec<(String, u32)> =
+            source::deserialize_yaml_file(self.source, &file)?;
     
         for (s, i) in vec {
             let id = Id::new(category, i);
@@ -158,8 +164,9 @@ impl CompatibilityLoader {
 
     fn load_traditions_id_map(&mut self, file: &str, category: Category)
             -> OptolithDataResult<()> {
-        let file = util::join(&self.path, file);
-        let vec: Vec<(String, Option<u32>, u32)> = util::deserialize_yaml_file(&file)?;
+        let file = source::join(&self.path, file);
+        let vec: Vec<(String, Option<u32>, u32)> =
+            source::deserialize_yaml_file(self.source, &file)?;
 
         for (s, _, i) in vec {
             let id = Id::new(category, i);
@@ -177,20 +184,27 @@ impl CompatibilityLoader {
     }
 
     fn load_diseases(&mut self) -> OptolithDataResult<()> {
-        load_u32_id_map(&mut self.compat.diseases_id_map, &self.path,
-            DISEASES_FILE, Category::Diseases)
+        load_u32_id_map(&mut self.compat.diseases_id_map, self.source,
+            &self.path, DISEASES_FILE, Category::Diseases)
     }
 
     fn load_poisons(&mut self) -> OptolithDataResult<()> {
-        load_u32_id_map(&mut self.compat.poisons_id_map, &self.path,
-            POISONS_FILE, Category::Poisons)
+        load_u32_id_map(&mut self.compat.poisons_id_map, self.source,
+            &self.path, POISONS_FILE, Category::Poisons)
     }
 }
 
 impl Compatibility {
 
     pub fn from_directory(path: &str) -> OptolithDataResult<Compatibility> {
-        let mut res = CompatibilityLoader::new(path);
+        Compatibility::from_source(&FileSystem, path)
+    }
+
+    /// Loads the mappings from the directory with the given path within the
+    /// given [DataSource].
+    pub fn from_source(source: &dyn DataSource, path: &str)
+            -> OptolithDataResult<Compatibility> {
+        let mut res = CompatibilityLoader::new(source, path);
 
         // TODO Publication (if that is even necessary)
 
@@ -319,6 +333,14 @@ impl Compatibility {
         util::to_file(self, path)
     }
 
+    /// Loads the mappings from the binary snapshot at `cache` if it is up to
+    /// date with the directory `dir`, and otherwise loads `dir` and rebuilds
+    /// the snapshot. See [cache::load_cached_or_build].
+    pub fn load_cached_or_build(dir: &str, cache: &str)
+            -> OptolithDataResult<Compatibility> {
+        cache::load_cached_or_build(dir, cache)
+    }
+
     pub fn get_str_id(&self, str_id: &str) -> Option<Id> {
         self.str_id_map.get(str_id).cloned()
     }
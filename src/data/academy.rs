@@ -8,7 +8,11 @@ use crate::id::{Category, Id, Identifiable};
 
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum ElectiveSpellworkRestriction {
@@ -16,6 +20,7 @@ pub enum ElectiveSpellworkRestriction {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct ElectiveSpellwork {
     #[serde(flatten)]
@@ -30,6 +35,7 @@ pub struct ElectiveSpellwork {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum RestrictedSpellwork {
@@ -49,6 +55,7 @@ pub enum RestrictedSpellwork {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct SpellworkAdjustment {
     pub id: StandardSpellworkId,
@@ -58,6 +65,7 @@ pub struct SpellworkAdjustment {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct SpellworkChange {
     pub replacement: SpellworkAdjustment,
@@ -65,6 +73,7 @@ pub struct SpellworkChange {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum LessonPackageSkillId {
@@ -76,6 +85,7 @@ pub enum LessonPackageSkillId {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct LessonPackageSkill {
     pub id: LessonPackageSkillId,
@@ -85,6 +95,7 @@ pub struct LessonPackageSkill {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct LessonPackage {
     pub id: u32,
@@ -108,6 +119,7 @@ impl TranslationsTranslatable for LessonPackage {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Curriculum {
     pub id: u32,
@@ -142,6 +154,7 @@ impl TranslationsTranslatable for Curriculum {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Guideline {
     pub id: u32,
@@ -167,6 +180,7 @@ impl TranslationsTranslatable for Guideline {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Influence {
     pub id: u32,
@@ -13,6 +13,12 @@ use serde::{Deserialize, Serialize};
 
 use serde_repr::{Deserialize_repr, Serialize_repr};
 
+#[cfg(feature = "schema")]
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+#[cfg(feature = "schema")]
+use std::borrow::Cow;
+
 #[derive(Clone, Deserialize_repr, Serialize_repr)]
 #[repr(u32)]
 pub enum TraitGroup {
@@ -21,7 +27,22 @@ pub enum TraitGroup {
     Karmal = 3
 }
 
+#[cfg(feature = "schema")]
+impl JsonSchema for TraitGroup {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("TraitGroup")
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "integer",
+            "enum": [1, 2, 3]
+        })
+    }
+}
+
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct TraitLocalization {
 
@@ -75,11 +96,16 @@ impl Localization for TraitLocalization {
     fn name(&self) -> &str {
         &self.name
     }
+
+    fn texts(&self) -> Vec<&str> {
+        self.rules.iter().map(String::as_str).collect()
+    }
 }
 
 /// The intrinsic trait of a character. This is used as a generic term for
 /// advantages and disadvantages.
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Trait {
     pub id: u32,
@@ -120,6 +146,7 @@ pub struct Trait {
 }
 
 #[derive(Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Advantage {
     #[serde(flatten)]
@@ -141,6 +168,7 @@ impl TranslationsTranslatable for Advantage {
 }
 
 #[derive(Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Disadvantage {
     #[serde(flatten)]
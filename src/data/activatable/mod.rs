@@ -6,10 +6,14 @@ use crate::data::src::SourceRefs;
 
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+
 pub mod character_trait;
 pub mod special_ability;
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub enum ActivatableType {
     Advantage,
@@ -60,7 +64,89 @@ pub enum ActivatableType {
     ChronicleEnchantment
 }
 
+impl ActivatableType {
+
+    /// The category of the entities of this type.
+    pub fn category(&self) -> Category {
+        match self {
+            ActivatableType::Advantage => Category::Advantages,
+            ActivatableType::Disadvantage => Category::Disadvantages,
+            ActivatableType::GeneralSpecialAbility =>
+                Category::GeneralSpecialAbilities,
+            ActivatableType::FatePointSpecialAbility =>
+                Category::FatePointSpecialAbilities,
+            ActivatableType::CombatSpecialAbility =>
+                Category::CombatSpecialAbilities,
+            ActivatableType::MagicalSpecialAbility =>
+                Category::MagicalSpecialAbilities,
+            ActivatableType::StaffEnchantment => Category::StaffEnchantments,
+            ActivatableType::FamiliarSpecialAbility =>
+                Category::FamiliarSpecialAbilities,
+            ActivatableType::KarmaSpecialAbility =>
+                Category::KarmaSpecialAbilities,
+            ActivatableType::ProtectiveWardingCircleSpecialAbility =>
+                Category::ProtectiveWardingCircleSpecialAbilities,
+            ActivatableType::CombatStyleSpecialAbility =>
+                Category::CombatStyleSpecialAbilities,
+            ActivatableType::AdvancedCombatSpecialAbility =>
+                Category::AdvancedCombatSpecialAbilities,
+            ActivatableType::CommandSpecialAbility =>
+                Category::CommandSpecialAbilities,
+            ActivatableType::MagicStyleSpecialAbility =>
+                Category::MagicStyleSpecialAbilities,
+            ActivatableType::AdvancedMagicalSpecialAbility =>
+                Category::AdvancedMagicalSpecialAbilities,
+            ActivatableType::SpellSwordEnchantment =>
+                Category::SpellSwordEnchantments,
+            ActivatableType::DaggerRitual => Category::DaggerRituals,
+            ActivatableType::InstrumentEnchantment =>
+                Category::InstrumentEnchantments,
+            ActivatableType::AttireEnchantment => Category::AttireEnchantments,
+            ActivatableType::OrbEnchantment => Category::OrbEnchantments,
+            ActivatableType::WandEnchantment => Category::WandEnchantments,
+            ActivatableType::BrawlingSpecialAbility =>
+                Category::BrawlingSpecialAbilities,
+            ActivatableType::AncestorGlyph => Category::AncestorGlyphs,
+            ActivatableType::CeremonialItemSpecialAbility =>
+                Category::CeremonialItemSpecialAbilities,
+            ActivatableType::Sermon => Category::Sermons,
+            ActivatableType::LiturgicalStyleSpecialAbility =>
+                Category::LiturgicalStyleSpecialAbilities,
+            ActivatableType::AdvancedKarmaSpecialAbility =>
+                Category::AdvancedKarmaSpecialAbilities,
+            ActivatableType::Vision => Category::Visions,
+            ActivatableType::MagicalTradition => Category::MagicalTraditions,
+            ActivatableType::BlessedTradition => Category::BlessedTraditions,
+            ActivatableType::PactGift => Category::PactGifts,
+            ActivatableType::SikaryanDrainSpecialAbility =>
+                Category::SikaryanDrainSpecialAbilities,
+            ActivatableType::LycantropicGift => Category::LycantropicGifts,
+            ActivatableType::Talentstilsonderfertigkeit =>
+                Category::SkillStyleSpecialAbilities,
+            ActivatableType::AdvancedSkillSpecialAbility =>
+                Category::AdvancedSkillSpecialAbilities,
+            ActivatableType::ArcaneOrbEnchantment =>
+                Category::ArcaneOrbEnchantments,
+            ActivatableType::CauldronEnchantment =>
+                Category::CauldronEnchantments,
+            ActivatableType::FoolsHatEnchantment =>
+                Category::FoolsHatEnchantments,
+            ActivatableType::ToyEnchantment => Category::ToyEnchantments,
+            ActivatableType::BowlEnchantment => Category::BowlEnchantments,
+            ActivatableType::FatePointSexSpecialAbility =>
+                Category::FatePointSexSpecialAbilities,
+            ActivatableType::SexSpecialAbility => Category::SexSpecialAbilities,
+            ActivatableType::WeaponEnchantment => Category::WeaponEnchantments,
+            ActivatableType::SickleRitual => Category::SickleRituals,
+            ActivatableType::RingEnchantment => Category::RingEnchantments,
+            ActivatableType::ChronicleEnchantment =>
+                Category::ChronicleEnchantments
+        }
+    }
+}
+
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct ActivatableId {
     #[serde(rename = "type")]
@@ -69,6 +155,7 @@ pub struct ActivatableId {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct CategorySelectOptionPrerequisite {
     pub target: ActivatableId,
@@ -77,6 +164,7 @@ pub struct CategorySelectOptionPrerequisite {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum CategoryPrerequisite {
@@ -91,6 +179,7 @@ pub enum CategoryPrerequisite {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct DerivedFromAbilityEntryAPValue {
     pub id: u32,
@@ -99,6 +188,7 @@ pub struct DerivedFromAbilityEntryAPValue {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum DerivedFromAbilityAPValue {
@@ -119,6 +209,7 @@ pub enum DerivedFromAbilityAPValue {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct DerivedSelectOptionSkillMod {
 
@@ -129,6 +220,7 @@ pub struct DerivedSelectOptionSkillMod {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct RequiredSkillRating {
 
@@ -143,6 +235,7 @@ pub struct RequiredSkillRating {
 
 /// Entries of the list of category IDs of derived select options.
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct DerivedSelectOption {
     pub category: Category,
@@ -212,6 +305,7 @@ pub struct DerivedSelectOption {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub enum SelectOptionAbility {
     Skill,
@@ -222,6 +316,7 @@ pub enum SelectOptionAbility {
 /// An identification of a select option for a specific activatable, if the
 /// activatable is apparent from context.
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(untagged)]
 #[serde(deny_unknown_fields)]
 pub enum SelectOptionId {
@@ -234,6 +329,7 @@ pub enum SelectOptionId {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(untagged)]
 #[serde(deny_unknown_fields)]
 pub enum ExplicitSelectOptionLocalization {
@@ -271,6 +367,7 @@ impl Localization for ExplicitSelectOptionLocalization {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct ExplicitSelectOption {
     pub id: SelectOptionId,
@@ -305,6 +402,7 @@ pub struct ExplicitSelectOption {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum SelectOptions {
@@ -325,6 +423,7 @@ pub enum SelectOptions {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum APValue {
@@ -335,6 +434,7 @@ pub enum APValue {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct SkillApplication {
 
@@ -356,6 +456,7 @@ pub struct SkillApplication {
 pub type SkillApplications = Vec<SkillApplication>;
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct SkillUse {
 
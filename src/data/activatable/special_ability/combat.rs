@@ -16,10 +16,14 @@ use crate::id::{Category, CategoryProvider, Id, Identifiable};
 
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+
 use std::marker::PhantomData;
 
 /// Type of combat special ability. The type id.
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub enum CombatSpecialAbilityType {
     Passive,
@@ -28,6 +32,7 @@ pub enum CombatSpecialAbilityType {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum AllCombatTechniqueRestriction {
@@ -39,6 +44,7 @@ pub enum AllCombatTechniqueRestriction {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum AllMeleeCombatTechniqueRestriction {
@@ -53,6 +59,7 @@ pub enum AllMeleeCombatTechniqueRestriction {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum AllRangedCombatTechniqueRestriction {
@@ -64,6 +71,7 @@ pub enum AllRangedCombatTechniqueRestriction {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum SpecificCombatTechniqueRestriction {
@@ -76,6 +84,7 @@ pub enum SpecificCombatTechniqueRestriction {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct SpecificCombatTechnique {
     pub id: CombatTechniqueId,
@@ -89,6 +98,7 @@ pub struct SpecificCombatTechnique {
 /// ranged CTs; 4: All melee CTs with parry; 5: All melee CTs used with
 /// one-handed weapons.
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum ApplicableCombatTechniques {
@@ -101,6 +111,7 @@ pub enum ApplicableCombatTechniques {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct CombatSpecialAbilitySuper<C: CategoryProvider> {
     pub id: u32,
@@ -193,6 +204,7 @@ pub type CommandSpecialAbility =
     CombatSpecialAbilitySuper<CommandSpecialAbilityCategory>;
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct CombatStyleSpecialAbility {
     pub id: u32,
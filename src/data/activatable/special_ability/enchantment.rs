@@ -1,6 +1,6 @@
 use crate::data::{Localization, TranslationsTranslatable, Translations};
 use crate::data::activatable::{APValue, SelectOptions};
-use crate::data::errata::Errata;
+use crate::data::errata::{Errata, Erratum};
 use crate::data::prerequisite::GeneralListOrByLevelPrerequisite;
 use crate::data::simple::{
     SimpleEntity,
@@ -12,9 +12,13 @@ use crate::id::{Category, CategoryProvider, Id, Identifiable};
 
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+
 use std::marker::PhantomData;
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct SimpleEnchantmentLocalization {
     
@@ -77,9 +81,18 @@ impl Localization for SimpleEnchantmentLocalization {
     fn name(&self) -> &str {
         &self.name
     }
+
+    fn texts(&self) -> Vec<&str> {
+        vec![&self.effect]
+    }
+
+    fn errata(&self) -> &[Erratum] {
+        self.errata.as_deref().unwrap_or_default()
+    }
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct UnboundEnchantmentLocalization {
     
@@ -135,11 +148,20 @@ impl Localization for UnboundEnchantmentLocalization {
     fn name(&self) -> &str {
         &self.name
     }
+
+    fn texts(&self) -> Vec<&str> {
+        vec![&self.effect]
+    }
+
+    fn errata(&self) -> &[Erratum] {
+        self.errata.as_deref().unwrap_or_default()
+    }
 }
 
 /// The magic property. See `Properties` to get the id. Use DependingOnProperty
 /// if there is no clear property.
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum Property {
@@ -148,6 +170,7 @@ pub enum Property {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct BasicEnchantment<C: CategoryProvider, L: Localization> {
     pub id: u32,
@@ -188,6 +211,7 @@ where
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct CauldronEnchantment {
     pub id: u32,
@@ -392,6 +416,7 @@ impl CategoryProvider for AnimalShapePathCategory {
 pub type AnimalShapePath = SimpleEntity<AnimalShapePathCategory>;
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct AnimalShapeSize {
     pub id: u32,
@@ -420,6 +445,7 @@ impl TranslationsTranslatable for AnimalShapeSize {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct AnimalShape {
     pub id: u32,
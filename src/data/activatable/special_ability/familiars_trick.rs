@@ -1,12 +1,16 @@
 use crate::data::{Localization, TranslationsTranslatable, Translations};
-use crate::data::errata::Errata;
+use crate::data::errata::{Errata, Erratum};
 use crate::data::skill::non_profane::MainParameterLocalization;
 use crate::data::src::SourceRefs;
 use crate::id::{Category, Id, Identifiable};
 
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum FamiliarAPValue {
@@ -15,6 +19,7 @@ pub enum FamiliarAPValue {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct FamiliarsTrickLocalization {
 
@@ -36,9 +41,18 @@ impl Localization for FamiliarsTrickLocalization {
     fn name(&self) -> &str {
         &self.name
     }
+
+    fn texts(&self) -> Vec<&str> {
+        vec![&self.effect]
+    }
+
+    fn errata(&self) -> &[Erratum] {
+        self.errata.as_deref().unwrap_or_default()
+    }
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct FamiliarsTrick {
     pub id: u32,
@@ -13,6 +13,9 @@ use crate::id::{CategoryProvider, Category, Id, Identifiable};
 
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+
 #[derive(Clone)]
 pub struct LycantropicGiftCategory;
 
@@ -34,6 +37,7 @@ pub type VampiricGift =
     SimpleSpecialAbility<VampiricGiftCategory, SpecialAbilityLocalization>;
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum PactGiftDemonicConsumption {
@@ -42,6 +46,7 @@ pub enum PactGiftDemonicConsumption {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub enum AutomaticEntryAction {
     Add,
@@ -49,6 +54,7 @@ pub enum AutomaticEntryAction {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub enum AutomaticEntrySelectionTargetType {
     MagicalTraditions,
@@ -56,6 +62,7 @@ pub enum AutomaticEntrySelectionTargetType {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum AutomaticEntryTarget {
@@ -71,6 +78,7 @@ pub enum AutomaticEntryTarget {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct AutomaticEntry {
 
@@ -87,6 +95,7 @@ pub struct AutomaticEntry {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct PactGift {
     pub id: u32,
@@ -126,6 +135,7 @@ impl TranslationsTranslatable for PactGift {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct UncategorizedSimpleEntity {
     pub id: u32,
@@ -136,6 +146,7 @@ pub type PactCategoryType = UncategorizedSimpleEntity;
 pub type PactCategoryDomain = UncategorizedSimpleEntity;
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct PactCategory {
     pub id: u32,
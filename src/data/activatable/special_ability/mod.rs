@@ -5,7 +5,7 @@ use crate::data::activatable::{
     SkillApplications,
     SkillUses
 };
-use crate::data::errata::Errata;
+use crate::data::errata::{Errata, Erratum};
 use crate::data::prerequisite::{DisplayOption, GeneralListOrByLevelPrerequisite};
 use crate::data::simple::SimpleEntity;
 use crate::data::src::SourceRefs;
@@ -13,6 +13,9 @@ use crate::id::{Category, CategoryProvider, Id, Identifiable};
 
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+
 use std::marker::PhantomData;
 
 pub mod ceremonial_item;
@@ -27,6 +30,7 @@ pub mod trade_secret;
 pub mod tradition;
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct SpecialAbilityLocalization {
 
@@ -81,11 +85,20 @@ impl Localization for SpecialAbilityLocalization {
     fn name(&self) -> &str {
         &self.name
     }
+
+    fn texts(&self) -> Vec<&str> {
+        vec![&self.rules]
+    }
+
+    fn errata(&self) -> &[Erratum] {
+        self.errata.as_deref().unwrap_or_default()
+    }
 }
 
 /// A normal special ability localization with a field `effect` instead of
 /// `rules`.
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct EffectSpecialAbilityLocalization {
     
@@ -137,15 +150,25 @@ impl Localization for EffectSpecialAbilityLocalization {
     fn name(&self) -> &str {
         &self.name
     }
+
+    fn texts(&self) -> Vec<&str> {
+        vec![&self.effect]
+    }
+
+    fn errata(&self) -> &[Erratum] {
+        self.errata.as_deref().unwrap_or_default()
+    }
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub enum RestrictOptionsType {
     Element
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(untagged)]
 #[serde(deny_unknown_fields)]
 pub enum RestrictOption {
@@ -161,6 +184,7 @@ pub enum RestrictOption {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(untagged)]
 #[serde(deny_unknown_fields)]
 pub enum RestrictOptions {
@@ -169,6 +193,7 @@ pub enum RestrictOptions {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum ExternalEntry {
@@ -176,6 +201,7 @@ pub enum ExternalEntry {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum FromOption {
@@ -183,15 +209,17 @@ pub enum FromOption {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Mapping {
     #[serde(rename = "fromOption")]
-    from_option: FromOption,
+    pub from_option: FromOption,
     #[serde(rename = "toAdvanced")]
-    to_advanced: u32
+    pub to_advanced: u32
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type")]
 #[serde(deny_unknown_fields)]
 pub enum ComplexAdvancedSpecialAbility {
@@ -214,6 +242,7 @@ pub enum ComplexAdvancedSpecialAbility {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(untagged)]
 #[serde(deny_unknown_fields)]
 pub enum AdvancedSpecialAbility {
@@ -234,6 +263,7 @@ pub enum AdvancedSpecialAbility {
 pub type AdvancedSpecialAbilities = [AdvancedSpecialAbility; 3];
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct SimpleSpecialAbility<C: CategoryProvider, L: Localization> {
     pub id: u32,
@@ -273,6 +303,7 @@ where
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct SkillInfluencingSpecialAbility<C, L>
 where
@@ -330,6 +361,7 @@ where
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub enum SpecialAbilityType {
     GeneralSpecialAbility,
@@ -378,7 +410,90 @@ pub enum SpecialAbilityType {
     ChronicleEnchantment
 }
 
+impl SpecialAbilityType {
+
+    /// The category of the special abilities of this type.
+    pub fn category(&self) -> Category {
+        match self {
+            SpecialAbilityType::GeneralSpecialAbility =>
+                Category::GeneralSpecialAbilities,
+            SpecialAbilityType::FatePointSpecialAbility =>
+                Category::FatePointSpecialAbilities,
+            SpecialAbilityType::CombatSpecialAbility =>
+                Category::CombatSpecialAbilities,
+            SpecialAbilityType::MagicalSpecialAbility =>
+                Category::MagicalSpecialAbilities,
+            SpecialAbilityType::StaffEnchantment => Category::StaffEnchantments,
+            SpecialAbilityType::FamiliarSpecialAbility =>
+                Category::FamiliarSpecialAbilities,
+            SpecialAbilityType::KarmaSpecialAbility =>
+                Category::KarmaSpecialAbilities,
+            SpecialAbilityType::ProtectiveWardingCircleSpecialAbility =>
+                Category::ProtectiveWardingCircleSpecialAbilities,
+            SpecialAbilityType::CombatStyleSpecialAbility =>
+                Category::CombatStyleSpecialAbilities,
+            SpecialAbilityType::AdvancedCombatSpecialAbility =>
+                Category::AdvancedCombatSpecialAbilities,
+            SpecialAbilityType::CommandSpecialAbility =>
+                Category::CommandSpecialAbilities,
+            SpecialAbilityType::MagicStyleSpecialAbility =>
+                Category::MagicStyleSpecialAbilities,
+            SpecialAbilityType::AdvancedMagicalSpecialAbility =>
+                Category::AdvancedMagicalSpecialAbilities,
+            SpecialAbilityType::SpellSwordEnchantment =>
+                Category::SpellSwordEnchantments,
+            SpecialAbilityType::DaggerRitual => Category::DaggerRituals,
+            SpecialAbilityType::InstrumentEnchantment =>
+                Category::InstrumentEnchantments,
+            SpecialAbilityType::AttireEnchantment =>
+                Category::AttireEnchantments,
+            SpecialAbilityType::OrbEnchantment => Category::OrbEnchantments,
+            SpecialAbilityType::WandEnchantment => Category::WandEnchantments,
+            SpecialAbilityType::BrawlingSpecialAbility =>
+                Category::BrawlingSpecialAbilities,
+            SpecialAbilityType::AncestorGlyph => Category::AncestorGlyphs,
+            SpecialAbilityType::CeremonialItemSpecialAbility =>
+                Category::CeremonialItemSpecialAbilities,
+            SpecialAbilityType::Sermon => Category::Sermons,
+            SpecialAbilityType::LiturgicalStyleSpecialAbility =>
+                Category::LiturgicalStyleSpecialAbilities,
+            SpecialAbilityType::AdvancedKarmaSpecialAbility =>
+                Category::AdvancedKarmaSpecialAbilities,
+            SpecialAbilityType::Vision => Category::Visions,
+            SpecialAbilityType::MagicalTradition => Category::MagicalTraditions,
+            SpecialAbilityType::BlessedTradition => Category::BlessedTraditions,
+            SpecialAbilityType::PactGift => Category::PactGifts,
+            SpecialAbilityType::SikaryanDrainSpecialAbility =>
+                Category::SikaryanDrainSpecialAbilities,
+            SpecialAbilityType::LycantropicGift => Category::LycantropicGifts,
+            SpecialAbilityType::Talentstilsonderfertigkeit =>
+                Category::SkillStyleSpecialAbilities,
+            SpecialAbilityType::AdvancedSkillSpecialAbility =>
+                Category::AdvancedSkillSpecialAbilities,
+            SpecialAbilityType::ArcaneOrbEnchantment =>
+                Category::ArcaneOrbEnchantments,
+            SpecialAbilityType::CauldronEnchantment =>
+                Category::CauldronEnchantments,
+            SpecialAbilityType::FoolsHatEnchantment =>
+                Category::FoolsHatEnchantments,
+            SpecialAbilityType::ToyEnchantment => Category::ToyEnchantments,
+            SpecialAbilityType::BowlEnchantment => Category::BowlEnchantments,
+            SpecialAbilityType::FatePointSexSpecialAbility =>
+                Category::FatePointSexSpecialAbilities,
+            SpecialAbilityType::SexSpecialAbility =>
+                Category::SexSpecialAbilities,
+            SpecialAbilityType::WeaponEnchantment =>
+                Category::WeaponEnchantments,
+            SpecialAbilityType::SickleRitual => Category::SickleRituals,
+            SpecialAbilityType::RingEnchantment => Category::RingEnchantments,
+            SpecialAbilityType::ChronicleEnchantment =>
+                Category::ChronicleEnchantments
+        }
+    }
+}
+
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct SpecialAbilityId {
     #[serde(rename = "type")]
@@ -10,11 +10,14 @@ use crate::data::activatable::special_ability::ordinary::{
     OrdinarySpecialAbility,
     OrdinarySkillInfluencingSpecialAbility
 };
-use crate::data::errata::Errata;
+use crate::data::errata::{Errata, Erratum};
 use crate::id::{Category, CategoryProvider, Id, Identifiable};
 
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+
 #[derive(Clone)]
 pub struct MagicalSpecialAbilityCategory;
 
@@ -85,6 +88,7 @@ pub type FamiliarSpecialAbility =
         EffectSpecialAbilityLocalization>;
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct AncestorGlyphLocalization {
 
@@ -139,6 +143,14 @@ impl Localization for AncestorGlyphLocalization {
     fn name(&self) -> &str {
         &self.name
     }
+
+    fn texts(&self) -> Vec<&str> {
+        vec![&self.rules]
+    }
+
+    fn errata(&self) -> &[Erratum] {
+        self.errata.as_deref().unwrap_or_default()
+    }
 }
 
 #[derive(Clone)]
@@ -152,6 +164,7 @@ pub type AncestorGlyph = SimpleSpecialAbility<AncestorGlyphCategory,
     AncestorGlyphLocalization>;
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct ProtectiveWardingCircleSpecialAbilityLocalization {
     pub name: String,
@@ -205,6 +218,10 @@ impl Localization for ProtectiveWardingCircleSpecialAbilityLocalization {
     fn name(&self) -> &str {
         &self.name
     }
+
+    fn errata(&self) -> &[Erratum] {
+        self.errata.as_deref().unwrap_or_default()
+    }
 }
 
 #[derive(Clone)]
@@ -220,6 +237,7 @@ pub type ProtectiveWardingCircleSpecialAbility =
         ProtectiveWardingCircleSpecialAbilityLocalization>;
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct NonProfaneStyleSpecialAbility<C: CategoryProvider> {
     #[serde(flatten)]
@@ -245,6 +263,7 @@ for NonProfaneStyleSpecialAbility<C> {
 // TODO figure out why C suddenly requires Deserialize, Serialize
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct MagicStyleSpecialAbilityCategory;
 
@@ -256,6 +275,7 @@ pub type MagicStyleSpecialAbility =
     NonProfaneStyleSpecialAbility<MagicStyleSpecialAbilityCategory>;
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct LiturgicalStyleSpecialAbilityCategory;
 
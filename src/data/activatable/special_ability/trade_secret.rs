@@ -1,12 +1,16 @@
 use crate::data::{Localization, TranslationsTranslatable, Translations};
-use crate::data::errata::Errata;
+use crate::data::errata::{Errata, Erratum};
 use crate::data::prerequisite::ProfessionListPrerequisite;
 use crate::data::src::SourceRefs;
 use crate::id::{Category, Id, Identifiable};
 
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct TradeSecretLocalization {
     pub name: String,
@@ -20,9 +24,18 @@ impl Localization for TradeSecretLocalization {
     fn name(&self) -> &str {
         &self.name
     }
+
+    fn texts(&self) -> Vec<&str> {
+        self.description.iter().map(String::as_str).collect()
+    }
+
+    fn errata(&self) -> &[Erratum] {
+        self.errata.as_deref().unwrap_or_default()
+    }
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct TradeSecret {
     pub id: u32,
@@ -5,7 +5,7 @@ use crate::data::activatable::{
     SkillApplications,
     SkillUses
 };
-use crate::data::errata::Errata;
+use crate::data::errata::{Errata, Erratum};
 use crate::data::prerequisite::{
     ArcaneTraditionListPrerequisite,
     GeneralListOrByLevelPrerequisite,
@@ -22,9 +22,13 @@ use crate::id::{Category, CategoryProvider, Id, Identifiable};
 
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+
 use std::marker::PhantomData;
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum FavoredCombatTechniques {
@@ -35,6 +39,7 @@ pub enum FavoredCombatTechniques {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct FavoredSkillsSelection {
     pub amount: u32,
@@ -42,6 +47,7 @@ pub struct FavoredSkillsSelection {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct SpecialRuleLocalization {
 
@@ -53,6 +59,7 @@ pub struct SpecialRuleLocalization {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct BlessedTraditionLocalization {
 
@@ -111,9 +118,14 @@ impl Localization for BlessedTraditionLocalization {
     fn name(&self) -> &str {
         &self.name
     }
+
+    fn errata(&self) -> &[Erratum] {
+        self.errata.as_deref().unwrap_or_default()
+    }
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct BlessedTradition {
     pub id: u32,
@@ -187,6 +199,7 @@ impl TranslationsTranslatable for BlessedTradition {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct MagicalTraditionLocalization {
     pub name: String,
@@ -243,9 +256,14 @@ impl Localization for MagicalTraditionLocalization {
     fn name(&self) -> &str {
         &self.name
     }
+
+    fn errata(&self) -> &[Erratum] {
+        self.errata.as_deref().unwrap_or_default()
+    }
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct MagicalTradition {
     pub id: u32,
@@ -330,6 +348,7 @@ impl TranslationsTranslatable for MagicalTradition {
 
 /// A schema that matches both the arcane bard and dancer traditions.
 #[derive(Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct ArcaneProfessionTradition<C: CategoryProvider> {
     pub id: u32,
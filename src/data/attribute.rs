@@ -1,9 +1,13 @@
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+
 use crate::data::{Localization, TranslationsTranslatable, Translations};
 use crate::id::{Category, Id, Identifiable};
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct AttributeLocalization {
     pub name: String,
@@ -16,9 +20,14 @@ impl Localization for AttributeLocalization {
     fn name(&self) -> &str {
         &self.name
     }
+
+    fn texts(&self) -> Vec<&str> {
+        vec![&self.description]
+    }
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Attribute {
     pub id: u32,
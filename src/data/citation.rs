@@ -0,0 +1,221 @@
+//! Formatting of source references as citations like
+//! `Aventurisches Kompendium II, S. 112–113` or `Core Rules p. 240`.
+//!
+//! The language of a citation is negotiated per publication among the
+//! languages in which the entry occurs and the publication is translated.
+//! Multiple references to the same publication are merged into a single
+//! citation, whose page ranges are sorted and deduplicated.
+
+use crate::data::OptolithData;
+use crate::data::locale::LocaleChain;
+use crate::data::publication::Publication;
+use crate::data::src::SourceRef;
+
+use std::fmt::{self, Display, Formatter, Write};
+
+/// The separator between the citations of different publications.
+const SEPARATOR: &str = "; ";
+
+/// A formatted reference to the pages of a single publication.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Citation {
+
+    /// The ID of the cited publication.
+    pub publication: u32,
+
+    /// The formatted citation, which consists of the name of the publication,
+    /// or its abbreviation, followed by the cited pages, such as
+    /// `Example pp. 112–114, 120`.
+    pub text: String,
+
+    /// The name of the locale the citation was formatted in.
+    pub locale: String,
+
+    /// Whether the locale does not match the most preferred one of the
+    /// [LocaleChain], i.e. whether the entry does not occur in the edition of
+    /// the publication in its language.
+    pub is_fallback: bool
+}
+
+impl Display for Citation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+/// How pages are referred to in a language.
+struct PageStyle {
+
+    /// The text between the name of the publication and the page label.
+    separator: &'static str,
+    page: &'static str,
+    pages: &'static str
+}
+
+impl PageStyle {
+    fn of(locale: &str) -> PageStyle {
+        match locale.split('-').next().unwrap() {
+            "de" => PageStyle { separator: ", ", page: "S.", pages: "S." },
+            "en" => PageStyle { separator: " ", page: "p.", pages: "pp." },
+            _ => PageStyle { separator: ", ", page: "p.", pages: "pp." }
+        }
+    }
+}
+
+/// Formats source references as citations in the best available locale of a
+/// [LocaleChain]. It is created by
+/// [OptolithData::citations](crate::data::OptolithData::citations). See the
+/// [module documentation](self) for how references are merged.
+pub struct CitationFormatter<'a> {
+    data: &'a OptolithData,
+    chain: LocaleChain,
+    abbreviated: bool,
+    printing: Option<u32>
+}
+
+impl<'a> CitationFormatter<'a> {
+    pub(crate) fn new(data: &'a OptolithData, chain: LocaleChain)
+            -> CitationFormatter<'a> {
+        CitationFormatter {
+            data,
+            chain,
+            abbreviated: false,
+            printing: None
+        }
+    }
+
+    /// Uses the abbreviations of the names of publications, such as `KRB`,
+    /// instead of their full names.
+    pub fn abbreviated(mut self) -> CitationFormatter<'a> {
+        self.abbreviated = true;
+        self
+    }
+
+    /// Omits the references to the publication containing the printing with
+    /// the given universal printing identifier if the entry is not present
+    /// in that printing, according to the `since` and `deprecated` printings
+    /// of the references. References to other publications are kept.
+    pub fn with_printing(mut self, printing: u32) -> CitationFormatter<'a> {
+        self.printing = Some(printing);
+        self
+    }
+
+    /// Creates a citation per cited publication, in the order in which they
+    /// are first referenced. References to unknown publications and
+    /// references without occurrences in any locale of the chain are
+    /// omitted.
+    pub fn cite(&self, src: &[SourceRef]) -> Vec<Citation> {
+        let mut publications = Vec::<u32>::new();
+
+        for src in src {
+            if !publications.contains(&src.id) {
+                publications.push(src.id);
+            }
+        }
+
+        publications.into_iter()
+            .filter_map(|id| {
+                let publication = self.data.get::<Publication>(id)?;
+                let refs = src.iter()
+                    .filter(|src| src.id == id)
+                    .filter(|src| self.is_in_printing(src, publication))
+                    .collect::<Vec<_>>();
+
+                self.cite_publication(publication, &refs)
+            })
+            .collect()
+    }
+
+    /// Formats the citations of all cited publications, separated by
+    /// semicolons. Returns an empty string if nothing can be cited.
+    pub fn format(&self, src: &[SourceRef]) -> String {
+        let mut text = String::new();
+
+        for citation in self.cite(src) {
+            if !text.is_empty() {
+                text.push_str(SEPARATOR);
+            }
+
+            text.push_str(&citation.text);
+        }
+
+        text
+    }
+
+    fn is_in_printing(&self, src: &SourceRef, publication: &Publication)
+            -> bool {
+        self.printing
+            .map(|printing| src.is_in_printing(publication, printing))
+            .unwrap_or(true)
+    }
+
+    fn cite_publication(&self, publication: &Publication, refs: &[&SourceRef])
+            -> Option<Citation> {
+        let available = refs.iter()
+            .flat_map(|src| src.occurrences.keys())
+            .filter(|locale| publication.translations.contains_key(*locale))
+            .map(String::as_str);
+        let locale = self.chain.negotiate(available)?;
+        let localization = &publication.translations[locale];
+        let mut occurrences = refs.iter()
+            .filter_map(|src| src.occurrences.get(locale))
+            .flat_map(|occurrences| occurrences.as_slice())
+            .map(|o| (o.first_page, o.last_page.unwrap_or(o.first_page)))
+            .collect::<Vec<_>>();
+        occurrences.sort_unstable();
+        let occurrences = merge_pages(occurrences);
+
+        let style = PageStyle::of(locale);
+        let is_range = occurrences.len() > 1 ||
+            occurrences.iter().any(|(first, last)| first != last);
+        let name = if self.abbreviated {
+            &localization.name_abbr
+        }
+        else {
+            &localization.name
+        };
+        let mut text = format!("{}{}{} ", name, style.separator,
+            if is_range { style.pages } else { style.page });
+
+        for (index, &(first, last)) in occurrences.iter().enumerate() {
+            if index > 0 {
+                text.push_str(", ");
+            }
+
+            write_pages(&mut text, first, last);
+        }
+
+        Some(Citation {
+            publication: publication.id,
+            text,
+            locale: locale.to_owned(),
+            is_fallback: !self.chain.is_preferred(locale)
+        })
+    }
+}
+
+/// Merges the given page ranges, which must be sorted, where they overlap or
+/// are adjacent, so `112–113`, `113` and `114` become `112–114`.
+fn merge_pages(pages: Vec<(u32, u32)>) -> Vec<(u32, u32)> {
+    let mut merged: Vec<(u32, u32)> = Vec::with_capacity(pages.len());
+
+    for (first, last) in pages {
+        match merged.last_mut() {
+            Some((_, previous)) if first <= previous.saturating_add(1) =>
+                *previous = last.max(*previous),
+            _ => merged.push((first, last))
+        }
+    }
+
+    merged
+}
+
+/// Appends the given pages, such as `240` or `112–113`, to the given text.
+fn write_pages(text: &mut String, first: u32, last: u32) {
+    if first == last {
+        write!(text, "{}", first)
+    }
+    else {
+        write!(text, "{}–{}", first, last)
+    }.unwrap()
+}
@@ -0,0 +1,73 @@
+//! An index of the reverse direction of prerequisites, which answers which
+//! entities require a specific entity.
+
+use crate::data::validation::{
+    collect_references,
+    Reference,
+    References,
+    Requirement
+};
+use crate::id::{Id, Identifiable};
+
+use std::collections::HashMap;
+
+/// An entity that requires another entity in one of its prerequisites.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Dependent {
+
+    /// The ID of the entity with the prerequisite.
+    pub id: Id,
+
+    /// The path of the field within the entity with the prerequisite that
+    /// refers to the required entity, using the keys of its data file, such
+    /// as `prerequisites.value[0].value.id.value`.
+    pub path: String,
+
+    /// What is required of the required entity, such as a level or value.
+    pub requirement: Requirement
+}
+
+/// An index of all entities that require a specific entity in their
+/// prerequisites, including prerequisites by level, prerequisites of select
+/// options and prerequisites that require one of several entities. It is
+/// provided by
+/// [OptolithData::dependent_index](crate::data::OptolithData::dependent_index).
+#[derive(Clone, Default)]
+pub struct DependentIndex {
+    dependents: HashMap<Id, Vec<Dependent>>
+}
+
+impl DependentIndex {
+    pub(crate) fn add<T>(&mut self, entity: &T)
+    where
+        T: Identifiable + References
+    {
+        let id = entity.id();
+
+        for reference in collect_references(entity) {
+            if let Reference {
+                path,
+                target,
+                requirement: Some(requirement)
+            } = reference {
+                self.dependents.entry(target).or_default().push(Dependent {
+                    id,
+                    path,
+                    requirement
+                });
+            }
+        }
+    }
+
+    /// Gets all entities that require the entity with the given ID, in the
+    /// order of their categories and IDs. An entity may be listed multiple
+    /// times if it requires the entity in multiple prerequisites.
+    pub fn dependents(&self, id: Id) -> &[Dependent] {
+        self.dependents.get(&id).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Indicates whether any entity requires the entity with the given ID.
+    pub fn has_dependents(&self, id: Id) -> bool {
+        self.dependents.contains_key(&id)
+    }
+}
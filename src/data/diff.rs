@@ -0,0 +1,254 @@
+//! A comparison of two versions of the data, such as two releases, by the IDs
+//! of their entities. For entities contained in both versions, the changed
+//! fields are determined from their serialized form, so changes in
+//! translations, AP values, prerequisites and source references are all
+//! reported the same way.
+
+use crate::data::{category_dir, Localization, OptolithData, Translatable};
+use crate::data::locale::LocaleChain;
+use crate::id::{Category, Id, Identifiable};
+
+use serde::Serialize;
+use serde_json::Value;
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+/// A field of an entity that differs between two versions of the data.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldChange {
+
+    /// The path of the field within the entity, such as
+    /// `translations.de-DE.name` or `prerequisites[2].level`.
+    pub path: String,
+
+    /// The value of the field in the old version, or `None` if it is absent.
+    pub old: Option<Value>,
+
+    /// The value of the field in the new version, or `None` if it is absent.
+    pub new: Option<Value>
+}
+
+/// A difference of a single entity between two versions of the data.
+#[derive(Clone, Debug, PartialEq)]
+pub enum EntityChange {
+
+    /// The entity with the given ID only exists in the new version.
+    Added(Id),
+
+    /// The entity with the given ID only exists in the old version.
+    Removed(Id),
+
+    /// The entity with the given ID exists in both versions, but the given
+    /// fields differ. Nested objects and lists of the same length are
+    /// compared field by field, so only the innermost changed fields are
+    /// listed.
+    Changed {
+        id: Id,
+        fields: Vec<FieldChange>
+    }
+}
+
+impl EntityChange {
+
+    /// The ID of the added, removed or changed entity.
+    pub fn id(&self) -> Id {
+        match self {
+            EntityChange::Added(id) => *id,
+            EntityChange::Removed(id) => *id,
+            EntityChange::Changed { id, .. } => *id
+        }
+    }
+}
+
+/// The number of added, removed and changed entities of a category.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ChangeSummary {
+    pub added: usize,
+    pub removed: usize,
+    pub changed: usize
+}
+
+/// All differences between two versions of the data. It is created by
+/// [OptolithData::diff].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DataDiff {
+    changes: Vec<EntityChange>
+}
+
+impl DataDiff {
+    pub(crate) fn compare<T>(&mut self, old: &BTreeMap<u32, T>,
+        new: &BTreeMap<u32, T>)
+    where
+        T: Identifiable + Serialize
+    {
+        let ids = old.keys().chain(new.keys()).collect::<BTreeSet<_>>();
+
+        for id in ids {
+            let change = match (old.get(id), new.get(id)) {
+                (Some(old), Some(new)) => {
+                    let mut fields = Vec::new();
+                    diff_values(&mut String::new(), Some(&to_value(old)),
+                        Some(&to_value(new)), &mut fields);
+
+                    if fields.is_empty() {
+                        continue;
+                    }
+
+                    EntityChange::Changed { id: new.id(), fields }
+                },
+                (Some(old), None) => EntityChange::Removed(old.id()),
+                (None, Some(new)) => EntityChange::Added(new.id()),
+                (None, None) => continue
+            };
+
+            self.changes.push(change);
+        }
+    }
+
+    /// All changes, in the order of their categories and IDs.
+    pub fn changes(&self) -> &[EntityChange] {
+        &self.changes
+    }
+
+    /// Indicates whether both versions contain the same entities.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Iterates over the changes of entities of the given category.
+    pub fn category_changes(&self, category: Category)
+            -> impl Iterator<Item = &EntityChange> {
+        self.changes.iter()
+            .filter(move |change| change.id().category() == category)
+    }
+
+    /// Counts the added, removed and changed entities of each category with
+    /// any changes.
+    pub fn summary(&self) -> BTreeMap<Category, ChangeSummary> {
+        let mut summary = BTreeMap::<_, ChangeSummary>::new();
+
+        for change in &self.changes {
+            let entry = summary.entry(change.id().category()).or_default();
+
+            match change {
+                EntityChange::Added(_) => entry.added += 1,
+                EntityChange::Removed(_) => entry.removed += 1,
+                EntityChange::Changed { .. } => entry.changed += 1
+            }
+        }
+
+        summary
+    }
+
+    /// Formats the changes as a human-readable changelog, which lists the
+    /// changes of each category after a line with the name of its directory
+    /// and their counts. Entities are named by their translations into the
+    /// best available locale of the given [LocaleChain] in the version they
+    /// occur in, or the newer one if they occur in both. `old` and `new` must
+    /// be the versions this diff was created from.
+    pub fn report(&self, old: &OptolithData, new: &OptolithData,
+            chain: &LocaleChain) -> String {
+        let summary = self.summary();
+        let mut report = String::new();
+
+        for (category, counts) in summary {
+            if !report.is_empty() {
+                report.push('\n');
+            }
+
+            writeln!(report, "{}: {} added, {} removed, {} changed",
+                category_dir(category), counts.added, counts.removed,
+                counts.changed).unwrap();
+
+            for change in self.category_changes(category) {
+                let (marker, data) = match change {
+                    EntityChange::Added(_) => ('+', new),
+                    EntityChange::Removed(_) => ('-', old),
+                    EntityChange::Changed { .. } => ('~', new)
+                };
+                let id = change.id();
+
+                match entity_name(data, id, chain) {
+                    Some(name) => writeln!(report, "  {} {} {}", marker,
+                        id.internal_id(), name),
+                    None => writeln!(report, "  {} {}", marker,
+                        id.internal_id())
+                }.unwrap();
+
+                if let EntityChange::Changed { fields, .. } = change {
+                    for field in fields {
+                        writeln!(report, "      {}: {} -> {}", field.path,
+                            format_value(&field.old),
+                            format_value(&field.new)).unwrap();
+                    }
+                }
+            }
+        }
+
+        report
+    }
+}
+
+fn to_value<T: Serialize>(entity: &T) -> Value {
+    // All entities have string keys only, so serializing cannot fail.
+    serde_json::to_value(entity).unwrap()
+}
+
+fn entity_name(data: &OptolithData, id: Id, chain: &LocaleChain)
+        -> Option<String> {
+    let entity = data.get_as_entity_ref(id)?;
+    let translation = entity.translate_negotiated(chain)?;
+    Some(translation.localization.display_name().into_owned())
+}
+
+fn format_value(value: &Option<Value>) -> String {
+    match value {
+        Some(value) => value.to_string(),
+        None => String::from("(none)")
+    }
+}
+
+/// Appends the changes between the given values at the given path to
+/// `changes`. `null` is treated like an absent value, since optional fields
+/// are serialized as `null`.
+fn diff_values(path: &mut String, old: Option<&Value>, new: Option<&Value>,
+        changes: &mut Vec<FieldChange>) {
+    let old = old.filter(|value| !value.is_null());
+    let new = new.filter(|value| !value.is_null());
+
+    match (old, new) {
+        (Some(Value::Object(old)), Some(Value::Object(new))) => {
+            let keys = old.keys().chain(new.keys()).collect::<BTreeSet<_>>();
+
+            for key in keys {
+                let len = path.len();
+
+                if !path.is_empty() {
+                    path.push('.');
+                }
+
+                path.push_str(key);
+                diff_values(path, old.get(key), new.get(key), changes);
+                path.truncate(len);
+            }
+        },
+        (Some(Value::Array(old)), Some(Value::Array(new)))
+                if old.len() == new.len() => {
+            for (index, (old, new)) in old.iter().zip(new).enumerate() {
+                let len = path.len();
+                write!(path, "[{}]", index).unwrap();
+                diff_values(path, Some(old), Some(new), changes);
+                path.truncate(len);
+            }
+        },
+        (old, new) if old != new => {
+            changes.push(FieldChange {
+                path: path.clone(),
+                old: old.cloned(),
+                new: new.cloned()
+            });
+        },
+        _ => { }
+    }
+}
@@ -1,9 +1,13 @@
 use crate::data::Localization;
-use crate::data::errata::Errata;
+use crate::data::errata::{Errata, Erratum};
 
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct LabelText {
 
@@ -15,6 +19,7 @@ pub struct LabelText {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct EffectsLocalization {
     pub name: String,
@@ -29,4 +34,8 @@ impl Localization for EffectsLocalization {
     fn name(&self) -> &str {
         &self.name
     }
+
+    fn errata(&self) -> &[Erratum] {
+        self.errata.as_deref().unwrap_or_default()
+    }
 }
@@ -1,17 +1,189 @@
-use crate::data::{Localization, Translations};
+use crate::data::{category_dir, Localization, Translations};
+use crate::id::{Category, Id};
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::Error as DeError;
 
+#[cfg(feature = "schema")]
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+#[cfg(feature = "schema")]
+use std::borrow::Cow;
+
+use std::error::Error;
+use std::fmt::{self, Display, Formatter, Write};
+use std::str::FromStr;
+
+/// A calendar date, which is written as `YYYY-MM-DD` in the data files.
+/// Dates are ordered chronologically.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Date {
+    year: u16,
+    month: u8,
+    day: u8
+}
+
+impl Date {
+
+    /// Creates a new date, or returns `None` if the given day does not exist
+    /// in the given month of the given year.
+    pub fn new(year: u16, month: u8, day: u8) -> Option<Date> {
+        let leap_year = year.is_multiple_of(4) &&
+            (!year.is_multiple_of(100) || year.is_multiple_of(400));
+        let days = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if leap_year => 29,
+            2 => 28,
+            _ => return None
+        };
+
+        if day == 0 || day > days {
+            return None;
+        }
+
+        Some(Date { year, month, day })
+    }
+
+    /// The year, such as 2021.
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// The month, from 1 (January) to 12 (December).
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// The day of the month, starting at 1.
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+}
+
+impl Display for Date {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// The error returned when parsing a [Date] from a string that is not a valid
+/// date in the format `YYYY-MM-DD`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseDateError {
+    text: String
+}
+
+impl Display for ParseDateError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid date \"{}\", expected YYYY-MM-DD", self.text)
+    }
+}
+
+impl Error for ParseDateError { }
+
+impl FromStr for Date {
+    type Err = ParseDateError;
+
+    fn from_str(s: &str) -> Result<Date, ParseDateError> {
+        let parts = s.split('-').collect::<Vec<_>>();
+
+        if let [year, month, day] = parts[..] {
+            let digits = |part: &str, len: usize|
+                part.len() == len && part.bytes().all(|b| b.is_ascii_digit());
+
+            if digits(year, 4) && digits(month, 2) && digits(day, 2) {
+                let date = Date::new(year.parse().unwrap(),
+                    month.parse().unwrap(), day.parse().unwrap());
+
+                if let Some(date) = date {
+                    return Ok(date);
+                }
+            }
+        }
+
+        Err(ParseDateError {
+            text: s.to_owned()
+        })
+    }
+}
+
+impl Serialize for Date {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Date {
+    fn deserialize<D>(deserializer: D) -> Result<Date, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        let text = String::deserialize(deserializer)?;
+        text.parse().map_err(D::Error::custom)
+    }
+}
+
+#[cfg(feature = "schema")]
+impl JsonSchema for Date {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("Date")
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "format": "date"
+        })
+    }
+}
+
+/// An erratum of an entity. A date that is not valid does not make loading
+/// the entity fail, instead the erratum is kept without a parsed date.
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Erratum {
-    pub date: String,
+    #[serde(rename = "date")]
+    #[cfg_attr(feature = "schema", schemars(with = "Date"))]
+    raw_date: String,
+
+    /// The description of the change, which may contain Markdown.
     pub description: String
 }
 
+impl Erratum {
+
+    /// Creates a new erratum with the given date, which should have the
+    /// format `YYYY-MM-DD`, and description.
+    pub fn new(raw_date: impl Into<String>, description: impl Into<String>)
+            -> Erratum {
+        Erratum {
+            raw_date: raw_date.into(),
+            description: description.into()
+        }
+    }
+
+    /// The date as written in the data file, which should have the format
+    /// `YYYY-MM-DD`.
+    pub fn raw_date(&self) -> &str {
+        &self.raw_date
+    }
+
+    /// The parsed date, or `None` if the date as written in the data file is
+    /// not a valid date.
+    pub fn date(&self) -> Option<Date> {
+        self.raw_date.parse().ok()
+    }
+}
+
 pub type Errata = Vec<Erratum>;
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct ErrataLocalization {
     pub name: String,
@@ -22,6 +194,148 @@ impl Localization for ErrataLocalization {
     fn name(&self) -> &str {
         &self.name
     }
+
+    fn errata(&self) -> &[Erratum] {
+        self.errata.as_deref().unwrap_or_default()
+    }
 }
 
 pub type ErrataTranslations = Translations<ErrataLocalization>;
+
+/// An erratum of an entity in an [ErrataFeed].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct ErrataEntry {
+
+    /// The ID of the entity the erratum refers to.
+    pub id: Id,
+
+    /// The name of the entity in the locale of the feed.
+    pub name: String,
+
+    /// The date as written in the data file.
+    pub raw_date: String,
+
+    /// The parsed date, or `None` if `raw_date` is not a valid date.
+    pub date: Option<Date>,
+
+    /// The description of the change as in the data file.
+    pub description: String
+}
+
+/// The errata of all entities in a single locale. It is created by
+/// [OptolithData::errata](crate::data::OptolithData::errata) and can be
+/// narrowed down and reordered before it is exported.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ErrataFeed {
+    entries: Vec<ErrataEntry>
+}
+
+impl ErrataFeed {
+    pub(crate) fn add(&mut self, id: Id, localization: &impl Localization) {
+        for erratum in localization.errata() {
+            self.entries.push(ErrataEntry {
+                id,
+                name: localization.display_name().into_owned(),
+                raw_date: erratum.raw_date.clone(),
+                date: erratum.date(),
+                description: erratum.description.clone()
+            });
+        }
+    }
+
+    /// The entries of this feed in their current order.
+    pub fn entries(&self) -> &[ErrataEntry] {
+        &self.entries
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Retains only the errata from the given date onwards. Errata without a
+    /// valid date are removed.
+    pub fn since(self, date: Date) -> ErrataFeed {
+        self.filter(|entry| entry.date.is_some_and(|d| d >= date))
+    }
+
+    /// Retains only the errata between the given dates, both inclusive.
+    /// Errata without a valid date are removed.
+    pub fn between(self, from: Date, to: Date) -> ErrataFeed {
+        self.filter(|entry| entry.date.is_some_and(|d| d >= from && d <= to))
+    }
+
+    /// Retains only the errata without a valid date, which allows reporting
+    /// malformed dates in the data files.
+    pub fn undated(self) -> ErrataFeed {
+        self.filter(|entry| entry.date.is_none())
+    }
+
+    /// Retains only the errata of entities of the given categories.
+    pub fn in_categories(self, categories: &[Category]) -> ErrataFeed {
+        self.filter(|entry| categories.contains(&entry.id.category()))
+    }
+
+    /// Retains only the entries for which the given predicate holds.
+    pub fn filter(mut self, predicate: impl FnMut(&ErrataEntry) -> bool)
+            -> ErrataFeed {
+        self.entries.retain(predicate);
+        self
+    }
+
+    /// Sorts the entries by date, oldest first, after those without a valid
+    /// date. Entries with the same date keep their order, which is initially
+    /// that of their categories and IDs.
+    pub fn oldest_first(mut self) -> ErrataFeed {
+        self.entries.sort_by_key(|entry| entry.date);
+        self
+    }
+
+    /// Sorts the entries by date, newest first, before those without a valid
+    /// date. Entries with the same date keep their order, which is initially
+    /// that of their categories and IDs.
+    pub fn newest_first(mut self) -> ErrataFeed {
+        self.entries.sort_by_key(|entry| std::cmp::Reverse(entry.date));
+        self
+    }
+
+    /// Formats the entries as a Markdown list, with one heading per date as
+    /// written in the data files in the order in which the dates first
+    /// occur. Each entry names the directory of its category.
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = String::new();
+        let mut dates = Vec::new();
+
+        for entry in &self.entries {
+            if !dates.contains(&&entry.raw_date) {
+                dates.push(&entry.raw_date);
+            }
+        }
+
+        for date in dates {
+            if !markdown.is_empty() {
+                markdown.push('\n');
+            }
+
+            writeln!(markdown, "## {}\n", date).unwrap();
+
+            for entry in self.entries.iter().filter(|e| &e.raw_date == date) {
+                writeln!(markdown, "- **{}** ({}): {}", entry.name,
+                    category_dir(entry.id.category()), entry.description)
+                    .unwrap();
+            }
+        }
+
+        markdown
+    }
+
+    /// Formats the entries as a JSON array of objects with the fields of
+    /// [ErrataEntry], in which parsed dates are strings like `2021-03-01`
+    /// and missing ones are `null`.
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(&self.entries)
+    }
+}
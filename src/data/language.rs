@@ -1,12 +1,16 @@
 use crate::data::{Localization, TranslationsTranslatable, Translations};
-use crate::data::errata::Errata;
+use crate::data::errata::{Errata, Erratum};
 use crate::data::prerequisite::LanguageListOrByLevelPrerequisite;
 use crate::data::src::SourceRefs;
 use crate::id::{Category, Id, Identifiable};
 
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct LanguageSpecializationLocalization {
 
@@ -19,6 +23,7 @@ pub struct LanguageSpecializationLocalization {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct LanguageSpecialization {
     pub id: u32,
@@ -26,6 +31,7 @@ pub struct LanguageSpecialization {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct LanguageLocalization {
     pub name: String,
@@ -52,9 +58,25 @@ impl Localization for LanguageLocalization {
     fn name(&self) -> &str {
         &self.name
     }
+
+    fn alternative_names(&self) -> Vec<&str> {
+        self.alternative_names.iter()
+            .flatten()
+            .map(String::as_str)
+            .collect()
+    }
+
+    fn texts(&self) -> Vec<&str> {
+        self.description.iter().map(String::as_str).collect()
+    }
+
+    fn errata(&self) -> &[Erratum] {
+        self.errata.as_deref().unwrap_or_default()
+    }
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Continent {
     pub id: u32,
@@ -65,6 +87,7 @@ pub struct Continent {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Language {
     pub id: u32,
@@ -98,12 +121,14 @@ impl Identifiable for Language {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct AssociatedLanguage {
     pub id: u32
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct ScriptLocalization {
 
@@ -123,9 +148,21 @@ impl Localization for ScriptLocalization {
     fn name(&self) -> &str {
         &self.name
     }
+
+    fn alternative_names(&self) -> Vec<&str> {
+        self.alternative_names.iter()
+            .flatten()
+            .map(String::as_str)
+            .collect()
+    }
+
+    fn errata(&self) -> &[Erratum] {
+        self.errata.as_deref().unwrap_or_default()
+    }
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Script {
     pub id: u32,
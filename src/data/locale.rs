@@ -0,0 +1,108 @@
+//! Negotiation of the locale of translations, for cases where the requested
+//! locale is not available.
+
+/// A list of locales in the order of preference, such as
+/// `nl-BE, nl-NL, en-US, de-DE`. It selects the best available locale for a
+/// translation, see [LocaleChain::negotiate].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LocaleChain {
+    locales: Vec<String>
+}
+
+impl LocaleChain {
+
+    /// Creates a new chain with the given locales, most preferred first.
+    pub fn new<S>(locales: impl IntoIterator<Item = S>) -> LocaleChain
+    where
+        S: Into<String>
+    {
+        LocaleChain {
+            locales: locales.into_iter().map(Into::into).collect()
+        }
+    }
+
+    /// The locales of this chain, most preferred first.
+    pub fn locales(&self) -> &[String] {
+        &self.locales
+    }
+
+    /// Returns a chain which tries the given locale after all locales of this
+    /// chain.
+    pub fn then(mut self, locale: impl Into<String>) -> LocaleChain {
+        self.locales.push(locale.into());
+        self
+    }
+
+    /// Indicates whether the given locale matches the most preferred locale
+    /// of this chain as described in [LocaleChain::negotiate], i.e. whether a
+    /// translation into it is no fallback. For example, `de-DE` matches the
+    /// preferred locale `de-AT`, but `en-US` does not.
+    pub fn is_preferred(&self, locale: &str) -> bool {
+        self.locales.first()
+            .map(|preferred| negotiate(preferred, &[locale]).is_some())
+            .unwrap_or(false)
+    }
+
+    /// Selects the best of the given available locales. The locales of the
+    /// chain are tried in order, and for each of them, these matches are
+    /// tried in order, ignoring case:
+    ///
+    /// 1. An available locale equal to it.
+    /// 2. An available locale equal to a prefix of it according to BCP 47,
+    ///    e.g. `de-CH` or `de` for `de-CH-1996`.
+    /// 3. The first available locale with the same primary language subtag,
+    ///    e.g. `de-DE` for `de-AT` or `en-US` for `en`.
+    ///
+    /// Only if none of these exist, the next locale of the chain is tried.
+    /// Returns `None` if none of the locales of the chain matches.
+    pub fn negotiate<'l>(&self, available: impl IntoIterator<Item = &'l str>)
+            -> Option<&'l str> {
+        let available = available.into_iter().collect::<Vec<_>>();
+
+        self.locales.iter().find_map(|locale| negotiate(locale, &available))
+    }
+}
+
+fn negotiate<'l>(locale: &str, available: &[&'l str]) -> Option<&'l str> {
+    let find = |tag: &str| available.iter()
+        .find(|a| a.eq_ignore_ascii_case(tag))
+        .copied();
+    let mut tag = locale;
+
+    loop {
+        if let Some(found) = find(tag) {
+            return Some(found);
+        }
+
+        match tag.rfind('-') {
+            Some(index) => tag = &tag[..index],
+            None => break
+        }
+    }
+
+    available.iter()
+        .find(|a| primary_language(a).eq_ignore_ascii_case(tag))
+        .copied()
+}
+
+/// Gets the primary language subtag of the given locale, such as `de` for
+/// `de-DE`.
+fn primary_language(locale: &str) -> &str {
+    locale.split('-').next().unwrap()
+}
+
+/// A translation together with the locale it was selected for by a
+/// [LocaleChain].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Translation<L> {
+
+    /// The localization of the entity.
+    pub localization: L,
+
+    /// The name of the locale that was used, as it is stored in the data.
+    pub locale: String,
+
+    /// Whether the locale does not match the most preferred one of the chain,
+    /// i.e. whether the entity is not translated into its language.
+    pub is_fallback: bool
+}
@@ -0,0 +1,381 @@
+//! Rendering of the Markdown in localized texts, such as rules and effects, to
+//! sanitized HTML or plain text.
+//!
+//! Links whose destination has the form `<Directory>/<ID>`, such as
+//! `[](Spells/12)`, refer to the entity with that ID in the category stored in
+//! that directory. Without a link text, they are labelled with the localized
+//! name of the entity. In HTML, they become links to the fragment
+//! `#<Directory>/<ID>` with the class `entity`, so applications can intercept
+//! them. Other links are only kept if they use the `http`, `https` or
+//! `mailto` scheme. Embedded HTML is escaped and images are replaced by their
+//! alternative text.
+
+use crate::data::{dir_category, Localization, OptolithData, Translatable};
+use crate::data::locale::LocaleChain;
+use crate::id::Id;
+
+use pulldown_cmark::{Event, Options, Parser, Tag};
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum Format {
+    Html,
+    PlainText
+}
+
+enum Link {
+    Entity {
+        destination: String,
+        name: Option<String>
+    },
+    External(Option<String>),
+    Image
+}
+
+/// The state of rendering a single text.
+struct Renderer<'r> {
+    format: Format,
+    markdown: &'r MarkdownRenderer<'r>,
+    out: String,
+
+    /// The links that are currently open together with the output before
+    /// them, since their content is only known at their end.
+    links: Vec<(Link, String)>,
+
+    /// The next numbers of the items of the currently open lists, or `None`
+    /// for bullet lists.
+    lists: Vec<Option<u64>>,
+    in_table_head: bool
+}
+
+impl<'r> Renderer<'r> {
+    fn text(&mut self, text: &str) {
+        match self.format {
+            Format::Html => escape(&mut self.out, text),
+            Format::PlainText => self.out.push_str(text)
+        }
+    }
+
+    fn html(&mut self, html: &str) {
+        if self.format == Format::Html {
+            self.out.push_str(html);
+        }
+    }
+
+    /// Writes the escaped value of an HTML attribute, which is omitted in
+    /// plain text.
+    fn attribute(&mut self, value: &str) {
+        if self.format == Format::Html {
+            escape(&mut self.out, value);
+        }
+    }
+
+    /// Ends a block in plain text, so that it is separated from the next one
+    /// by an empty line.
+    fn end_block(&mut self) {
+        if self.format == Format::PlainText {
+            while !self.out.is_empty() && !self.out.ends_with("\n\n") {
+                self.out.push('\n');
+            }
+        }
+    }
+
+    fn start(&mut self, tag: Tag<'_>) {
+        match tag {
+            Tag::Paragraph => self.html("<p>"),
+            Tag::Heading(level, _, _) =>
+                self.html(&format!("<h{}>", level as usize)),
+            Tag::BlockQuote => self.html("<blockquote>\n"),
+            Tag::CodeBlock(_) => self.html("<pre><code>"),
+            Tag::List(start) => {
+                match start {
+                    Some(start) if start != 1 =>
+                        self.html(&format!("<ol start=\"{}\">\n", start)),
+                    Some(_) => self.html("<ol>\n"),
+                    None => self.html("<ul>\n")
+                }
+
+                if self.format == Format::PlainText &&
+                        !self.out.is_empty() && !self.out.ends_with('\n') {
+                    self.out.push('\n');
+                }
+
+                self.lists.push(start);
+            },
+            Tag::Item => {
+                self.html("<li>");
+
+                if self.format == Format::PlainText {
+                    let depth = self.lists.len().saturating_sub(1);
+                    let marker = match self.lists.last_mut() {
+                        Some(Some(number)) => {
+                            *number += 1;
+                            format!("{}. ", *number - 1)
+                        },
+                        _ => String::from("- ")
+                    };
+
+                    self.out.push_str(&"  ".repeat(depth));
+                    self.out.push_str(&marker);
+                }
+            },
+            Tag::Table(_) => self.html("<table>\n"),
+            Tag::TableHead => {
+                self.in_table_head = true;
+                self.html("<thead><tr>");
+            },
+            Tag::TableRow => self.html("<tr>"),
+            Tag::TableCell if self.in_table_head => self.html("<th>"),
+            Tag::TableCell => self.html("<td>"),
+            Tag::Emphasis => self.html("<em>"),
+            Tag::Strong => self.html("<strong>"),
+            Tag::Strikethrough => self.html("<del>"),
+            Tag::Link(_, destination, _) => {
+                let link = self.markdown.link(&destination);
+                let outer = std::mem::take(&mut self.out);
+                self.links.push((link, outer));
+            },
+            Tag::Image(_, _, _) => {
+                let outer = std::mem::take(&mut self.out);
+                self.links.push((Link::Image, outer));
+            },
+            Tag::FootnoteDefinition(_) => { }
+        }
+    }
+
+    fn end(&mut self, tag: Tag<'_>) {
+        match tag {
+            Tag::Paragraph => {
+                self.html("</p>\n");
+                self.end_block();
+            },
+            Tag::Heading(level, _, _) => {
+                self.html(&format!("</h{}>\n", level as usize));
+                self.end_block();
+            },
+            Tag::BlockQuote => {
+                self.html("</blockquote>\n");
+                self.end_block();
+            },
+            Tag::CodeBlock(_) => {
+                self.html("</code></pre>\n");
+                self.end_block();
+            },
+            Tag::List(start) => {
+                self.html(if start.is_some() { "</ol>\n" } else { "</ul>\n" });
+                self.lists.pop();
+
+                if self.lists.is_empty() {
+                    self.end_block();
+                }
+            },
+            Tag::Item => {
+                self.html("</li>\n");
+
+                if self.format == Format::PlainText &&
+                        !self.out.ends_with('\n') {
+                    self.out.push('\n');
+                }
+            },
+            Tag::Table(_) => {
+                self.html("</tbody></table>\n");
+                self.end_block();
+            },
+            Tag::TableHead => {
+                self.in_table_head = false;
+                self.html("</tr></thead><tbody>\n");
+
+                if self.format == Format::PlainText {
+                    self.out.push('\n');
+                }
+            },
+            Tag::TableRow => {
+                self.html("</tr>\n");
+
+                if self.format == Format::PlainText {
+                    self.out.push('\n');
+                }
+            },
+            Tag::TableCell => {
+                self.html(if self.in_table_head { "</th>" } else { "</td>" });
+
+                if self.format == Format::PlainText {
+                    self.out.push('\t');
+                }
+            },
+            Tag::Emphasis => self.html("</em>"),
+            Tag::Strong => self.html("</strong>"),
+            Tag::Strikethrough => self.html("</del>"),
+            Tag::Link(_, _, _) | Tag::Image(_, _, _) => self.end_link(),
+            Tag::FootnoteDefinition(_) => { }
+        }
+    }
+
+    fn end_link(&mut self) {
+        let (link, outer) = match self.links.pop() {
+            Some(link) => link,
+            None => return
+        };
+        let content = std::mem::replace(&mut self.out, outer);
+
+        match link {
+            Link::Entity { destination, name } => {
+                let has_content = !content.trim().is_empty();
+
+                if name.is_some() {
+                    self.html("<a class=\"entity\" href=\"#");
+                    self.attribute(&destination);
+                    self.html("\">");
+                }
+
+                match (has_content, &name) {
+                    (true, _) => self.out.push_str(&content),
+                    (false, Some(name)) => self.text(name),
+                    (false, None) => self.text(&destination)
+                }
+
+                if name.is_some() {
+                    self.html("</a>");
+                }
+            },
+            Link::External(Some(href)) => {
+                self.html("<a href=\"");
+                self.attribute(&href);
+                self.html("\">");
+                self.out.push_str(&content);
+                self.html("</a>");
+            },
+            Link::External(None) | Link::Image => self.out.push_str(&content)
+        }
+    }
+
+    fn event(&mut self, event: Event<'_>) {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) => self.text(&text),
+            Event::Code(code) => {
+                self.html("<code>");
+                self.text(&code);
+                self.html("</code>");
+            },
+            Event::Html(html) => self.text(&html),
+            Event::SoftBreak => self.out.push(match self.format {
+                Format::Html => '\n',
+                Format::PlainText => ' '
+            }),
+            Event::HardBreak => match self.format {
+                Format::Html => self.out.push_str("<br>\n"),
+                Format::PlainText => self.out.push('\n')
+            },
+            Event::Rule => {
+                self.html("<hr>\n");
+                self.end_block();
+            },
+            Event::FootnoteReference(_) | Event::TaskListMarker(_) => { }
+        }
+    }
+}
+
+/// Appends the given text to the given HTML with all characters that have a
+/// special meaning in HTML escaped.
+fn escape(out: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            c => out.push(c)
+        }
+    }
+}
+
+/// Renders Markdown texts from the data to HTML or plain text, resolving
+/// links to entities to their names in the best available locale of a
+/// [LocaleChain]. It is created by
+/// [OptolithData::markdown](crate::data::OptolithData::markdown). See the
+/// [module documentation](self) for the supported links.
+pub struct MarkdownRenderer<'a> {
+    data: &'a OptolithData,
+    chain: LocaleChain
+}
+
+impl<'a> MarkdownRenderer<'a> {
+    pub(crate) fn new(data: &'a OptolithData, chain: LocaleChain)
+            -> MarkdownRenderer<'a> {
+        MarkdownRenderer {
+            data,
+            chain
+        }
+    }
+
+    /// Renders the given Markdown to HTML. The result is safe to embed into
+    /// a web page.
+    pub fn to_html(&self, markdown: &str) -> String {
+        self.render(markdown, Format::Html)
+    }
+
+    /// Renders the given Markdown to plain text without any formatting. Blocks
+    /// are separated by empty lines, list items are put on lines of their own
+    /// and table cells are separated by tabs.
+    pub fn to_plain_text(&self, markdown: &str) -> String {
+        self.render(markdown, Format::PlainText)
+    }
+
+    fn render(&self, markdown: &str, format: Format) -> String {
+        let options = Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH;
+        let mut renderer = Renderer {
+            format,
+            markdown: self,
+            out: String::with_capacity(markdown.len()),
+            links: Vec::new(),
+            lists: Vec::new(),
+            in_table_head: false
+        };
+
+        for event in Parser::new_ext(markdown, options) {
+            renderer.event(event);
+        }
+
+        while !renderer.links.is_empty() {
+            renderer.end_link();
+        }
+
+        let len = renderer.out.trim_end().len();
+        renderer.out.truncate(len);
+        renderer.out
+    }
+
+    /// Gets the ID of the entity the given link destination refers to, if it
+    /// has the form `<Directory>/<ID>`.
+    fn entity_id(destination: &str) -> Option<Id> {
+        let (dir, id) = destination.split_once('/')?;
+        let category = dir_category(dir)?;
+        let id = id.parse().ok()?;
+
+        Some(Id::new(category, id))
+    }
+
+    fn link(&self, destination: &str) -> Link {
+        if let Some(id) = MarkdownRenderer::entity_id(destination) {
+            return Link::Entity {
+                destination: destination.to_owned(),
+                name: self.entity_name(id)
+            };
+        }
+
+        let lower = destination.to_ascii_lowercase();
+        let is_safe = ["http://", "https://", "mailto:"].iter()
+            .any(|scheme| lower.starts_with(scheme));
+
+        Link::External(Some(destination.to_owned()).filter(|_| is_safe))
+    }
+
+    fn entity_name(&self, id: Id) -> Option<String> {
+        let entity = self.data.get_as_entity_ref(id)?;
+        let translation = entity.translate_negotiated(&self.chain)?;
+
+        Some(translation.localization.display_name().into_owned())
+    }
+}
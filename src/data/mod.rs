@@ -78,11 +78,22 @@ use crate::data::activatable::special_ability::{
 };
 use crate::data::aspect::Aspect;
 use crate::data::attribute::Attribute;
+use crate::data::citation::CitationFormatter;
+use crate::data::dependents::DependentIndex;
 use crate::data::derived_characteristic::DerivedCharacteristic;
-use crate::data::entity::Entity;
+use crate::data::diff::DataDiff;
+use crate::data::errata::{ErrataFeed, Erratum};
+use crate::data::locale::{LocaleChain, Translation};
+
+#[cfg(feature = "markdown")]
+use crate::data::markdown::MarkdownRenderer;
+
+use crate::data::entity::{Entity, EntityRef};
 use crate::data::experience_level::ExperienceLevel;
 use crate::data::item::{EquipmentPackage, ItemGroup};
 use crate::data::language::{Language, Script};
+use crate::data::options::LoadOptions;
+use crate::data::overlay::{Origins, OverlayLoader, OverlayReport};
 use crate::data::package::culture::Culture;
 use crate::data::package::profession::Profession;
 use crate::data::patron::{Patron, PatronCategory};
@@ -139,27 +150,256 @@ use crate::data::status_effect::{
     Poison,
     State
 };
-use crate::id::{Category, Id, Identifiable};
-use crate::error::OptolithDataResult;
+use crate::data::search::{SearchIndex, SearchOptions};
+use crate::data::ui::Messages;
+use crate::data::validation::DanglingReference;
+use crate::data::view::Sourced;
+use crate::cache;
+use crate::id::{Category, CategoryProvider, Id, Identifiable};
+use crate::error::{self, OptolithDataError, OptolithDataResult};
+use crate::source::{self, DataSource, FileSystem};
 use crate::util;
 
 use serde::{Deserialize, Serialize};
-
-use std::collections::HashMap;
-use std::fs::DirEntry;
-use std::hash::Hash;
+use serde_yaml::{Mapping, Value};
+
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+
+#[cfg(feature = "parallel")]
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+use std::borrow::Cow;
+use std::collections::{btree_map, BTreeMap, HashSet};
+use std::path::Path;
+use std::sync::{Mutex, OnceLock};
+
+/// The registry of all categories that are loaded into an [OptolithData]. It
+/// invokes the given macro with the list of categories appended to the given
+/// arguments. Each entry has the form
+/// `field: Entity = (CategoryVariant, "DirectoryName")`. Adding an entry
+/// suffices to add a category to the data. It is defined before the
+/// submodules, so they can use it as well.
+macro_rules! with_categories {
+    ($callback:ident!($($args:tt)*)) => {
+        $callback! { $($args)* {
+            advanced_combat_special_abilities: AdvancedCombatSpecialAbility =
+                (AdvancedCombatSpecialAbilities,
+                    "AdvancedCombatSpecialAbilities"),
+            advanced_karma_special_abilities: AdvancedKarmaSpecialAbility =
+                (AdvancedKarmaSpecialAbilities,
+                    "AdvancedKarmaSpecialAbilities"),
+            advanced_magical_special_abilities: AdvancedMagicalSpecialAbility =
+                (AdvancedMagicalSpecialAbilities,
+                    "AdvancedMagicalSpecialAbilities"),
+            advanced_skill_special_abilities: AdvancedSkillSpecialAbility =
+                (AdvancedSkillSpecialAbilities,
+                    "AdvancedSkillSpecialAbilities"),
+            advantages: Advantage = (Advantages, "Advantages"),
+            ancestor_glyphs: AncestorGlyph = (AncestorGlyphs, "AncestorGlyphs"),
+            animal_diseases: AnimalDisease = (AnimalDiseases, "AnimalDiseases"),
+            animal_shapes: AnimalShape = (AnimalShapes, "AnimalShapes"),
+            animal_shape_paths: AnimalShapePath =
+                (AnimalShapePaths, "AnimalShapePaths"),
+            animal_shape_sizes: AnimalShapeSize =
+                (AnimalShapeSizes, "AnimalShapeSizes"),
+            animal_types: AnimalType = (AnimalTypes, "AnimalTypes"),
+            animist_powers: AnimistPower = (AnimistPowers, "AnimistPowers"),
+            arcane_bard_traditions: ArcaneBardTradition =
+                (ArcaneBardTraditions, "ArcaneBardTraditions"),
+            arcane_dancer_traditions: ArcaneDancerTradition =
+                (ArcaneDancerTraditions, "ArcaneDancerTraditions"),
+            arcane_orb_enchantments: ArcaneOrbEnchantment =
+                (ArcaneOrbEnchantments, "ArcaneOrbEnchantments"),
+            armor_types: ArmorType = (ArmorTypes, "ArmorTypes"),
+            aspects: Aspect = (Aspects, "Aspects"),
+            attire_enchantments: AttireEnchantment =
+                (AttireEnchantments, "AttireEnchantments"),
+            attributes: Attribute = (Attributes, "Attributes"),
+            blessed_traditions: BlessedTradition =
+                (BlessedTraditions, "BlessedTraditions"),
+            blessings: Blessing = (Blessings, "Blessings"),
+            bowl_enchantments: BowlEnchantment =
+                (BowlEnchantments, "BowlEnchantments"),
+            brawling_special_abilities: BrawlingSpecialAbility =
+                (BrawlingSpecialAbilities, "BrawlingSpecialAbilities"),
+            brews: Brew = (Brews, "Brews"),
+            cantrips: Cantrip = (Cantrips, "Cantrips"),
+            cauldron_enchantments: CauldronEnchantment =
+                (CauldronEnchantments, "CauldronEnchantments"),
+            ceremonial_item_special_abilities: CeremonialItemSpecialAbility =
+                (CeremonialItemSpecialAbilities,
+                    "CeremonialItemSpecialAbilities"),
+            ceremonies: Ceremony = (Ceremonies, "Ceremonies"),
+            chronicle_enchantments: ChronicleEnchantment =
+                (ChronicleEnchantments, "ChronicleEnchantments"),
+            combat_special_abilities: CombatSpecialAbility =
+                (CombatSpecialAbilities, "CombatSpecialAbilities"),
+            combat_special_ability_groups: CombatSpecialAbilityGroup =
+                (CombatSpecialAbilityGroups, "CombatSpecialAbilityGroups"),
+            combat_style_special_abilities: CombatStyleSpecialAbility =
+                (CombatStyleSpecialAbilities, "CombatStyleSpecialAbilities"),
+            combat_technique_groups: CombatTechniqueGroup =
+                (CombatTechniqueGroups, "CombatTechniqueGroups"),
+            command_special_abilities: CommandSpecialAbility =
+                (CommandSpecialAbilities, "CommandSpecialAbilities"),
+            conditions: Condition = (Conditions, "Conditions"),
+            cultures: Culture = (Cultures, "Cultures"),
+            curricula: Curriculum = (Curricula, "Curricula"),
+            curses: Curse = (Curses, "Curses"),
+            dagger_rituals: DaggerRitual = (DaggerRituals, "DaggerRituals"),
+            derived_characteristics: DerivedCharacteristic =
+                (DerivedCharacteristics, "DerivedCharacteristics"),
+            disadvantages: Disadvantage = (Disadvantages, "Disadvantages"),
+            diseases: Disease = (Diseases, "Diseases"),
+            domination_rituals: DominationRitual =
+                (DominationRituals, "DominationRituals"),
+            elements: Element = (Elements, "Elements"),
+            elven_magical_songs: ElvenMagicalSong =
+                (ElvenMagicalSongs, "ElvenMagicalSongs"),
+            equipment_packages: EquipmentPackage =
+                (EquipmentPackages, "EquipmentPackages"),
+            experience_levels: ExperienceLevel =
+                (ExperienceLevels, "ExperienceLevels"),
+            eye_colors: EyeColor = (EyeColors, "EyeColors"),
+            familiar_special_abilities: FamiliarSpecialAbility =
+                (FamiliarSpecialAbilities, "FamiliarSpecialAbilities"),
+            familiars_tricks: FamiliarsTrick =
+                (FamiliarsTricks, "FamiliarsTricks"),
+            fate_point_sex_special_abilities: FatePointSexSpecialAbility =
+                (FatePointSexSpecialAbilities, "FatePointSexSpecialAbilities"),
+            fate_point_special_abilities: FatePointSpecialAbility =
+                (FatePointSpecialAbilities, "FatePointSpecialAbilities"),
+            focus_rules: FocusRule = (FocusRules, "FocusRules"),
+            fools_hat_enchantments: FoolsHatEnchantment =
+                (FoolsHatEnchantments, "FoolsHatEnchantments"),
+            general_special_abilities: GeneralSpecialAbility =
+                (GeneralSpecialAbilities, "GeneralSpecialAbilities"),
+            geode_rituals: GeodeRitual = (GeodeRituals, "GeodeRituals"),
+            guidelines: Guideline = (Guidelines, "Guidelines"),
+            hair_colors: HairColor = (HairColors, "HairColors"),
+            influences: Influence = (Influences, "Influences"),
+            instrument_enchantments: InstrumentEnchantment =
+                (InstrumentEnchantments, "InstrumentEnchantments"),
+            item_groups: ItemGroup = (ItemGroups, "ItemGroups"),
+            jester_tricks: JesterTrick = (JesterTricks, "JesterTricks"),
+            karma_special_abilities: KarmaSpecialAbility =
+                (KarmaSpecialAbilities, "KarmaSpecialAbilities"),
+            krallenkettenzauber: Krallenkettenzauber =
+                (Krallenkettenzauber, "Krallenkettenzauber"),
+            languages: Language = (Languages, "Languages"),
+            liturgical_chants: LiturgicalChant =
+                (LiturgicalChants, "LiturgicalChants"),
+            liturgical_chant_groups: LiturgicalChantGroup =
+                (LiturgicalChantGroups, "LiturgicalChantGroups"),
+            liturgical_style_special_abilities: LiturgicalStyleSpecialAbility =
+                (LiturgicalStyleSpecialAbilities,
+                    "LiturgicalStyleSpecialAbilities"),
+            lycantropic_gifts: LycantropicGift =
+                (LycantropicGifts, "LycantropicGifts"),
+            magical_dances: MagicalDance = (MagicalDances, "MagicalDances"),
+            magical_melodies: MagicalMelody =
+                (MagicalMelodies, "MagicalMelodies"),
+            magical_runes: MagicalRune = (MagicalRunes, "MagicalRunes"),
+            magical_special_abilities: MagicalSpecialAbility =
+                (MagicalSpecialAbilities, "MagicalSpecialAbilities"),
+            magical_traditions: MagicalTradition =
+                (MagicalTraditions, "MagicalTraditions"),
+            magical_tradition_placeholders: MagicalTraditionPlaceholder =
+                (MagicalTraditionPlaceholders, "MagicalTraditionPlaceholders"),
+            magic_style_special_abilities: MagicStyleSpecialAbility =
+                (MagicStyleSpecialAbilities, "MagicStyleSpecialAbilities"),
+            melee_combat_techniques: MeleeCombatTechnique =
+                (MeleeCombatTechniques, "MeleeCombatTechniques"),
+            optional_rules: OptionalRule = (OptionalRules, "OptionalRules"),
+            orb_enchantments: OrbEnchantment =
+                (OrbEnchantments, "OrbEnchantments"),
+            pact_categories: PactCategory = (PactCategories, "PactCategories"),
+            pact_gifts: PactGift = (PactGifts, "PactGifts"),
+            patrons: Patron = (Patrons, "Patrons"),
+            patron_categories: PatronCategory =
+                (PatronCategories, "PatronCategories"),
+            personality_traits: PersonalityTrait =
+                (PersonalityTraits, "PersonalityTraits"),
+            poisons: Poison = (Poisons, "Poisons"),
+            professions: Profession = (Professions, "Professions"),
+            properties: Property = (Properties, "Properties"),
+            protective_warding_circle_special_abilities:
+                ProtectiveWardingCircleSpecialAbility =
+                (ProtectiveWardingCircleSpecialAbilities,
+                    "ProtectiveWardingCircleSpecialAbilities"),
+            publications: Publication = (Publications, "Publications"),
+            races: Race = (Races, "Races"),
+            ranged_combat_techniques: RangedCombatTechnique =
+                (RangedCombatTechniques, "RangedCombatTechniques"),
+            reaches: Reach = (Reaches, "Reaches"),
+            regions: Region = (Regions, "Regions"),
+            ring_enchantments: RingEnchantment =
+                (RingEnchantments, "RingEnchantments"),
+            rituals: Ritual = (Rituals, "Rituals"),
+            scripts: Script = (Scripts, "Scripts"),
+            sermons: Sermon = (Sermons, "Sermons"),
+            services: Service = (Services, "Services"),
+            sex_practices: SexPractice = (SexPractices, "SexPractices"),
+            sex_special_abilities: SexSpecialAbility =
+                (SexSpecialAbilities, "SexSpecialAbilities"),
+            sickle_rituals: SickleRitual = (SickleRituals, "SickleRituals"),
+            sikaryan_drain_special_abilities: SikaryanDrainSpecialAbility =
+                (SikaryanDrainSpecialAbilities,
+                    "SikaryanDrainSpecialAbilities"),
+            skills: Skill = (Skills, "Skills"),
+            skill_groups: SkillGroup = (SkillGroups, "SkillGroups"),
+            skill_style_special_abilities: SkillStyleSpecialAbility =
+                (SkillStyleSpecialAbilities, "SkillStyleSpecialAbilities"),
+            social_statuses: SocialStatus = (SocialStatuses, "SocialStatuses"),
+            special_ability_groups: SpecialAbilityGroup =
+                (SpecialAbilityGroups, "SpecialAbilityGroups"),
+            spells: Spell = (Spells, "Spells"),
+            spell_groups: SpellGroup = (SpellGroups, "SpellGroups"),
+            spell_sword_enchantments: SpellSwordEnchantment =
+                (SpellSwordEnchantments, "SpellSwordEnchantments"),
+            staff_enchantments: StaffEnchantment =
+                (StaffEnchantments, "StaffEnchantments"),
+            states: State = (States, "States"),
+            subjects: Subject = (Subjects, "Subjects"),
+            toy_enchantments: ToyEnchantment =
+                (ToyEnchantments, "ToyEnchantments"),
+            trade_secrets: TradeSecret = (TradeSecrets, "TradeSecrets"),
+            tribes: Tribe = (Tribes, "Tribes"),
+            trinkhornzauber: Trinkhornzauber =
+                (Trinkhornzauber, "Trinkhornzauber"),
+            vampiric_gifts: VampiricGift = (VampiricGifts, "VampiricGifts"),
+            visions: Vision = (Visions, "Visions"),
+            wand_enchantments: WandEnchantment =
+                (WandEnchantments, "WandEnchantments"),
+            weapon_enchantments: WeaponEnchantment =
+                (WeaponEnchantments, "WeaponEnchantments"),
+            zibilja_rituals: ZibiljaRitual = (ZibiljaRituals, "ZibiljaRituals")
+        } }
+    };
+}
 
 pub mod academy;
 pub mod activatable;
 pub mod aspect;
 pub mod attribute;
+pub mod citation;
+pub mod dependents;
 pub mod derived_characteristic;
+pub mod diff;
 pub mod effects_localization;
 pub mod entity;
 pub mod errata;
 pub mod experience_level;
 pub mod item;
 pub mod language;
+pub mod locale;
+
+#[cfg(feature = "markdown")]
+pub mod markdown;
+
+pub mod options;
+pub mod overlay;
 pub mod package;
 pub mod patron;
 pub mod personality_trait;
@@ -168,656 +408,900 @@ pub mod property;
 pub mod publication;
 pub mod race;
 pub mod rule;
+
+#[cfg(feature = "schema")]
+pub mod schema;
+
+pub mod search;
 pub mod service;
 pub mod sex;
 pub mod simple;
 pub mod skill;
 pub mod src;
 pub mod status_effect;
+pub mod ui;
+pub mod validation;
+pub mod view;
 
-const ADVANCED_COMBAT_SPECIAL_ABILITY_DIR: &str =
-    "AdvancedCombatSpecialAbilities";
-const ADVANCED_KARMA_SPECIAL_ABILITY_DIR: &str =
-    "AdvancedKarmaSpecialAbilities";
-const ADVANCED_MAGICAL_SPECIAL_ABILITY_DIR: &str =
-    "AdvancedMagicalSpecialAbilities";
-const ADVANCED_SKILL_SPECIAL_ABILITY_DIR: &str =
-    "AdvancedSkillSpecialAbilities";
-const ADVANTAGE_DIR: &str = "Advantages";
-const ANCESTOR_GLYPH_DIR: &str = "AncestorGlyphs";
-const ANIMAL_DISEASE_DIR: &str = "AnimalDiseases";
-const ANIMAL_SHAPE_DIR: &str = "AnimalShapes";
-const ANIMAL_SHAPE_PATH_DIR: &str = "AnimalShapePaths";
-const ANIMAL_SHAPE_SIZE_DIR: &str = "AnimalShapeSizes";
-const ANIMAL_TYPE_DIR: &str = "AnimalTypes";
-const ANIMIST_POWER_DIR: &str = "AnimistPowers";
-const ARCANE_BARD_TRADITION_DIR: &str = "ArcaneBardTraditions";
-const ARCANE_DANCER_TRADITION_DIR: &str = "ArcaneDancerTraditions";
-const ARCANE_ORB_ENCHANTMENT_DIR: &str = "ArcaneOrbEnchantments";
-const ARMOR_TYPE_DIR: &str = "ArmorTypes";
-const ASPECT_DIR: &str = "Aspects";
-const ATTIRE_ENCHANTMENT_DIR: &str = "AttireEnchantments";
-const ATTRIBUTE_DIR: &str = "Attributes";
-const BLESSED_TRADITION_DIR: &str = "BlessedTraditions";
-const BLESSING_DIR: &str = "Blessings";
-const BOWL_ENCHANTMENT_DIR: &str = "BowlEnchantments";
-const BRAWLING_SPECIAL_ABILITY_DIR: &str = "BrawlingSpecialAbilities";
-const BREW_DIR: &str = "Brews";
-const CANTRIP_DIR: &str = "Cantrips";
-const CAULDRON_ENCHANTMENT_DIR: &str = "CauldronEnchantments";
-const CEREMONIAL_ITEM_SPECIAL_ABILITY_DIR: &str =
-    "CeremonialItemSpecialAbilities";
-const CEREMONY_DIR: &str = "Ceremonies";
-const CHRONICLE_ENCHANTMENT_DIR: &str = "ChronicleEnchantments";
-const COMBAT_SPECIAL_ABILITY_DIR: &str = "CombatSpecialAbilities";
-const COMBAT_SPECIAL_ABILITY_GROUP_DIR: &str = "CombatSpecialAbilityGroups";
-const COMBAT_STYLE_SPECIAL_ABILITY_DIR: &str = "CombatStyleSpecialAbilities";
-const COMBAT_TECHNIQUE_GROUP_DIR: &str = "CombatTechniqueGroups";
-const COMMAND_SPECIAL_ABILITY_DIR: &str = "CommandSpecialAbilities";
-const CONDITION_DIR: &str = "Conditions";
-const CULTURE_DIR: &str = "Cultures";
-// const CURRICULUM_DIR: &str = "Curricula";
-const CURSE_DIR: &str = "Curses";
-const DAGGER_RITUAL_DIR: &str = "DaggerRituals";
-const DERIVED_CHARACTERISTIC_DIR: &str = "DerivedCharacteristics";
-const DISADVANTAGE_DIR: &str = "Disadvantages";
-const DISEASE_DIR: &str = "Diseases";
-const DOMINATION_RITUAL_DIR: &str = "DominationRituals";
-const ELEMENT_DIR: &str = "Elements";
-const ELVEN_MAGICAL_SONG_DIR: &str = "ElvenMagicalSongs";
-const EQUIPMENT_PACKAGE_DIR: &str = "EquipmentPackages";
-const EXPERIENCE_LEVEL_DIR: &str = "ExperienceLevels";
-const EYE_COLOR_DIR: &str = "EyeColors";
-const FAMILIAR_SPECIAL_ABILITY_DIR: &str = "FamiliarSpecialAbilities";
-const FAMILIARS_TRICK_DIR: &str = "FamiliarsTricks";
-const FATE_POINT_SEX_SPECIAL_ABILITY_DIR: &str =
-    "FatePointSexSpecialAbilities";
-const FATE_POINT_SPECIAL_ABILITY_DIR: &str = "FatePointSpecialAbilities";
-const FOCUS_RULE_DIR: &str = "FocusRules";
-const FOOLS_HAT_ENCHANTMENT_DIR: &str = "FoolsHatEnchantments";
-const GENERAL_SPECIAL_ABILITY_DIR: &str = "GeneralSpecialAbilities";
-const GEODE_RITUAL_DIR: &str = "GeodeRituals";
-const GUIDELINE_DIR: &str = "Guidelines";
-const HAIR_COLOR_DIR: &str = "HairColors";
-const INFLUENCE_DIR: &str = "Influences";
-const INSTRUMENT_ENCHANTMENT_DIR: &str = "InstrumentEnchantments";
-const ITEM_GROUP_DIR: &str = "ItemGroups";
-const JESTER_TRICK_DIR: &str = "JesterTricks";
-const KARMA_SPECIAL_ABILITY_DIR: &str = "KarmaSpecialAbilities";
-const KRALLENKETTENZAUBER_DIR: &str = "Krallenkettenzauber";
-const LANGUAGE_DIR: &str = "Languages";
-const LITURGICAL_CHANT_DIR: &str = "LiturgicalChants";
-const LITURGICAL_CHANT_GROUP_DIR: &str = "LiturgicalChantGroups";
-const LITURGICAL_STYLE_SPECIAL_ABILITY_DIR: &str =
-    "LiturgicalStyleSpecialAbilities";
-const LYCANTROPIC_GIFT_DIR: &str = "LycantropicGifts";
-const MAGICAL_DANCE_DIR: &str = "MagicalDances";
-const MAGICAL_MELODY_DIR: &str = "MagicalMelodies";
-const MAGICAL_RUNE_DIR: &str = "MagicalRunes";
-const MAGICAL_SPECIAL_ABILITY_DIR: &str = "MagicalSpecialAbilities";
-const MAGICAL_TRADITION_DIR: &str = "MagicalTraditions";
-const MAGICAL_TRADITION_PLACEHOLDER_DIR: &str = "MagicalTraditionPlaceholders";
-const MAGIC_STYLE_SPECIAL_ABILITY_DIR: &str = "MagicStyleSpecialAbilities";
-const MELEE_COMBAT_TECHNIQUE_DIR: &str = "MeleeCombatTechniques";
-const OPTIONAL_RULE_DIR: &str = "OptionalRules";
-const ORB_ENCHANTMENT_DIR: &str = "OrbEnchantments";
-const PACT_CATEGORY_DIR: &str = "PactCategories";
-const PACT_GIFT_DIR: &str = "PactGifts";
-const PATRON_DIR: &str = "Patrons";
-const PATRON_CATEGORY_DIR: &str = "PatronCategories";
-const PERSONALITY_TRAIT_DIR: &str = "PersonalityTraits";
-const POISON_DIR: &str = "Poisons";
-// const PROFESSION_DIR: &str = "Professions";
-const PROPERTY_DIR: &str = "Properties";
-const PROTECTIVE_WARDING_CIRCLE_SPECIAL_ABILITY_DIR: &str =
-    "ProtectiveWardingCircleSpecialAbilities";
-const PUBLICATION_DIR: &str = "Publications";
-const RACE_DIR: &str = "Races";
-const RANGED_COMBAT_TECHNIQUE_DIR: &str = "RangedCombatTechniques";
-const REACH_DIR: &str = "Reaches";
-const REGION_DIR: &str = "Regions";
-const RING_ENCHANTMENT_DIR: &str = "RingEnchantments";
-const RITUAL_DIR: &str = "Rituals";
-const SCRIPT_DIR: &str = "Scripts";
-const SERMON_DIR: &str = "Sermons";
-const SERVICE_DIR: &str = "Services";
-const SEX_PRACTICE_DIR: &str = "SexPractices";
-const SEX_SPECIAL_ABILITY_DIR: &str = "SexSpecialAbilities";
-const SICKLE_RITUAL_DIR: &str = "SickleRituals";
-const SIKARYAN_DRAIN_SPECIAL_ABILITY_DIR: &str =
-    "SikaryanDrainSpecialAbilities";
-const SKILL_DIR: &str = "Skills";
-const SKILL_GROUP_DIR: &str = "SkillGroups";
-const SKILL_STYLE_SPECIAL_ABILITY_DIR: &str = "SkillStyleSpecialAbilities";
-const SOCIAL_STATUS_DIR: &str = "SocialStatuses";
-const SPECIAL_ABILITY_GROUP_DIR: &str = "SpecialAbilityGroups";
-const SPELL_DIR: &str = "Spells";
-const SPELL_GROUP_DIR: &str = "SpellGroups";
-const SPELL_SWORD_ENCHANTMENT_DIR: &str = "SpellSwordEnchantments";
-const STAFF_ENCHANTMENT_DIR: &str = "StaffEnchantments";
-const STATE_DIR: &str = "States";
-const SUBJECT_DIR: &str = "Subjects";
-const TOY_ENCHANTMENT_DIR: &str = "ToyEnchantments";
-const TRADE_SECRET_DIR: &str = "TradeSecrets";
-const TRIBE_DIR: &str = "Tribes";
-const TRINKHORNZAUBER_DIR: &str = "Trinkhornzauber";
-const UI_DIR: &str = "UI";
-const VAMPIRIC_GIFT_DIR: &str = "VampiricGifts";
-const VISION_DIR: &str = "Visions";
-const WAND_ENCHANTMENT_DIR: &str = "WandEnchantments";
-const WEAPON_ENCHANTMENT_DIR: &str = "WeaponEnchantments";
-const ZIBILJA_RITUAL_DIR: &str = "ZibiljaRituals";
-
-const EXCLUDED_FILES: &[&str] = &[
-    "25_Waldschrate.yml",
-    "5_Toad-Poison.yml",
-    "53_Mond.yml",
-    "128_Keulenweihe.yml",
-    "129_Keulenwurf.yml"
-];
+pub(crate) const UI_DIR: &str = "UI";
 
 #[derive(Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(untagged)]
 pub enum UIValue {
     Simple(String),
-    SubMap(HashMap<String, String>)
+    SubMap(BTreeMap<String, String>)
 }
 
-type UI = HashMap<String, UIValue>;
+type UI = BTreeMap<String, UIValue>;
 
-type IdMap<T> = HashMap<u32, T>;
+type IdMap<T> = BTreeMap<u32, T>;
 
-/// Stores the data from Optolith resource files in dictionaries to make it
-/// accessible by IDs.
-#[derive(Deserialize, Serialize)]
-pub struct OptolithData {
-    advanced_combat_special_abilities: IdMap<AdvancedCombatSpecialAbility>,
-    advanced_karma_special_abilities: IdMap<AdvancedKarmaSpecialAbility>,
-    advanced_magical_special_abilities: IdMap<AdvancedMagicalSpecialAbility>,
-    advanced_skill_special_abilities: IdMap<AdvancedSkillSpecialAbility>,
-    advantages: IdMap<Advantage>,
-    ancestor_glyphs: IdMap<AncestorGlyph>,
-    animal_diseases: IdMap<AnimalDisease>,
-    animal_shapes: IdMap<AnimalShape>,
-    animal_shape_paths: IdMap<AnimalShapePath>,
-    animal_shape_sizes: IdMap<AnimalShapeSize>,
-    animal_types: IdMap<AnimalType>,
-    animist_powers: IdMap<AnimistPower>,
-    arcane_bard_traditions: IdMap<ArcaneBardTradition>,
-    arcane_dancer_traditions: IdMap<ArcaneDancerTradition>,
-    arcane_orb_enchantments: IdMap<ArcaneOrbEnchantment>,
-    armor_types: IdMap<ArmorType>,
-    aspects: IdMap<Aspect>,
-    attire_enchantments: IdMap<AttireEnchantment>,
-    attributes: IdMap<Attribute>,
-    blessed_traditions: IdMap<BlessedTradition>,
-    blessings: IdMap<Blessing>,
-    bowl_enchantments: IdMap<BowlEnchantment>,
-    brawling_special_abilities: IdMap<BrawlingSpecialAbility>,
-    brews: IdMap<Brew>,
-    cantrips: IdMap<Cantrip>,
-    cauldron_enchantments: IdMap<CauldronEnchantment>,
-    ceremonial_item_special_abilities: IdMap<CeremonialItemSpecialAbility>,
-    ceremonies: IdMap<Ceremony>,
-    chronicle_enchantments: IdMap<ChronicleEnchantment>,
-    combat_special_abilities: IdMap<CombatSpecialAbility>,
-    combat_special_ability_groups: IdMap<CombatSpecialAbilityGroup>,
-    combat_style_special_abilities: IdMap<CombatStyleSpecialAbility>,
-    combat_technique_groups: IdMap<CombatTechniqueGroup>,
-    command_special_abilities: IdMap<CommandSpecialAbility>,
-    conditions: IdMap<Condition>,
-    cultures: IdMap<Culture>,
-    curricula: IdMap<Curriculum>,
-    curses: IdMap<Curse>,
-    dagger_rituals: IdMap<DaggerRitual>,
-    derived_characteristics: IdMap<DerivedCharacteristic>,
-    disadvantages: IdMap<Disadvantage>,
-    diseases: IdMap<Disease>,
-    domination_rituals: IdMap<DominationRitual>,
-    elements: IdMap<Element>,
-    elven_magical_songs: IdMap<ElvenMagicalSong>,
-    equipment_packages: IdMap<EquipmentPackage>,
-    experience_levels: IdMap<ExperienceLevel>,
-    eye_colors: IdMap<EyeColor>,
-    familiar_special_abilities: IdMap<FamiliarSpecialAbility>,
-    familiars_tricks: IdMap<FamiliarsTrick>,
-    fate_point_sex_special_abilities: IdMap<FatePointSexSpecialAbility>,
-    fate_point_special_abilities: IdMap<FatePointSpecialAbility>,
-    focus_rules: IdMap<FocusRule>,
-    fools_hat_enchantments: IdMap<FoolsHatEnchantment>,
-    general_special_abilities: IdMap<GeneralSpecialAbility>,
-    geode_rituals: IdMap<GeodeRitual>,
-    guidelines: IdMap<Guideline>,
-    hair_colors: IdMap<HairColor>,
-    influences: IdMap<Influence>,
-    instrument_enchantments: IdMap<InstrumentEnchantment>,
-    item_groups: IdMap<ItemGroup>,
-    jester_tricks: IdMap<JesterTrick>,
-    karma_special_abilities: IdMap<KarmaSpecialAbility>,
-    krallenkettenzauber: IdMap<Krallenkettenzauber>,
-    languages: IdMap<Language>,
-    liturgical_chants: IdMap<LiturgicalChant>,
-    liturgical_chant_groups: IdMap<LiturgicalChantGroup>,
-    liturgical_style_special_abilities: IdMap<LiturgicalStyleSpecialAbility>,
-    lycantropic_gifts: IdMap<LycantropicGift>,
-    magical_dances: IdMap<MagicalDance>,
-    magical_melodies: IdMap<MagicalMelody>,
-    magical_runes: IdMap<MagicalRune>,
-    magical_special_abilities: IdMap<MagicalSpecialAbility>,
-    magical_traditions: IdMap<MagicalTradition>,
-    magical_tradition_placeholders: IdMap<MagicalTraditionPlaceholder>,
-    magic_style_special_abilities: IdMap<MagicStyleSpecialAbility>,
-    melee_combat_techniques: IdMap<MeleeCombatTechnique>,
-    optional_rules: IdMap<OptionalRule>,
-    orb_enchantments: IdMap<OrbEnchantment>,
-    pact_categories: IdMap<PactCategory>,
-    pact_gifts: IdMap<PactGift>,
-    patrons: IdMap<Patron>,
-    patron_categories: IdMap<PatronCategory>,
-    personality_traits: IdMap<PersonalityTrait>,
-    poisons: IdMap<Poison>,
-    professions: IdMap<Profession>,
-    properties: IdMap<Property>,
-    protective_warding_circle_special_abilities:
-        IdMap<ProtectiveWardingCircleSpecialAbility>,
-    publications: IdMap<Publication>,
-    races: IdMap<Race>,
-    ranged_combat_techniques: IdMap<RangedCombatTechnique>,
-    reaches: IdMap<Reach>,
-    regions: IdMap<Region>,
-    ring_enchantments: IdMap<RingEnchantment>,
-    rituals: IdMap<Ritual>,
-    scripts: IdMap<Script>,
-    sermons: IdMap<Sermon>,
-    services: IdMap<Service>,
-    sex_practices: IdMap<SexPractice>,
-    sex_special_abilities: IdMap<SexSpecialAbility>,
-    sickle_rituals: IdMap<SickleRitual>,
-    sikaryan_drain_special_abilities: IdMap<SikaryanDrainSpecialAbility>,
-    skills: IdMap<Skill>,
-    skill_groups: IdMap<SkillGroup>,
-    skill_style_special_abilities: IdMap<SkillStyleSpecialAbility>,
-    social_statuses: IdMap<SocialStatus>,
-    special_ability_groups: IdMap<SpecialAbilityGroup>,
-    spells: IdMap<Spell>,
-    spell_groups: IdMap<SpellGroup>,
-    spell_sword_enchantments: IdMap<SpellSwordEnchantment>,
-    staff_enchantments: IdMap<StaffEnchantment>,
-    states: IdMap<State>,
-    subjects: IdMap<Subject>,
-    toy_enchantments: IdMap<ToyEnchantment>,
-    trade_secrets: IdMap<TradeSecret>,
-    tribes: IdMap<Tribe>,
-    trinkhornzauber: IdMap<Trinkhornzauber>,
-    vampiric_gifts: IdMap<VampiricGift>,
-    visions: IdMap<Vision>,
-    wand_enchantments: IdMap<WandEnchantment>,
-    weapon_enchantments: IdMap<WeaponEnchantment>,
-    zibilja_rituals: IdMap<ZibiljaRitual>,
-    uis: HashMap<String, UI>
+fn retain_keys(map: &mut Mapping, locales: &HashSet<String>) {
+    *map = std::mem::take(map).into_iter()
+        .filter(|(locale, _)| locale.as_str()
+            .map(|locale| locales.contains(locale))
+            .unwrap_or(true))
+        .collect();
 }
 
-fn is_placeholder(path: &str) -> bool {
-    // TODO remove once sorted out
-    if EXCLUDED_FILES.contains(&path) {
-        return true;
+/// Removes the translations into all locales except the given ones from every
+/// `Translations` map in the given YAML value.
+fn retain_locales(value: &mut Value, locales: &HashSet<String>) {
+    match value {
+        Value::Mapping(map) => {
+            let replacement = map.get(&Value::from("type")) ==
+                Some(&Value::from("ReplaceWith"));
+
+            for (key, value) in map.iter_mut() {
+                let is_translations = match key.as_str() {
+                    Some("translations") => true,
+                    Some("value") => replacement,
+                    _ => false
+                };
+
+                if let (true, Value::Mapping(translations)) =
+                        (is_translations, &mut *value) {
+                    retain_keys(translations, locales);
+                }
+
+                retain_locales(value, locales);
+            }
+        },
+        Value::Sequence(values) => {
+            for value in values {
+                retain_locales(value, locales);
+            }
+        },
+        _ => { }
     }
+}
 
-    if let Some(underscore) = path.find('_') {
-        match path[0..underscore].parse::<u32>() {
-            Ok(n) => n >= 1000,
-            Err(_) => true
-        }
-    }
-    else {
-        true
-    }
+/// Gets the given locales which are missing in the top-level translations of
+/// the given YAML value in ascending order.
+fn missing_locales(value: &Value, locales: &HashSet<String>) -> Vec<String> {
+    let translations = match value.get("translations") {
+        Some(Value::Mapping(translations)) => translations,
+        _ => return Vec::new()
+    };
+    let mut missing = locales.iter()
+        .filter(|&locale|
+            !translations.contains_key(&Value::from(locale.as_str())))
+        .cloned()
+        .collect::<Vec<_>>();
+    missing.sort();
+    missing
 }
 
 struct IdMapBuilder<'a> {
-    path: &'a str
+    source: &'a dyn DataSource,
+    path: &'a str,
+    options: &'a LoadOptions,
+
+    /// The errors collected if the options are lenient.
+    failures: Mutex<Vec<OptolithDataError>>,
+
+    /// The entities lacking requested locales with the paths of their files.
+    missing_locales: Mutex<Vec<(String, MissingLocales)>>
 }
 
 impl<'a> IdMapBuilder<'a> {
-    fn map<K, V>(&self, dir_name: &str,
-        placeholder_predicate: impl Fn(&DirEntry) -> bool,
-        key_builder: impl Fn(&V, &DirEntry) -> K)
-        -> OptolithDataResult<HashMap<K, V>>
+    fn new(source: &'a dyn DataSource, path: &'a str,
+            options: &'a LoadOptions) -> IdMapBuilder<'a> {
+        IdMapBuilder {
+            source,
+            path,
+            options,
+            failures: Mutex::new(Vec::new()),
+            missing_locales: Mutex::new(Vec::new())
+        }
+    }
+
+    /// Returns the value of the given result, or its error if `lenient` is
+    /// `false`. Otherwise, the error is recorded and `None` returned.
+    fn check<T>(&self, lenient: bool, result: OptolithDataResult<T>)
+            -> OptolithDataResult<Option<T>> {
+        match result {
+            Ok(t) => Ok(Some(t)),
+            Err(e) if lenient => {
+                self.failures.lock().unwrap().push(e);
+                Ok(None)
+            },
+            Err(e) => Err(e)
+        }
+    }
+
+    fn collect_map<K, V>(&self, lenient: bool,
+        objects: impl IntoIterator<Item = (String, OptolithDataResult<V>)>,
+        key_builder: impl Fn(&V, &str) -> K)
+        -> OptolithDataResult<BTreeMap<K, V>>
     where
-        for<'de> V : Deserialize<'de>,
-        K: Eq + Hash
+        K: Ord
     {
-        let mut map: HashMap<K, V> = HashMap::new();
-        let dir = util::join(self.path, dir_name);
-    
-        for file in util::read_dir(&dir)? {
-            let file = file?;
-    
-            // TODO remove once a more permanent solution for placeholders has been
-            // found
-    
-            if placeholder_predicate(&file) {
-                continue;
+        let mut map = BTreeMap::new();
+
+        for (file_name, object) in objects {
+            if let Some(object) = self.check(lenient, object)? {
+                let id = key_builder(&object, &file_name);
+                map.insert(id, object);
             }
-    
-            let object: V = util::deserialize_yaml_file(&file.path())?;
-            let id = key_builder(&object, &file);
-            map.insert(id, object);
         }
-    
+
         Ok(map)
     }
 
-    fn map_u32<V>(&self, dir_name: &str) -> OptolithDataResult<IdMap<V>>
+    fn map<K, V>(&self, category: Option<Category>, dir_name: &str,
+        lenient: bool,
+        file_predicate: impl Fn(&str) -> bool,
+        key_builder: impl Fn(&V, &str) -> K)
+        -> OptolithDataResult<BTreeMap<K, V>>
     where
-        for<'de> V : Deserialize<'de> + Identifiable
+        for<'de> V : Deserialize<'de> + Send,
+        K: Ord
     {
-        self.map(dir_name, |d| is_placeholder(d.file_name().to_str().unwrap()),
+        let dir = source::join(self.path, dir_name);
+
+        let files = match self.check(lenient, self.source.list_files(&dir))? {
+            Some(files) => files,
+            None => return Ok(BTreeMap::new())
+        };
+        let files = files.into_iter().filter(|file| file_predicate(file));
+        let parse = |file_name: String| {
+            let object = self.load_file(category, dir_name, &file_name);
+            (file_name, object)
+        };
+
+        // Results are collected in directory order, so the first error is the
+        // same one the sequential loader would report.
+
+        #[cfg(feature = "parallel")]
+        {
+            if self.options.parallel {
+                let objects = files.collect::<Vec<_>>()
+                    .into_par_iter()
+                    .map(parse)
+                    .collect::<Vec<_>>();
+                return self.collect_map(lenient, objects, key_builder);
+            }
+        }
+
+        self.collect_map(lenient, files.map(parse), key_builder)
+    }
+
+    /// Loads the file with the given name from the directory with the given
+    /// name. If only some locales are requested, all other translations are
+    /// removed and, if the file contains an entity of the given category that
+    /// lacks requested locales, this is recorded.
+    fn load_file<V>(&self, category: Option<Category>, dir_name: &str,
+        file_name: &str) -> OptolithDataResult<V>
+    where
+        for<'de> V : Deserialize<'de>
+    {
+        let path = source::join(&source::join(self.path, dir_name), file_name);
+        let locales = match &self.options.locales {
+            Some(locales) => locales,
+            None => return source::deserialize_yaml_file(self.source, &path)
+        };
+        let mut value: Value =
+            source::deserialize_yaml_file(self.source, &path)?;
+        retain_locales(&mut value, locales);
+        let missing = missing_locales(&value, locales);
+        let internal_id = value.get("id").and_then(Value::as_u64);
+        let mut res =
+            serde_path_to_error::deserialize(value).map_err(Into::into);
+        error::set_file(&mut res, Path::new(&path));
+        let object = res?;
+
+        if let (Some(category), Some(internal_id)) = (category, internal_id) {
+            if !missing.is_empty() {
+                let missing = MissingLocales {
+                    id: Id::new(category, internal_id as u32),
+                    locales: missing
+                };
+                self.missing_locales.lock().unwrap().push((path, missing));
+            }
+        }
+
+        Ok(object)
+    }
+
+    /// Returns the recorded failures and entities lacking requested locales,
+    /// both sorted by the paths of their files.
+    fn into_findings(self) -> (Vec<OptolithDataError>, Vec<MissingLocales>) {
+        let mut failures = self.failures.into_inner().unwrap();
+        failures.sort_by(|a, b| a.file().cmp(b.file()));
+        let mut missing_locales = self.missing_locales.into_inner().unwrap();
+        missing_locales.sort_by(|(a, _), (b, _)| a.cmp(b));
+        let missing_locales = missing_locales.into_iter()
+            .map(|(_, missing)| missing)
+            .collect();
+
+        (failures, missing_locales)
+    }
+
+    fn map_u32<V>(&self, category: Category, dir_name: &str)
+        -> OptolithDataResult<IdMap<V>>
+    where
+        for<'de> V : Deserialize<'de> + Identifiable + Send
+    {
+        self.map(Some(category), dir_name, self.options.is_lenient(category),
+            |f| self.options.is_loaded(category, f),
             |v: &V, _| v.id().internal_id())
     }
 }
 
+/// Defines the [OptolithData] struct with one field per category and
+/// implements [Stored] for all entity types.
+macro_rules! define_data {
+    ({ $($field:ident: $entity:ty = ($category:ident, $dir:expr)),* }) => {
+        /// Stores the data from Optolith resource files in dictionaries to
+        /// make it accessible by IDs.
+        #[derive(Deserialize, Serialize)]
+        pub struct OptolithData {
+            $($field: IdMap<$entity>,)*
+            uis: BTreeMap<String, UI>,
+
+            /// The overlay packs the entities not from the base data come
+            /// from.
+            #[serde(default)]
+            origins: Origins,
+
+            /// The index of dependents, which is built on first use and
+            /// discarded whenever the entities change.
+            #[serde(skip)]
+            dependent_index: OnceLock<DependentIndex>
+        }
+
+        $(
+            impl CategoryProvider for $entity {
+                const CATEGORY: Category = Category::$category;
+            }
+
+            impl Stored for $entity {
+                fn entities(data: &OptolithData) -> &IdMap<$entity> {
+                    &data.$field
+                }
+            }
+        )*
+    };
+}
+
+with_categories!(define_data!());
+
+/// A trait for the entity types which are stored in an [OptolithData]. It
+/// allows accessing the entities of a category generically, for example with
+/// [OptolithData::get] or [DataView::get](view::DataView::get).
+pub trait Stored : CategoryProvider + Identifiable + Sourced + Sized {
+
+    /// Gets all entities of this type from the given data by their internal
+    /// IDs.
+    fn entities(data: &OptolithData) -> &BTreeMap<u32, Self>;
+}
+
+macro_rules! category_dirs {
+    ({ $($field:ident: $entity:ty = ($category:ident, $dir:expr)),* }) => {
+        &[$((Category::$category, $dir)),*]
+    };
+}
+
+/// The categories loaded into an [OptolithData] together with the names of
+/// their directories.
+pub(crate) const CATEGORY_DIRS: &[(Category, &str)] =
+    with_categories!(category_dirs!());
+
+#[cfg(feature = "schema")]
+macro_rules! category_schemas {
+    ({ $($field:ident: $entity:ty = ($category:ident, $dir:expr)),* }) => {
+        vec![$((Category::$category, $dir, schemars::schema_for!($entity))),*]
+    };
+}
+
+/// Generates the JSON Schemas of the entities of all categories loaded into
+/// an [OptolithData] together with the names of their directories.
+#[cfg(feature = "schema")]
+pub(crate) fn category_schemas()
+        -> Vec<(Category, &'static str, schemars::Schema)> {
+    with_categories!(category_schemas!())
+}
+
+/// Gets the category whose files are stored in the directory with the given
+/// name.
+pub(crate) fn dir_category(dir_name: &str) -> Option<Category> {
+    CATEGORY_DIRS.iter()
+        .find(|(_, dir)| *dir == dir_name)
+        .map(|(category, _)| *category)
+}
+
+/// Matches the given [Category] to the name of its directory.
+macro_rules! category_dir {
+    ($category:ident, {
+        $($field:ident: $entity:ty = ($cat:ident, $dir:expr)),*
+    }) => {
+        match $category {
+            $(Category::$cat => $dir),*
+        }
+    };
+}
+
+/// Gets the name of the directory in which the files of the given category
+/// are stored, such as `Spells`.
+pub(crate) fn category_dir(category: Category) -> &'static str {
+    with_categories!(category_dir!(category,))
+}
+
+/// Gets the name of the locale whose UI strings are stored in the file with
+/// the given name.
+pub(crate) fn locale_name(file_name: &str) -> String {
+    String::from(file_name.split('.').next().unwrap())
+}
+
+/// The locale whose entity names are preferred for the names of the files
+/// written by [OptolithData::write_directory].
+const FILE_NAME_LOCALE: &str = "en-US";
+
+/// The name used in the names of the files written by
+/// [OptolithData::write_directory] for entities without a name.
+const UNNAMED_FILE_NAME: &str = "Unnamed";
+
+/// Builds the name of the file the entity with the given internal ID and name
+/// is written to, such as `1_SenseOfDirection.yml` for the name "Sense of
+/// direction". All characters other than letters and digits are removed and
+/// the words they separate are capitalized. Without a usable name,
+/// [UNNAMED_FILE_NAME] is used instead, since files whose name does not start
+/// with an ID followed by an underscore are considered placeholders.
+pub(crate) fn entity_file_name(id: u32, name: Option<&str>) -> String {
+    let name = name.unwrap_or_default()
+        .split(|c: char| !c.is_alphanumeric())
+        .flat_map(|word| {
+            let mut chars = word.chars();
+            chars.next().into_iter()
+                .flat_map(char::to_uppercase)
+                .chain(chars)
+        })
+        .collect::<String>();
+
+    if name.is_empty() {
+        format!("{}_{}.yml", id, UNNAMED_FILE_NAME)
+    }
+    else {
+        format!("{}_{}.yml", id, name)
+    }
+}
+
+/// Loads the file with the given name of the given category with the given
+/// [IdMapBuilder] and inserts its entity into the [OptolithData], evaluating
+/// to the resulting [DataChange].
+macro_rules! reload_category_file {
+    ($data:ident, $builder:ident, $cat:ident, $file_name:ident, {
+        $($field:ident: $entity:ty = ($category:ident, $dir:expr)),*
+    }) => {
+        match $cat {
+            $(Category::$category => {
+                let entity = $builder.load_file::<$entity>(
+                    Some($cat), $dir, $file_name)?;
+                let id = entity.id();
+
+                match $data.$field.insert(id.internal_id(), entity) {
+                    Some(_) => DataChange::Updated(id),
+                    None => DataChange::Created(id)
+                }
+            },)*
+        }
+    };
+}
+
+/// Gets a reference to the entity with the given ID from the [OptolithData].
+/// Since the match is exhaustive, every [Category] must be registered.
+macro_rules! get_entity_ref {
+    ($data:ident, $id:ident, {
+        $($field:ident: $entity:ty = ($category:ident, $dir:expr)),*
+    }) => {
+        match $id.category() {
+            $(Category::$category =>
+                $data.$field.get(&$id.internal_id()).map(EntityRef::from),)*
+        }
+    };
+}
+
+/// Removes the entity with the given ID from the [OptolithData], evaluating
+/// to whether it existed.
+macro_rules! remove_entity {
+    ($data:ident, $id:ident, {
+        $($field:ident: $entity:ty = ($category:ident, $dir:expr)),*
+    }) => {
+        match $id.category() {
+            $(Category::$category =>
+                $data.$field.remove(&$id.internal_id()).is_some(),)*
+        }
+    };
+}
+
+/// Compares the entities of all categories in the two [OptolithData] and
+/// records the differences in a [DataDiff].
+macro_rules! diff_categories {
+    ($old:ident, $new:ident, $diff:ident, {
+        $($field:ident: $entity:ty = ($category:ident, $dir:expr)),*
+    }) => {
+        $($diff.compare(&$old.$field, &$new.$field);)*
+    };
+}
+
+/// Adds the prerequisites of all entities of all categories in the
+/// [OptolithData] to a [DependentIndex].
+macro_rules! index_dependents {
+    ($data:ident, $index:ident, {
+        $($field:ident: $entity:ty = ($category:ident, $dir:expr)),*
+    }) => {
+        $(for entity in $data.$field.values() {
+            $index.add(entity);
+        })*
+    };
+}
+
+/// Adds the errata of all entities of all categories in the [OptolithData] in
+/// the given locale to an [ErrataFeed].
+macro_rules! collect_errata {
+    ($data:ident, $feed:ident, $locale:ident, {
+        $($field:ident: $entity:ty = ($category:ident, $dir:expr)),*
+    }) => {
+        $(for entity in $data.$field.values() {
+            if let Some(localization) = entity.translate($locale) {
+                $feed.add(entity.id(), &localization);
+            }
+        })*
+    };
+}
+
+/// Adds the localizations of all entities of all categories in the
+/// [OptolithData] into the locales selected by the given [SearchOptions] to a
+/// [SearchIndex].
+macro_rules! index_categories {
+    ($data:ident, $index:ident, $options:ident, {
+        $($field:ident: $entity:ty = ($category:ident, $dir:expr)),*
+    }) => {
+        $(for entity in $data.$field.values() {
+            for locale in entity.locales() {
+                if !$options.is_locale_indexed(locale) {
+                    continue;
+                }
+
+                if let Some(localization) = entity.translate(locale) {
+                    $index.add(entity.id(), locale, &localization, $options);
+                }
+            }
+        })*
+    };
+}
+
+/// Appends the dangling references of all entities of all categories in the
+/// [OptolithData] to the given list.
+macro_rules! validate_categories {
+    ($data:ident, $dangling:ident, {
+        $($field:ident: $entity:ty = ($category:ident, $dir:expr)),*
+    }) => {
+        $(for entity in $data.$field.values() {
+            validation::find_dangling($data, entity, &mut $dangling);
+        })*
+    };
+}
+
+/// Writes the entities of all categories in the [OptolithData] to one file
+/// each in their directories within the given path. The file names use the
+/// name of each entity in the first of the given locales it is translated
+/// into.
+macro_rules! write_categories {
+    ($data:ident, $path:ident, $locales:ident, {
+        $($field:ident: $entity:ty = ($category:ident, $dir:expr)),*
+    }) => {
+        $({
+            let dir = util::join($path, $dir);
+            util::create_dir_all(&dir)?;
+
+            for entity in $data.$field.values() {
+                let name = $locales.iter()
+                    .find_map(|locale| entity.translate(locale))
+                    .map(|localization| localization.name());
+                let file_name =
+                    entity_file_name(entity.id().internal_id(), name);
+                util::serialize_yaml_file(&dir.join(file_name), entity)?;
+            }
+        })*
+    };
+}
+
+/// Applies the files of all categories with the given [OverlayLoader] to the
+/// [OptolithData].
+macro_rules! apply_overlay_categories {
+    ($data:ident, $loader:ident, {
+        $($field:ident: $entity:ty = ($category:ident, $dir:expr)),*
+    }) => {
+        $($loader.apply(&mut $data.origins, &mut $data.$field,
+            Category::$category, $dir)?;)*
+    };
+}
+
+/// Loads every listed category with the given [IdMapBuilder] and assembles
+/// the results together with the `other` fields into an [OptolithData]. If
+/// the builder is parallel, all categories are loaded concurrently, otherwise
+/// they are loaded in the listed order. Either way, the first error in the
+/// listed order is returned.
+macro_rules! load_categories {
+    ($builder:ident, {
+        $($other:ident),*
+    }, {
+        $($field:ident: $entity:ty = ($category:ident, $dir:expr)),*
+    }) => {{
+        #[cfg(feature = "parallel")]
+        {
+            if $builder.options.parallel {
+                $(let mut $field = None;)*
+
+                rayon::scope(|s| {
+                    $(s.spawn(|_|
+                        $field = Some($builder.map_u32::<$entity>(
+                            Category::$category, $dir)));)*
+                });
+
+                $(let $field = $field.unwrap()?;)*
+
+                return Ok(OptolithData {
+                    $($field,)*
+                    $($other,)*
+                });
+            }
+        }
+
+        $(let $field = $builder.map_u32::<$entity>(
+            Category::$category, $dir)?;)*
+
+        Ok(OptolithData {
+            $($field,)*
+            $($other,)*
+        })
+    }};
+}
+
+/// A change of an [OptolithData] caused by reloading or removing a single
+/// file.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataChange {
+
+    /// An entity with a new ID was added.
+    Created(Id),
+
+    /// An existing entity was replaced.
+    Updated(Id),
+
+    /// An entity was removed.
+    Deleted(Id),
+
+    /// The UI strings of the locale with the given name were added or
+    /// replaced.
+    UIUpdated(String),
+
+    /// The UI strings of the locale with the given name were removed.
+    UIDeleted(String)
+}
+
+/// An entity which lacks translations into some of the locales requested by
+/// [LoadOptions::locales].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MissingLocales {
+
+    /// The ID of the entity.
+    pub id: Id,
+
+    /// The requested locales without translations, in ascending order.
+    pub locales: Vec<String>
+}
+
+/// The result of loading data leniently. It contains all entities that could
+/// be loaded and the errors of all files that could not.
+pub struct LoadReport {
+    data: OptolithData,
+    failures: Vec<OptolithDataError>,
+    missing_locales: Vec<MissingLocales>
+}
+
+impl LoadReport {
+
+    /// The data of all files that were loaded successfully.
+    pub fn data(&self) -> &OptolithData {
+        &self.data
+    }
+
+    /// Consumes this report and returns the loaded data.
+    pub fn into_data(self) -> OptolithData {
+        self.data
+    }
+
+    /// The errors of all files and directories that could not be loaded,
+    /// sorted by file path. Each error provides its kind, the file and, if
+    /// applicable, the position in the file.
+    pub fn failures(&self) -> &[OptolithDataError] {
+        &self.failures
+    }
+
+    /// The entities which lack translations into some of the locales
+    /// requested by [LoadOptions::locales], sorted by the paths of their
+    /// files.
+    pub fn missing_locales(&self) -> &[MissingLocales] {
+        &self.missing_locales
+    }
+
+    /// Indicates whether all files were loaded without errors.
+    pub fn is_complete(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
 impl OptolithData {
+    fn load(builder: &IdMapBuilder) -> OptolithDataResult<OptolithData> {
+        let uis =
+            builder.map(None, UI_DIR, builder.options.lenient,
+                |f| builder.options.is_locale_loaded(&locale_name(f)),
+                |_: &UI, file_name| locale_name(file_name))?;
+        let origins = Origins::default();
+        let dependent_index = OnceLock::new();
+
+        with_categories!(load_categories!(builder,
+            { uis, origins, dependent_index },))
+    }
+
+    /// Loads the data from the given directory with the default
+    /// [LoadOptions]. The first file that cannot be read or parsed makes it
+    /// fail, naming the file and the field, except for curricula and
+    /// professions, whose mismatching files are skipped. Use
+    /// [OptolithData::from_directory_with_options] to get them reported in a
+    /// [LoadReport].
     pub fn from_directory(path: &str) -> OptolithDataResult<OptolithData> {
-        let builder = IdMapBuilder {
-            path
+        OptolithData::from_directory_with_options(path, &LoadOptions::default())
+            .map(LoadReport::into_data)
+    }
+
+    /// Loads the data from the given directory like
+    /// [OptolithData::from_directory], but parses categories and the files
+    /// within them concurrently. The result, including the reported error if
+    /// any, is the same as that of the sequential loader.
+    #[cfg(feature = "parallel")]
+    pub fn from_directory_parallel(path: &str)
+            -> OptolithDataResult<OptolithData> {
+        let options = LoadOptions {
+            parallel: true,
+            ..LoadOptions::default()
         };
 
-        let advanced_combat_special_abilities =
-            builder.map_u32(ADVANCED_COMBAT_SPECIAL_ABILITY_DIR)?;
-        let advanced_karma_special_abilities =
-            builder.map_u32(ADVANCED_KARMA_SPECIAL_ABILITY_DIR)?;
-        let advanced_magical_special_abilities =
-            builder.map_u32(ADVANCED_MAGICAL_SPECIAL_ABILITY_DIR)?;
-        let advanced_skill_special_abilities =
-            builder.map_u32(ADVANCED_SKILL_SPECIAL_ABILITY_DIR)?;
-        let advantages = builder.map_u32(ADVANTAGE_DIR)?;
-        let ancestor_glyphs = builder.map_u32(ANCESTOR_GLYPH_DIR)?;
-        let animal_diseases = builder.map_u32(ANIMAL_DISEASE_DIR)?;
-        let animal_shapes = builder.map_u32(ANIMAL_SHAPE_DIR)?;
-        let animal_shape_paths = builder.map_u32(ANIMAL_SHAPE_PATH_DIR)?;
-        let animal_shape_sizes = builder.map_u32(ANIMAL_SHAPE_SIZE_DIR)?;
-        let animal_types = builder.map_u32(ANIMAL_TYPE_DIR)?;
-        let animist_powers = builder.map_u32(ANIMIST_POWER_DIR)?;
-        let arcane_bard_traditions =
-            builder.map_u32(ARCANE_BARD_TRADITION_DIR)?;
-        let arcane_dancer_traditions =
-            builder.map_u32(ARCANE_DANCER_TRADITION_DIR)?;
-        let arcane_orb_enchantments =
-            builder.map_u32(ARCANE_ORB_ENCHANTMENT_DIR)?;
-        let armor_types = builder.map_u32(ARMOR_TYPE_DIR)?;
-        let aspects = builder.map_u32(ASPECT_DIR)?;
-        let attire_enchantments = builder.map_u32(ATTIRE_ENCHANTMENT_DIR)?;
-        let attributes = builder.map_u32(ATTRIBUTE_DIR)?;
-        let blessed_traditions = builder.map_u32(BLESSED_TRADITION_DIR)?;
-        let blessings = builder.map_u32(BLESSING_DIR)?;
-        let brawling_special_abilities =
-            builder.map_u32(BRAWLING_SPECIAL_ABILITY_DIR)?;
-        let bowl_enchantments = builder.map_u32(BOWL_ENCHANTMENT_DIR)?;
-        let brews = builder.map_u32(BREW_DIR)?;
-        let cantrips = builder.map_u32(CANTRIP_DIR)?;
-        let cauldron_enchantments = builder.map_u32(CAULDRON_ENCHANTMENT_DIR)?;
-        let ceremonial_item_special_abilities =
-            builder.map_u32(CEREMONIAL_ITEM_SPECIAL_ABILITY_DIR)?;
-        let ceremonies = builder.map_u32(CEREMONY_DIR)?;
-        let chronicle_enchantments =
-            builder.map_u32(CHRONICLE_ENCHANTMENT_DIR)?;
-        let combat_special_abilities =
-            builder.map_u32(COMBAT_SPECIAL_ABILITY_DIR)?;
-        let combat_special_ability_groups =
-            builder.map_u32(COMBAT_SPECIAL_ABILITY_GROUP_DIR)?;
-        let combat_style_special_abilities =
-            builder.map_u32(COMBAT_STYLE_SPECIAL_ABILITY_DIR)?;
-        let combat_technique_groups =
-            builder.map_u32(COMBAT_TECHNIQUE_GROUP_DIR)?;
-        let command_special_abilities =
-            builder.map_u32(COMMAND_SPECIAL_ABILITY_DIR)?;
-        let conditions = builder.map_u32(CONDITION_DIR)?;
-        let cultures = builder.map_u32(CULTURE_DIR)?;
-        // TODO reactivate once errors are gone
-        let curricula = HashMap::new(); //builder.map_u32(CURRICULUM_DIR)?;
-        let curses = builder.map_u32(CURSE_DIR)?;
-        let dagger_rituals = builder.map_u32(DAGGER_RITUAL_DIR)?;
-        let derived_characteristics =
-            builder.map_u32(DERIVED_CHARACTERISTIC_DIR)?;
-        let disadvantages = builder.map_u32(DISADVANTAGE_DIR)?;
-        let diseases = builder.map_u32(DISEASE_DIR)?;
-        let domination_rituals = builder.map_u32(DOMINATION_RITUAL_DIR)?;
-        let elements = builder.map_u32(ELEMENT_DIR)?;
-        let elven_magical_songs = builder.map_u32(ELVEN_MAGICAL_SONG_DIR)?;
-        let equipment_packages = builder.map_u32(EQUIPMENT_PACKAGE_DIR)?;
-        let experience_levels = builder.map_u32(EXPERIENCE_LEVEL_DIR)?;
-        let eye_colors = builder.map_u32(EYE_COLOR_DIR)?;
-        let familiar_special_abilities =
-            builder.map_u32(FAMILIAR_SPECIAL_ABILITY_DIR)?;
-        let familiars_tricks = builder.map_u32(FAMILIARS_TRICK_DIR)?;
-        let fate_point_sex_special_abilities =
-            builder.map_u32(FATE_POINT_SEX_SPECIAL_ABILITY_DIR)?;
-        let fate_point_special_abilities =
-            builder.map_u32(FATE_POINT_SPECIAL_ABILITY_DIR)?;
-        let focus_rules = builder.map_u32(FOCUS_RULE_DIR)?;
-        let fools_hat_enchantments =
-            builder.map_u32(FOOLS_HAT_ENCHANTMENT_DIR)?;
-        let general_special_abilities =
-            builder.map_u32(GENERAL_SPECIAL_ABILITY_DIR)?;
-        let geode_rituals = builder.map_u32(GEODE_RITUAL_DIR)?;
-        let guidelines = builder.map_u32(GUIDELINE_DIR)?;
-        let hair_colors = builder.map_u32(HAIR_COLOR_DIR)?;
-        let influences = builder.map_u32(INFLUENCE_DIR)?;
-        let instrument_enchantments =
-            builder.map_u32(INSTRUMENT_ENCHANTMENT_DIR)?;
-        let item_groups = builder.map_u32(ITEM_GROUP_DIR)?;
-        let jester_tricks = builder.map_u32(JESTER_TRICK_DIR)?;
-        let karma_special_abilities =
-            builder.map_u32(KARMA_SPECIAL_ABILITY_DIR)?;
-        let krallenkettenzauber = builder.map_u32(KRALLENKETTENZAUBER_DIR)?;
-        let languages = builder.map_u32(LANGUAGE_DIR)?;
-        let liturgical_chants = builder.map_u32(LITURGICAL_CHANT_DIR)?;
-        let liturgical_chant_groups =
-            builder.map_u32(LITURGICAL_CHANT_GROUP_DIR)?;
-        let liturgical_style_special_abilities =
-            builder.map_u32(LITURGICAL_STYLE_SPECIAL_ABILITY_DIR)?;
-        let lycantropic_gifts = builder.map_u32(LYCANTROPIC_GIFT_DIR)?;
-        let magical_dances = builder.map_u32(MAGICAL_DANCE_DIR)?;
-        let magical_melodies = builder.map_u32(MAGICAL_MELODY_DIR)?;
-        let magical_runes = builder.map_u32(MAGICAL_RUNE_DIR)?;
-        let magical_special_abilities =
-            builder.map_u32(MAGICAL_SPECIAL_ABILITY_DIR)?;
-        let magical_traditions = builder.map_u32(MAGICAL_TRADITION_DIR)?;
-        let magical_tradition_placeholders =
-            builder.map_u32(MAGICAL_TRADITION_PLACEHOLDER_DIR)?;
-        let magic_style_special_abilities =
-            builder.map_u32(MAGIC_STYLE_SPECIAL_ABILITY_DIR)?;
-        let melee_combat_techniques =
-            builder.map_u32(MELEE_COMBAT_TECHNIQUE_DIR)?;
-        let optional_rules = builder.map_u32(OPTIONAL_RULE_DIR)?;
-        let orb_enchantments = builder.map_u32(ORB_ENCHANTMENT_DIR)?;
-        let pact_categories = builder.map_u32(PACT_CATEGORY_DIR)?;
-        let pact_gifts = builder.map_u32(PACT_GIFT_DIR)?;
-        let patrons = builder.map_u32(PATRON_DIR)?;
-        let patron_categories = builder.map_u32(PATRON_CATEGORY_DIR)?;
-        let personality_traits = builder.map_u32(PERSONALITY_TRAIT_DIR)?;
-        let poisons = builder.map_u32(POISON_DIR)?;
-        // TODO reactivate once errors are gone
-        let professions = HashMap::new(); //builder.map_u32(PROFESSION_DIR)?;
-        let properties = builder.map_u32(PROPERTY_DIR)?;
-        let protective_warding_circle_special_abilities =
-            builder.map_u32(PROTECTIVE_WARDING_CIRCLE_SPECIAL_ABILITY_DIR)?;
-        let publications = builder.map_u32(PUBLICATION_DIR)?;
-        let races = builder.map_u32(RACE_DIR)?;
-        let ranged_combat_techniques =
-            builder.map_u32(RANGED_COMBAT_TECHNIQUE_DIR)?;
-        let reaches = builder.map_u32(REACH_DIR)?;
-        let regions = builder.map_u32(REGION_DIR)?;
-        let ring_enchantments = builder.map_u32(RING_ENCHANTMENT_DIR)?;
-        let rituals = builder.map_u32(RITUAL_DIR)?;
-        let scripts = builder.map_u32(SCRIPT_DIR)?;
-        let sex_practices = builder.map_u32(SEX_PRACTICE_DIR)?;
-        let sex_special_abilities = builder.map_u32(SEX_SPECIAL_ABILITY_DIR)?;
-        let sermons = builder.map_u32(SERMON_DIR)?;
-        let services = builder.map_u32(SERVICE_DIR)?;
-        let sickle_rituals = builder.map_u32(SICKLE_RITUAL_DIR)?;
-        let sikaryan_drain_special_abilities =
-            builder.map_u32(SIKARYAN_DRAIN_SPECIAL_ABILITY_DIR)?;
-        let skills = builder.map_u32(SKILL_DIR)?;
-        let skill_groups = builder.map_u32(SKILL_GROUP_DIR)?;
-        let skill_style_special_abilities =
-            builder.map_u32(SKILL_STYLE_SPECIAL_ABILITY_DIR)?;
-        let social_statuses = builder.map_u32(SOCIAL_STATUS_DIR)?;
-        let special_ability_groups = builder.map_u32(SPECIAL_ABILITY_GROUP_DIR)?;
-        let spell_groups = builder.map_u32(SPELL_GROUP_DIR)?;
-        let spell_sword_enchantments =
-            builder.map_u32(SPELL_SWORD_ENCHANTMENT_DIR)?;
-        let spells = builder.map_u32(SPELL_DIR)?;
-        let staff_enchantments = builder.map_u32(STAFF_ENCHANTMENT_DIR)?;
-        let states = builder.map_u32(STATE_DIR)?;
-        let subjects = builder.map_u32(SUBJECT_DIR)?;
-        let toy_enchantments = builder.map_u32(TOY_ENCHANTMENT_DIR)?;
-        let trade_secrets = builder.map_u32(TRADE_SECRET_DIR)?;
-        let tribes = builder.map_u32(TRIBE_DIR)?;
-        let trinkhornzauber = builder.map_u32(TRINKHORNZAUBER_DIR)?;
-        let vampiric_gifts = builder.map_u32(VAMPIRIC_GIFT_DIR)?;
-        let visions = builder.map_u32(VISION_DIR)?;
-        let wand_enchantments = builder.map_u32(WAND_ENCHANTMENT_DIR)?;
-        let weapon_enchantments = builder.map_u32(WEAPON_ENCHANTMENT_DIR)?;
-        let zibilja_rituals = builder.map_u32(ZIBILJA_RITUAL_DIR)?;
-        let uis =
-            builder.map(UI_DIR,
-                |_| false,
-                |_: &UI, d| {
-                    let os_file_name = d.file_name();
-                    let file_name = os_file_name.to_str().unwrap();
-                    let locale_name = file_name.split('.').next().unwrap();
-                    String::from(locale_name)
-                })?;
+        OptolithData::from_directory_with_options(path, &options)
+            .map(LoadReport::into_data)
+    }
 
-        Ok(OptolithData {
-            advanced_combat_special_abilities,
-            advanced_karma_special_abilities,
-            advanced_magical_special_abilities,
-            advanced_skill_special_abilities,
-            advantages,
-            ancestor_glyphs,
-            animal_diseases,
-            animal_shapes,
-            animal_shape_paths,
-            animal_shape_sizes,
-            animal_types,
-            animist_powers,
-            arcane_bard_traditions,
-            arcane_dancer_traditions,
-            arcane_orb_enchantments,
-            armor_types,
-            aspects,
-            attire_enchantments,
-            attributes,
-            blessed_traditions,
-            blessings,
-            bowl_enchantments,
-            brawling_special_abilities,
-            brews,
-            cantrips,
-            cauldron_enchantments,
-            ceremonial_item_special_abilities,
-            ceremonies,
-            chronicle_enchantments,
-            combat_special_abilities,
-            combat_special_ability_groups,
-            combat_style_special_abilities,
-            combat_technique_groups,
-            command_special_abilities,
-            conditions,
-            cultures,
-            curricula,
-            curses,
-            dagger_rituals,
-            derived_characteristics,
-            disadvantages,
-            diseases,
-            domination_rituals,
-            elements,
-            elven_magical_songs,
-            equipment_packages,
-            experience_levels,
-            eye_colors,
-            familiar_special_abilities,
-            familiars_tricks,
-            fate_point_sex_special_abilities,
-            fate_point_special_abilities,
-            focus_rules,
-            fools_hat_enchantments,
-            general_special_abilities,
-            geode_rituals,
-            guidelines,
-            hair_colors,
-            influences,
-            instrument_enchantments,
-            item_groups,
-            jester_tricks,
-            karma_special_abilities,
-            krallenkettenzauber,
-            languages,
-            liturgical_chants,
-            liturgical_chant_groups,
-            liturgical_style_special_abilities,
-            lycantropic_gifts,
-            magical_dances,
-            magical_melodies,
-            magical_runes,
-            magical_special_abilities,
-            magical_traditions,
-            magical_tradition_placeholders,
-            magic_style_special_abilities,
-            melee_combat_techniques,
-            optional_rules,
-            orb_enchantments,
-            pact_categories,
-            pact_gifts,
-            patrons,
-            patron_categories,
-            personality_traits,
-            poisons,
-            professions,
-            properties,
-            protective_warding_circle_special_abilities,
-            publications,
-            races,
-            ranged_combat_techniques,
-            reaches,
-            regions,
-            ring_enchantments,
-            rituals,
-            scripts,
-            sermons,
-            services,
-            sex_practices,
-            sex_special_abilities,
-            sickle_rituals,
-            sikaryan_drain_special_abilities,
-            skills,
-            skill_groups,
-            skill_style_special_abilities,
-            social_statuses,
-            special_ability_groups,
-            spell_groups,
-            spell_sword_enchantments,
-            spells,
-            staff_enchantments,
-            states,
-            subjects,
-            toy_enchantments,
-            trade_secrets,
-            tribes,
-            trinkhornzauber,
-            vampiric_gifts,
-            visions,
-            wand_enchantments,
-            weapon_enchantments,
-            zibilja_rituals,
-            uis
+    /// Loads the data from the given directory like
+    /// [OptolithData::from_directory], but does not stop at the first file
+    /// that cannot be read or parsed. Instead, all such files are skipped and
+    /// their errors are listed in the returned [LoadReport].
+    pub fn from_directory_lenient(path: &str)
+            -> OptolithDataResult<LoadReport> {
+        let options = LoadOptions {
+            lenient: true,
+            ..LoadOptions::default()
+        };
+
+        OptolithData::from_directory_with_options(path, &options)
+    }
+
+    /// Loads the data from the given directory, selecting the files to load
+    /// and the handling of errors according to the given [LoadOptions]. If
+    /// the options are not lenient, the first error is returned and the
+    /// report only contains failures of the categories listed in
+    /// [LoadOptions::lenient_categories].
+    pub fn from_directory_with_options(path: &str, options: &LoadOptions)
+            -> OptolithDataResult<LoadReport> {
+        OptolithData::from_source_with_options(&FileSystem, path, options)
+    }
+
+    /// Loads the data from the directory with the given path within the given
+    /// [DataSource], such as an archive or an in-memory file tree.
+    pub fn from_source(source: &dyn DataSource, path: &str)
+            -> OptolithDataResult<OptolithData> {
+        OptolithData::from_source_with_options(source, path,
+            &LoadOptions::default())
+            .map(LoadReport::into_data)
+    }
+
+    /// Loads the data from the directory with the given path within the given
+    /// [DataSource] according to the given [LoadOptions]. See
+    /// [OptolithData::from_directory_with_options].
+    pub fn from_source_with_options(source: &dyn DataSource, path: &str,
+            options: &LoadOptions) -> OptolithDataResult<LoadReport> {
+        let builder = IdMapBuilder::new(source, path, options);
+        let data = OptolithData::load(&builder)?;
+        let (failures, missing_locales) = builder.into_findings();
+
+        Ok(LoadReport {
+            data,
+            failures,
+            missing_locales
         })
     }
 
+    /// Loads the file with the given name in the directory of a category or
+    /// the UI directory, which is located in the data directory with the
+    /// given path within the given [DataSource], and adds or replaces its
+    /// content. Returns the resulting change, or `None` if the directory does
+    /// not belong to any category or the file is not selected by the given
+    /// [LoadOptions]. If the file cannot be loaded, the data is unchanged.
+    /// A reloaded entity counts as part of the base data, even if it was
+    /// previously defined by an overlay pack.
+    pub fn reload_file(&mut self, source: &dyn DataSource, path: &str,
+            options: &LoadOptions, dir_name: &str, file_name: &str)
+            -> OptolithDataResult<Option<DataChange>> {
+        let builder = IdMapBuilder::new(source, path, options);
+
+        if dir_name == UI_DIR {
+            let locale = locale_name(file_name);
+
+            if !options.is_locale_loaded(&locale) {
+                return Ok(None);
+            }
+
+            let ui = builder.load_file::<UI>(None, UI_DIR, file_name)?;
+            self.uis.insert(locale.clone(), ui);
+            return Ok(Some(DataChange::UIUpdated(locale)));
+        }
+
+        let change = match dir_category(dir_name) {
+            Some(category) if options.is_loaded(category, file_name) =>
+                with_categories!(reload_category_file!(
+                    self, builder, category, file_name,)),
+            _ => return Ok(None)
+        };
+
+        if let DataChange::Created(id) | DataChange::Updated(id) = change {
+            self.origins.remove(id);
+        }
+
+        self.dependent_index.take();
+
+        Ok(Some(change))
+    }
+
+    /// Removes the entity with the given ID. Returns `true` if it existed.
+    pub fn remove(&mut self, id: Id) -> bool {
+        self.origins.remove(id);
+        self.dependent_index.take();
+        with_categories!(remove_entity!(self, id,))
+    }
+
+    /// Applies the overlay pack in the given directory, whose path is also
+    /// used as the name of the pack. See the [overlay] module for the layout
+    /// of overlay packs.
+    pub fn apply_overlay(&mut self, path: &str)
+            -> OptolithDataResult<OverlayReport> {
+        self.apply_overlay_from_source(&FileSystem, path, path,
+            &LoadOptions::default())
+    }
+
+    /// Applies the overlay packs in the given directories in the given order,
+    /// so later packs override earlier ones. See
+    /// [OptolithData::apply_overlay].
+    pub fn apply_overlays(&mut self, paths: &[&str])
+            -> OptolithDataResult<OverlayReport> {
+        let mut report = OverlayReport::default();
+
+        for path in paths {
+            report.append(self.apply_overlay(path)?);
+        }
+
+        Ok(report)
+    }
+
+    /// Applies the overlay pack with the given name stored in the directory
+    /// with the given path within the given [DataSource]. The files are
+    /// selected and errors are handled according to the given [LoadOptions],
+    /// as in [OptolithData::from_directory_with_options]. If an error is
+    /// returned, the data may already contain parts of the pack.
+    pub fn apply_overlay_from_source(&mut self, source: &dyn DataSource,
+            pack: &str, path: &str, options: &LoadOptions)
+            -> OptolithDataResult<OverlayReport> {
+        let builder = IdMapBuilder::new(source, path, options);
+        let mut loader = OverlayLoader::new(builder, pack);
+        let data = self;
+
+        data.dependent_index.take();
+        loader.apply_uis(&mut data.uis)?;
+        with_categories!(apply_overlay_categories!(data, loader,));
+
+        Ok(loader.into_report())
+    }
+
+    /// Gets the name of the overlay pack which defined the entity with the
+    /// given ID, or `None` if it is part of the base data or does not exist.
+    pub fn pack(&self, id: Id) -> Option<&str> {
+        self.origins.get(id)
+    }
+
+    /// Gets the names of the overlay packs which patched the translations of
+    /// the entity with the given ID since it was defined, in the order they
+    /// were applied.
+    pub fn patches(&self, id: Id) -> &[String] {
+        self.origins.patches(id)
+    }
+
+    /// Gets the entity of type `T` with the given internal ID, for example
+    /// `data.get::<Spell>(id)`. The `get_*` methods per entity type, such as
+    /// [OptolithData::get_spell], are shorthands for this.
+    pub fn get<T: Stored>(&self, id: u32) -> Option<&T> {
+        T::entities(self).get(&id)
+    }
+
+    /// Iterates over all entities of type `T` in ascending order of their
+    /// IDs.
+    pub fn iter<T: Stored>(&self) -> btree_map::Values<'_, u32, T> {
+        T::entities(self).values()
+    }
+
+    /// Gets the number of entities of type `T`.
+    pub fn len<T: Stored>(&self) -> usize {
+        T::entities(self).len()
+    }
+
+    /// Indicates whether there are no entities of type `T`.
+    pub fn is_empty<T: Stored>(&self) -> bool {
+        T::entities(self).is_empty()
+    }
+
+    /// Removes the UI strings of the locale with the given name. Returns
+    /// `true` if they existed.
+    pub fn remove_ui(&mut self, locale: &str) -> bool {
+        self.uis.remove(locale).is_some()
+    }
+
+    /// Writes the data to the directory with the given path in the layout it
+    /// is loaded from by [OptolithData::from_directory], i.e. one YAML file
+    /// named like `<ID>_<Name>.yml` per entity in the directory of its
+    /// category, and one file per locale in the UI directory. Directories
+    /// are created as needed and existing files with the same names are
+    /// replaced, but other existing files are left in place, so the
+    /// directory should be empty if the output is to be loaded again. The
+    /// overlay packs entities come from are not written. Entities keep their
+    /// IDs, so those which are placeholders according to the
+    /// [LoadOptions] are only loaded again if `load_placeholders` is set.
+    pub fn write_directory(&self, path: &str) -> OptolithDataResult<()> {
+        let ui_dir = util::join(path, UI_DIR);
+        util::create_dir_all(&ui_dir)?;
+
+        for (locale, ui) in &self.uis {
+            let file = ui_dir.join(format!("{}.yml", locale));
+            util::serialize_yaml_file(&file, ui)?;
+        }
+
+        let locales = std::iter::once(FILE_NAME_LOCALE)
+            .chain(self.locales().filter(|l| *l != FILE_NAME_LOCALE))
+            .collect::<Vec<_>>();
+        with_categories!(write_categories!(self, path, locales,));
+        Ok(())
+    }
+
     pub fn from_file(path: &str) -> OptolithDataResult<OptolithData> {
         util::from_file(path)
     }
@@ -826,804 +1310,655 @@ impl OptolithData {
         util::to_file(self, path)
     }
 
+    /// Loads the data from the binary snapshot at `cache` if it is up to date
+    /// with the directory `dir`, and otherwise loads `dir` and rebuilds the
+    /// snapshot. See [cache::load_cached_or_build].
+    pub fn load_cached_or_build(dir: &str, cache: &str)
+            -> OptolithDataResult<OptolithData> {
+        cache::load_cached_or_build(dir, cache)
+    }
+
     pub fn get_advanced_combat_special_ability(&self, id: u32)
             -> Option<&AdvancedCombatSpecialAbility> {
-        self.advanced_combat_special_abilities.get(&id)
+        self.get(id)
     }
 
     pub fn get_advanced_karma_special_ability(&self, id: u32)
             -> Option<&AdvancedKarmaSpecialAbility> {
-        self.advanced_karma_special_abilities.get(&id)
+        self.get(id)
     }
 
     pub fn get_advanced_magical_special_ability(&self, id: u32)
             -> Option<&AdvancedMagicalSpecialAbility> {
-        self.advanced_magical_special_abilities.get(&id)
+        self.get(id)
     }
 
     pub fn get_advanced_skill_special_ability(&self, id: u32)
             -> Option<&AdvancedSkillSpecialAbility> {
-        self.advanced_skill_special_abilities.get(&id)
+        self.get(id)
     }
 
     pub fn get_advantage(&self, id: u32) -> Option<&Advantage> {
-        self.advantages.get(&id)
+        self.get(id)
     }
 
     pub fn get_ancestor_glyph(&self, id: u32) -> Option<&AncestorGlyph> {
-        self.ancestor_glyphs.get(&id)
+        self.get(id)
     }
 
     pub fn get_animal_disease(&self, id: u32) -> Option<&AnimalDisease> {
-        self.animal_diseases.get(&id)
+        self.get(id)
     }
 
     pub fn get_animal_shape(&self, id: u32) -> Option<&AnimalShape> {
-        self.animal_shapes.get(&id)
+        self.get(id)
     }
 
     pub fn get_animal_shape_path(&self, id: u32) -> Option<&AnimalShapePath> {
-        self.animal_shape_paths.get(&id)
+        self.get(id)
     }
 
     pub fn get_animal_shape_size(&self, id: u32) -> Option<&AnimalShapeSize> {
-        self.animal_shape_sizes.get(&id)
+        self.get(id)
     }
 
     pub fn get_animal_type(&self, id: u32) -> Option<&AnimalType> {
-        self.animal_types.get(&id)
+        self.get(id)
     }
 
     pub fn get_animist_power(&self, id: u32) -> Option<&AnimistPower> {
-        self.animist_powers.get(&id)
+        self.get(id)
     }
 
     pub fn get_arcane_bard_tradition(&self, id: u32)
             -> Option<&ArcaneBardTradition> {
-        self.arcane_bard_traditions.get(&id)
+        self.get(id)
     }
 
     pub fn get_arcane_dancer_tradition(&self, id: u32)
             -> Option<&ArcaneDancerTradition> {
-        self.arcane_dancer_traditions.get(&id)
+        self.get(id)
     }
 
     pub fn get_arcane_orb_enchantment(&self, id: u32)
             -> Option<&ArcaneOrbEnchantment> {
-        self.arcane_orb_enchantments.get(&id)
+        self.get(id)
     }
 
     pub fn get_armor_type(&self, id: u32) -> Option<&ArmorType> {
-        self.armor_types.get(&id)
+        self.get(id)
     }
 
     pub fn get_aspect(&self, id: u32) -> Option<&Aspect> {
-        self.aspects.get(&id)
+        self.get(id)
     }
 
     pub fn get_attire_enchantment(&self, id: u32)
             -> Option<&AttireEnchantment> {
-        self.attire_enchantments.get(&id)
+        self.get(id)
     }
 
     pub fn get_attribute(&self, id: u32) -> Option<&Attribute> {
-        self.attributes.get(&id)
+        self.get(id)
     }
 
     pub fn get_blessed_tradition(&self, id: u32) -> Option<&BlessedTradition> {
-        self.blessed_traditions.get(&id)
+        self.get(id)
     }
 
     pub fn get_blessing(&self, id: u32) -> Option<&Blessing> {
-        self.blessings.get(&id)
+        self.get(id)
     }
 
     pub fn get_bowl_enchantment(&self, id: u32) -> Option<&BowlEnchantment> {
-        self.bowl_enchantments.get(&id)
+        self.get(id)
     }
 
     pub fn get_brawling_special_ability(&self, id: u32)
             -> Option<&BrawlingSpecialAbility> {
-        self.brawling_special_abilities.get(&id)
+        self.get(id)
     }
 
     pub fn get_brew(&self, id: u32) -> Option<&Brew> {
-        self.brews.get(&id)
+        self.get(id)
     }
 
     pub fn get_cantrip(&self, id: u32) -> Option<&Cantrip> {
-        self.cantrips.get(&id)
+        self.get(id)
     }
 
     pub fn get_cauldron_enchantment(&self, id: u32)
             -> Option<&CauldronEnchantment> {
-        self.cauldron_enchantments.get(&id)
+        self.get(id)
     }
 
     pub fn get_ceremonial_item_special_ability(&self, id: u32)
             -> Option<&CeremonialItemSpecialAbility> {
-        self.ceremonial_item_special_abilities.get(&id)
+        self.get(id)
     }
 
     pub fn get_ceremony(&self, id: u32) -> Option<&Ceremony> {
-        self.ceremonies.get(&id)
+        self.get(id)
     }
 
     pub fn get_chronicle_enchantment(&self, id: u32)
             -> Option<&ChronicleEnchantment> {
-        self.chronicle_enchantments.get(&id)
+        self.get(id)
     }
 
     pub fn get_combat_special_ability(&self, id: u32)
             -> Option<&CombatSpecialAbility> {
-        self.combat_special_abilities.get(&id)
+        self.get(id)
     }
 
     pub fn get_combat_special_ability_group(&self, id: u32)
             -> Option<&CombatSpecialAbilityGroup> {
-        self.combat_special_ability_groups.get(&id)
+        self.get(id)
     }
 
     pub fn get_combat_style_special_ability(&self, id: u32)
             -> Option<&CombatStyleSpecialAbility> {
-        self.combat_style_special_abilities.get(&id)
+        self.get(id)
     }
 
     pub fn get_combat_technique_group(&self, id: u32)
             -> Option<&CombatTechniqueGroup> {
-        self.combat_technique_groups.get(&id)
+        self.get(id)
     }
 
     pub fn get_command_special_ability(&self, id: u32)
             -> Option<&CommandSpecialAbility> {
-        self.command_special_abilities.get(&id)
+        self.get(id)
     }
 
     pub fn get_condition(&self, id: u32) -> Option<&Condition> {
-        self.conditions.get(&id)
+        self.get(id)
     }
 
     pub fn get_culture(&self, id: u32) -> Option<&Culture> {
-        self.cultures.get(&id)
+        self.get(id)
     }
 
     pub fn get_curriculum(&self, id: u32) -> Option<&Curriculum> {
-        self.curricula.get(&id)
+        self.get(id)
     }
 
     pub fn get_curse(&self, id: u32) -> Option<&Curse> {
-        self.curses.get(&id)
+        self.get(id)
     }
 
     pub fn get_dagger_ritual(&self, id: u32) -> Option<&DaggerRitual> {
-        self.dagger_rituals.get(&id)
+        self.get(id)
     }
 
     pub fn get_derived_characteristic(&self, id: u32)
             -> Option<&DerivedCharacteristic> {
-        self.derived_characteristics.get(&id)
+        self.get(id)
     }
 
     pub fn get_disadvantage(&self, id: u32) -> Option<&Disadvantage> {
-        self.disadvantages.get(&id)
+        self.get(id)
     }
 
     pub fn get_disease(&self, id: u32) -> Option<&Disease> {
-        self.diseases.get(&id)
+        self.get(id)
     }
 
     pub fn get_domination_ritual(&self, id: u32) -> Option<&DominationRitual> {
-        self.domination_rituals.get(&id)
+        self.get(id)
     }
 
     pub fn get_element(&self, id: u32) -> Option<&Element> {
-        self.elements.get(&id)
+        self.get(id)
     }
 
     pub fn get_elven_magical_song(&self, id: u32)
             -> Option<&ElvenMagicalSong> {
-        self.elven_magical_songs.get(&id)
+        self.get(id)
     }
 
     pub fn get_equipment_package(&self, id: u32) -> Option<&EquipmentPackage> {
-        self.equipment_packages.get(&id)
+        self.get(id)
     }
 
     pub fn get_experience_level(&self, id: u32) -> Option<&ExperienceLevel> {
-        self.experience_levels.get(&id)
+        self.get(id)
     }
 
     pub fn get_eye_color(&self, id: u32) -> Option<&EyeColor> {
-        self.eye_colors.get(&id)
+        self.get(id)
     }
 
     pub fn get_familiar_special_ability(&self, id: u32)
             -> Option<&FamiliarSpecialAbility> {
-        self.familiar_special_abilities.get(&id)
+        self.get(id)
     }
 
     pub fn get_familiars_trick(&self, id: u32) -> Option<&FamiliarsTrick> {
-        self.familiars_tricks.get(&id)
+        self.get(id)
     }
 
     pub fn get_fate_point_sex_special_ability(&self, id: u32)
             -> Option<&FatePointSexSpecialAbility> {
-        self.fate_point_sex_special_abilities.get(&id)
+        self.get(id)
     }
 
     pub fn get_fate_point_special_ability(&self, id: u32)
             -> Option<&FatePointSpecialAbility> {
-        self.fate_point_special_abilities.get(&id)
+        self.get(id)
     }
 
     pub fn get_focus_rule(&self, id: u32) -> Option<&FocusRule> {
-        self.focus_rules.get(&id)
+        self.get(id)
     }
 
     pub fn get_fools_hat_enchantment(&self, id: u32)
             -> Option<&FoolsHatEnchantment> {
-        self.fools_hat_enchantments.get(&id)
+        self.get(id)
     }
 
     pub fn get_general_special_ability(&self, id: u32)
             -> Option<&GeneralSpecialAbility> {
-        self.general_special_abilities.get(&id)
+        self.get(id)
     }
 
     pub fn get_geode_ritual(&self, id: u32) -> Option<&GeodeRitual> {
-        self.geode_rituals.get(&id)
+        self.get(id)
     }
 
     pub fn get_guideline(&self, id: u32) -> Option<&Guideline> {
-        self.guidelines.get(&id)
+        self.get(id)
     }
 
     pub fn get_hair_color(&self, id: u32) -> Option<&HairColor> {
-        self.hair_colors.get(&id)
+        self.get(id)
     }
 
     pub fn get_influence(&self, id: u32) -> Option<&Influence> {
-        self.influences.get(&id)
+        self.get(id)
     }
 
     pub fn get_instrument_enchantment(&self, id: u32)
             -> Option<&InstrumentEnchantment> {
-        self.instrument_enchantments.get(&id)
+        self.get(id)
     }
 
     pub fn get_item_group(&self, id: u32) -> Option<&ItemGroup> {
-        self.item_groups.get(&id)
+        self.get(id)
     }
 
     pub fn get_jester_trick(&self, id: u32) -> Option<&JesterTrick> {
-        self.jester_tricks.get(&id)
+        self.get(id)
     }
 
     pub fn get_karma_special_ability(&self, id: u32)
             -> Option<&KarmaSpecialAbility> {
-        self.karma_special_abilities.get(&id)
+        self.get(id)
     }
 
     pub fn get_krallenkettenzauber(&self, id: u32)
             -> Option<&Krallenkettenzauber> {
-        self.krallenkettenzauber.get(&id)
+        self.get(id)
     }
 
     pub fn get_language(&self, id: u32) -> Option<&Language> {
-        self.languages.get(&id)
+        self.get(id)
     }
 
     pub fn get_liturgical_chant(&self, id: u32) -> Option<&LiturgicalChant> {
-        self.liturgical_chants.get(&id)
+        self.get(id)
     }
 
     pub fn get_liturgical_chant_group(&self, id: u32)
             -> Option<&LiturgicalChantGroup> {
-        self.liturgical_chant_groups.get(&id)
+        self.get(id)
     }
 
     pub fn get_liturgical_style_special_ability(&self, id: u32)
             -> Option<&LiturgicalStyleSpecialAbility> {
-        self.liturgical_style_special_abilities.get(&id)
+        self.get(id)
     }
 
     pub fn get_lycantropic_gift(&self, id: u32) -> Option<&LycantropicGift> {
-        self.lycantropic_gifts.get(&id)
+        self.get(id)
     }
 
     pub fn get_magical_dance(&self, id: u32) -> Option<&MagicalDance> {
-        self.magical_dances.get(&id)
+        self.get(id)
     }
 
     pub fn get_magical_melody(&self, id: u32) -> Option<&MagicalMelody> {
-        self.magical_melodies.get(&id)
+        self.get(id)
     }
 
     pub fn get_magical_rune(&self, id: u32) -> Option<&MagicalRune> {
-        self.magical_runes.get(&id)
+        self.get(id)
     }
 
     pub fn get_magical_special_ability(&self, id: u32)
             -> Option<&MagicalSpecialAbility> {
-        self.magical_special_abilities.get(&id)
+        self.get(id)
     }
 
     pub fn get_magical_tradition(&self, id: u32) -> Option<&MagicalTradition> {
-        self.magical_traditions.get(&id)
+        self.get(id)
     }
 
     pub fn get_magical_tradition_placeholder(&self, id: u32)
             -> Option<&MagicalTraditionPlaceholder> {
-        self.magical_tradition_placeholders.get(&id)
+        self.get(id)
     }
 
     pub fn get_magic_style_special_ability(&self, id: u32)
             -> Option<&MagicStyleSpecialAbility> {
-        self.magic_style_special_abilities.get(&id)
+        self.get(id)
     }
 
     pub fn get_melee_combat_technique(&self, id: u32)
             -> Option<&MeleeCombatTechnique> {
-        self.melee_combat_techniques.get(&id)
+        self.get(id)
     }
 
     pub fn get_optional_rule(&self, id: u32) -> Option<&OptionalRule> {
-        self.optional_rules.get(&id)
+        self.get(id)
     }
 
     pub fn get_orb_enchantment(&self, id: u32) -> Option<&OrbEnchantment> {
-        self.orb_enchantments.get(&id)
+        self.get(id)
     }
 
     pub fn get_pact_category(&self, id: u32) -> Option<&PactCategory> {
-        self.pact_categories.get(&id)
+        self.get(id)
     }
 
     pub fn get_pact_gift(&self, id: u32) -> Option<&PactGift> {
-        self.pact_gifts.get(&id)
+        self.get(id)
     }
 
     pub fn get_patron(&self, id: u32) -> Option<&Patron> {
-        self.patrons.get(&id)
+        self.get(id)
     }
 
     pub fn get_patron_categories(&self, id: u32) -> Option<&PatronCategory> {
-        self.patron_categories.get(&id)
+        self.get(id)
     }
 
     pub fn get_personality_trait(&self, id: u32) -> Option<&PersonalityTrait> {
-        self.personality_traits.get(&id)
+        self.get(id)
     }
 
     pub fn get_poison(&self, id: u32) -> Option<&Poison> {
-        self.poisons.get(&id)
+        self.get(id)
     }
 
     pub fn get_profession(&self, id: u32) -> Option<&Profession> {
-        self.professions.get(&id)
+        self.get(id)
     }
 
     pub fn get_property(&self, id: u32) -> Option<&Property> {
-        self.properties.get(&id)
+        self.get(id)
     }
 
     pub fn get_protective_warding_circle_special_ability(&self, id: u32)
             -> Option<&ProtectiveWardingCircleSpecialAbility> {
-        self.protective_warding_circle_special_abilities.get(&id)
+        self.get(id)
     }
 
     pub fn get_publication(&self, id: u32) -> Option<&Publication> {
-        self.publications.get(&id)
+        self.get(id)
     }
 
     pub fn get_race(&self, id: u32) -> Option<&Race> {
-        self.races.get(&id)
+        self.get(id)
     }
 
     pub fn get_ranged_combat_technique(&self, id: u32)
             -> Option<&RangedCombatTechnique> {
-        self.ranged_combat_techniques.get(&id)
+        self.get(id)
     }
 
     pub fn get_reach(&self, id: u32) -> Option<&Reach> {
-        self.reaches.get(&id)
+        self.get(id)
     }
 
     pub fn get_region(&self, id: u32) -> Option<&Region> {
-        self.regions.get(&id)
+        self.get(id)
     }
 
     pub fn get_ring_enchantment(&self, id: u32) -> Option<&RingEnchantment> {
-        self.ring_enchantments.get(&id)
+        self.get(id)
     }
 
     pub fn get_ritual(&self, id: u32) -> Option<&Ritual> {
-        self.rituals.get(&id)
+        self.get(id)
     }
 
     pub fn get_script(&self, id: u32) -> Option<&Script> {
-        self.scripts.get(&id)
+        self.get(id)
     }
 
     pub fn get_sermon(&self, id: u32) -> Option<&Sermon> {
-        self.sermons.get(&id)
+        self.get(id)
     }
 
     pub fn get_service(&self, id: u32) -> Option<&Service> {
-        self.services.get(&id)
+        self.get(id)
     }
 
     pub fn get_sex_practice(&self, id: u32) -> Option<&SexPractice> {
-        self.sex_practices.get(&id)
+        self.get(id)
     }
 
     pub fn get_sex_special_ability(&self, id: u32)
             -> Option<&SexSpecialAbility> {
-        self.sex_special_abilities.get(&id)
+        self.get(id)
     }
 
     pub fn get_sickle_ritual(&self, id: u32) -> Option<&SickleRitual> {
-        self.sickle_rituals.get(&id)
+        self.get(id)
     }
 
     pub fn get_sikaryan_drain_special_ability(&self, id: u32)
             -> Option<&SikaryanDrainSpecialAbility> {
-        self.sikaryan_drain_special_abilities.get(&id)
+        self.get(id)
     }
 
     pub fn get_skill(&self, id: u32) -> Option<&Skill> {
-        self.skills.get(&id)
+        self.get(id)
     }
 
     pub fn get_skill_group(&self, id: u32) -> Option<&SkillGroup> {
-        self.skill_groups.get(&id)
+        self.get(id)
     }
 
     pub fn get_skill_style_special_ability(&self, id: u32)
             -> Option<&SkillStyleSpecialAbility> {
-        self.skill_style_special_abilities.get(&id)
+        self.get(id)
     }
 
     pub fn get_social_status(&self, id: u32) -> Option<&SocialStatus> {
-        self.social_statuses.get(&id)
+        self.get(id)
     }
 
     pub fn get_special_ability_group(&self, id: u32)
             -> Option<&SpecialAbilityGroup> {
-        self.special_ability_groups.get(&id)
+        self.get(id)
     }
 
     pub fn get_spell(&self, id: u32) -> Option<&Spell> {
-        self.spells.get(&id)
+        self.get(id)
     }
 
     pub fn get_spell_group(&self, id: u32) -> Option<&SpellGroup> {
-        self.spell_groups.get(&id)
+        self.get(id)
     }
 
     pub fn get_spell_sword_enchantment(&self, id: u32)
             -> Option<&SpellSwordEnchantment> {
-        self.spell_sword_enchantments.get(&id)
+        self.get(id)
     }
 
     pub fn get_staff_enchantment(&self, id: u32) -> Option<&StaffEnchantment> {
-        self.staff_enchantments.get(&id)
+        self.get(id)
     }
 
     pub fn get_state(&self, id: u32) -> Option<&State> {
-        self.states.get(&id)
+        self.get(id)
     }
 
     pub fn get_subject(&self, id: u32) -> Option<&Subject> {
-        self.subjects.get(&id)
+        self.get(id)
     }
 
     pub fn get_toy_enchantment(&self, id: u32) -> Option<&ToyEnchantment> {
-        self.toy_enchantments.get(&id)
+        self.get(id)
     }
 
     pub fn get_trade_secret(&self, id: u32) -> Option<&TradeSecret> {
-        self.trade_secrets.get(&id)
+        self.get(id)
     }
 
     pub fn get_tribe(&self, id: u32) -> Option<&Tribe> {
-        self.tribes.get(&id)
+        self.get(id)
     }
 
     pub fn get_trinkhornzauber(&self, id: u32) -> Option<&Trinkhornzauber> {
-        self.trinkhornzauber.get(&id)
+        self.get(id)
     }
 
     pub fn get_vampiric_gift(&self, id: u32) -> Option<&VampiricGift> {
-        self.vampiric_gifts.get(&id)
+        self.get(id)
     }
 
     pub fn get_vision(&self, id: u32) -> Option<&Vision> {
-        self.visions.get(&id)
+        self.get(id)
     }
 
     pub fn get_wand_enchantment(&self, id: u32) -> Option<&WandEnchantment> {
-        self.wand_enchantments.get(&id)
+        self.get(id)
     }
 
     pub fn get_weapon_enchantment(&self, id: u32)
             -> Option<&WeaponEnchantment> {
-        self.weapon_enchantments.get(&id)
+        self.get(id)
     }
 
     pub fn get_zibilja_ritual(&self, id: u32) -> Option<&ZibiljaRitual> {
-        self.zibilja_rituals.get(&id)
+        self.get(id)
+    }
+
+    /// Iterates over the names of the locales for which UI strings are
+    /// loaded, in alphabetical order.
+    pub fn locales(&self) -> impl Iterator<Item = &str> {
+        self.uis.keys().map(String::as_str)
+    }
+
+    /// Gets the UI value with the given key in the locale with the given
+    /// name, which may be a single string or a map of nested strings.
+    pub fn get_ui_value(&self, locale: &str, id: &str) -> Option<&UIValue> {
+        self.uis.get(locale).and_then(|ui| ui.get(id))
+    }
+
+    /// Creates a formatter for citations of source references which selects
+    /// the best available locale of the given chain for each publication.
+    pub fn citations(&self, chain: LocaleChain) -> CitationFormatter<'_> {
+        CitationFormatter::new(self, chain)
+    }
+
+    /// Creates a renderer for the Markdown in localized texts which names
+    /// linked entities in the best available locale of the given chain.
+    #[cfg(feature = "markdown")]
+    pub fn markdown(&self, chain: LocaleChain) -> MarkdownRenderer<'_> {
+        MarkdownRenderer::new(self, chain)
+    }
+
+    /// Creates a lookup of UI strings which selects the best available locale
+    /// of the given chain for each string. See [ui] for the format of keys
+    /// and strings.
+    pub fn messages(&self, chain: LocaleChain) -> Messages<'_> {
+        Messages::new(self, chain)
+    }
+
+    /// Lists the keys of UI strings, including nested ones like `nested.a`,
+    /// which exist in some locale but not in the one they are mapped to.
+    /// Locales without missing strings are omitted.
+    pub fn missing_ui_keys(&self) -> BTreeMap<String, Vec<String>> {
+        ui::missing_keys(&self.uis)
     }
 
     pub fn get_ui_string(&self, locale: &str, id: &str) -> Option<&String> {
         self.uis.get(locale)
-            .map(|ui| ui.get(id)
-                .map(|v|
-                    if let UIValue::Simple(s) = v { Some(s) } else { None })
-                .flatten())
-            .flatten()
+            .and_then(|ui| ui.get(id))
+            .and_then(|v|
+                if let UIValue::Simple(s) = v { Some(s) } else { None })
     }
 
+    /// Gets a copy of the entity with the given ID, regardless of its
+    /// category.
     pub fn get_as_entity(&self, id: Id) -> Option<Entity> {
-        let int_id = id.internal_id();
-
-        match id.category() {
-            Category::AdvancedCombatSpecialAbilities =>
-                to_entity(self.get_advanced_combat_special_ability(int_id)),
-            Category::AdvancedKarmaSpecialAbilities =>
-                to_entity(self.get_advanced_karma_special_ability(int_id)),
-            Category::AdvancedMagicalSpecialAbilities =>
-                to_entity(self.get_advanced_magical_special_ability(int_id)),
-            Category::AdvancedSkillSpecialAbilities =>
-                to_entity(self.get_advanced_skill_special_ability(int_id)),
-            Category::Advantages =>
-                to_entity(self.get_advantage(int_id)),
-            Category::AncestorGlyphs =>
-                to_entity(self.get_ancestor_glyph(int_id)),
-            Category::AnimalDiseases =>
-                to_entity(self.get_animal_disease(int_id)),
-            Category::AnimalShapes =>
-                to_entity(self.get_animal_shape(int_id)),
-            Category::AnimalShapePaths =>
-                to_entity(self.get_animal_shape_path(int_id)),
-            Category::AnimalShapeSizes =>
-                to_entity(self.get_animal_shape_size(int_id)),
-            Category::AnimalTypes =>
-                to_entity(self.get_animal_type(int_id)),
-            Category::AnimistPowers =>
-                to_entity(self.get_animist_power(int_id)),
-            Category::ArcaneBardTraditions =>
-                to_entity(self.get_arcane_bard_tradition(int_id)),
-            Category::ArcaneDancerTraditions =>
-                to_entity(self.get_arcane_dancer_tradition(int_id)),
-            Category::ArcaneOrbEnchantments =>
-                to_entity(self.get_arcane_orb_enchantment(int_id)),
-            Category::ArmorTypes =>
-                to_entity(self.get_armor_type(int_id)),
-            Category::Aspects =>
-                to_entity(self.get_aspect(int_id)),
-            Category::AttireEnchantments =>
-                to_entity(self.get_attire_enchantment(int_id)),
-            Category::Attributes =>
-                to_entity(self.get_attribute(int_id)),
-            Category::BlessedTraditions =>
-                to_entity(self.get_blessed_tradition(int_id)),
-            Category::Blessings =>
-                to_entity(self.get_blessing(int_id)),
-            Category::BowlEnchantments =>
-                to_entity(self.get_bowl_enchantment(int_id)),
-            Category::BrawlingSpecialAbilities =>
-                to_entity(self.get_brawling_special_ability(int_id)),
-            Category::Brews =>
-                to_entity(self.get_brew(int_id)),
-            Category::Cantrips =>
-                to_entity(self.get_cantrip(int_id)),
-            Category::CauldronEnchantments =>
-                to_entity(self.get_cauldron_enchantment(int_id)),
-            Category::CeremonialItemSpecialAbilities =>
-                to_entity(self.get_ceremonial_item_special_ability(int_id)),
-            Category::Ceremonies =>
-                to_entity(self.get_ceremony(int_id)),
-            Category::ChronicleEnchantments =>
-                to_entity(self.get_chronicle_enchantment(int_id)),
-            Category::CombatSpecialAbilities =>
-                to_entity(self.get_combat_special_ability(int_id)),
-            Category::CombatSpecialAbilityGroups =>
-                to_entity(self.get_combat_special_ability_group(int_id)),
-            Category::CombatStyleSpecialAbilities =>
-                to_entity(self.get_combat_style_special_ability(int_id)),
-            Category::CombatTechniqueGroups =>
-                to_entity(self.get_combat_technique_group(int_id)),
-            Category::CommandSpecialAbilities =>
-                to_entity(self.get_command_special_ability(int_id)),
-            Category::Conditions =>
-                to_entity(self.get_condition(int_id)),
-            Category::Cultures =>
-                to_entity(self.get_culture(int_id)),
-            Category::Curricula =>
-                to_entity(self.get_curriculum(int_id)),
-            Category::Curses =>
-                to_entity(self.get_curse(int_id)),
-            Category::DaggerRituals =>
-                to_entity(self.get_dagger_ritual(int_id)),
-            Category::DerivedCharacteristics =>
-                to_entity(self.get_derived_characteristic(int_id)),
-            Category::Disadvantages =>
-                to_entity(self.get_disadvantage(int_id)),
-            Category::Diseases =>
-                to_entity(self.get_disease(int_id)),
-            Category::DominationRituals =>
-                to_entity(self.get_domination_ritual(int_id)),
-            Category::Elements =>
-                to_entity(self.get_element(int_id)),
-            Category::ElvenMagicalSongs =>
-                to_entity(self.get_elven_magical_song(int_id)),
-            Category::EquipmentPackages =>
-                to_entity(self.get_equipment_package(int_id)),
-            Category::ExperienceLevels =>
-                to_entity(self.get_experience_level(int_id)),
-            Category::EyeColors =>
-                to_entity(self.get_eye_color(int_id)),
-            Category::FamiliarSpecialAbilities =>
-                to_entity(self.get_familiar_special_ability(int_id)),
-            Category::FamiliarsTricks =>
-                to_entity(self.get_familiars_trick(int_id)),
-            Category::FatePointSexSpecialAbilities =>
-                to_entity(self.get_fate_point_sex_special_ability(int_id)),
-            Category::FatePointSpecialAbilities =>
-                to_entity(self.get_fate_point_special_ability(int_id)),
-            Category::FocusRules =>
-                to_entity(self.get_focus_rule(int_id)),
-            Category::FoolsHatEnchantments =>
-                to_entity(self.get_fools_hat_enchantment(int_id)),
-            Category::GeneralSpecialAbilities =>
-                to_entity(self.get_general_special_ability(int_id)),
-            Category::GeodeRituals =>
-                to_entity(self.get_geode_ritual(int_id)),
-            Category::Guidelines =>
-                to_entity(self.get_guideline(int_id)),
-            Category::HairColors =>
-                to_entity(self.get_hair_color(int_id)),
-            Category::Influences =>
-                to_entity(self.get_influence(int_id)),
-            Category::InstrumentEnchantments =>
-                to_entity(self.get_instrument_enchantment(int_id)),
-            Category::ItemGroups =>
-                to_entity(self.get_item_group(int_id)),
-            Category::JesterTricks =>
-                to_entity(self.get_jester_trick(int_id)),
-            Category::KarmaSpecialAbilities =>
-                to_entity(self.get_karma_special_ability(int_id)),
-            Category::Krallenkettenzauber =>
-                to_entity(self.get_krallenkettenzauber(int_id)),
-            Category::Languages =>
-                to_entity(self.get_language(int_id)),
-            Category::LiturgicalChants =>
-                to_entity(self.get_liturgical_chant(int_id)),
-            Category::LiturgicalChantGroups =>
-                to_entity(self.get_liturgical_chant_group(int_id)),
-            Category::LiturgicalStyleSpecialAbilities =>
-                to_entity(self.get_liturgical_style_special_ability(int_id)),
-            Category::LycantropicGifts =>
-                to_entity(self.get_lycantropic_gift(int_id)),
-            Category::MagicalDances =>
-                to_entity(self.get_magical_dance(int_id)),
-            Category::MagicalMelodies =>
-                to_entity(self.get_magical_melody(int_id)),
-            Category::MagicalRunes =>
-                to_entity(self.get_magical_rune(int_id)),
-            Category::MagicalSpecialAbilities =>
-                to_entity(self.get_magical_special_ability(int_id)),
-            Category::MagicalTraditions =>
-                to_entity(self.get_magical_tradition(int_id)),
-            Category::MagicalTraditionPlaceholders =>
-                to_entity(self.get_magical_tradition_placeholder(int_id)),
-            Category::MagicStyleSpecialAbilities =>
-                to_entity(self.get_magic_style_special_ability(int_id)),
-            Category::MeleeCombatTechniques =>
-                to_entity(self.get_melee_combat_technique(int_id)),
-            Category::OptionalRules =>
-                to_entity(self.get_optional_rule(int_id)),
-            Category::OrbEnchantments =>
-                to_entity(self.get_orb_enchantment(int_id)),
-            Category::PactCategories =>
-                to_entity(self.get_pact_category(int_id)),
-            Category::PactGifts =>
-                to_entity(self.get_pact_gift(int_id)),
-            Category::Patrons =>
-                to_entity(self.get_patron(int_id)),
-            Category::PatronCategories =>
-                to_entity(self.get_patron_categories(int_id)),
-            Category::PersonalityTraits =>
-                to_entity(self.get_personality_trait(int_id)),
-            Category::Poisons =>
-                to_entity(self.get_poison(int_id)),
-            Category::Professions =>
-                to_entity(self.get_profession(int_id)),
-            Category::Properties =>
-                to_entity(self.get_property(int_id)),
-            Category::ProtectiveWardingCircleSpecialAbilities =>
-                to_entity(self.get_protective_warding_circle_special_ability(
-                    int_id)),
-            Category::Publications =>
-                to_entity(self.get_publication(int_id)),
-            Category::Races =>
-                to_entity(self.get_race(int_id)),
-            Category::RangedCombatTechniques =>
-                to_entity(self.get_ranged_combat_technique(int_id)),
-            Category::Reaches =>
-                to_entity(self.get_reach(int_id)),
-            Category::Regions =>
-                to_entity(self.get_region(int_id)),
-            Category::RingEnchantments =>
-                to_entity(self.get_ring_enchantment(int_id)),
-            Category::Rituals =>
-                to_entity(self.get_ritual(int_id)),
-            Category::Scripts =>
-                to_entity(self.get_script(int_id)),
-            Category::Sermons =>
-                to_entity(self.get_sermon(int_id)),
-            Category::Services =>
-                to_entity(self.get_service(int_id)),
-            Category::SexPractices =>
-                to_entity(self.get_sex_practice(int_id)),
-            Category::SexSpecialAbilities =>
-                to_entity(self.get_sex_special_ability(int_id)),
-            Category::SickleRituals =>
-                to_entity(self.get_sickle_ritual(int_id)),
-            Category::SikaryanDrainSpecialAbilities =>
-                to_entity(self.get_sikaryan_drain_special_ability(int_id)),
-            Category::Skills =>
-                to_entity(self.get_skill(int_id)),
-            Category::SkillGroups =>
-                to_entity(self.get_skill_group(int_id)),
-            Category::SkillStyleSpecialAbilities =>
-                to_entity(self.get_skill_style_special_ability(int_id)),
-            Category::SocialStatuses =>
-                to_entity(self.get_social_status(int_id)),
-            Category::SpecialAbilityGroups =>
-                to_entity(self.get_special_ability_group(int_id)),
-            Category::Spells =>
-                to_entity(self.get_spell(int_id)),
-            Category::SpellGroups =>
-                to_entity(self.get_spell_group(int_id)),
-            Category::SpellSwordEnchantments =>
-                to_entity(self.get_spell_sword_enchantment(int_id)),
-            Category::StaffEnchantments =>
-                to_entity(self.get_staff_enchantment(int_id)),
-            Category::States =>
-                to_entity(self.get_state(int_id)),
-            Category::Subjects =>
-                to_entity(self.get_subject(int_id)),
-            Category::ToyEnchantments =>
-                to_entity(self.get_toy_enchantment(int_id)),
-            Category::TradeSecrets =>
-                to_entity(self.get_trade_secret(int_id)),
-            Category::Tribes =>
-                to_entity(self.get_tribe(int_id)),
-            Category::Trinkhornzauber =>
-                to_entity(self.get_trinkhornzauber(int_id)),
-            Category::VampiricGifts =>
-                to_entity(self.get_vampiric_gift(int_id)),
-            Category::Visions =>
-                to_entity(self.get_vision(int_id)),
-            Category::WandEnchantments =>
-                to_entity(self.get_wand_enchantment(int_id)),
-            Category::WeaponEnchantments =>
-                to_entity(self.get_weapon_enchantment(int_id)),
-            Category::ZibiljaRituals =>
-                to_entity(self.get_zibilja_ritual(int_id)),
-            _ => None // TODO update until all are implemented
-        }
+        self.get_as_entity_ref(id).map(Entity::from)
+    }
+
+    /// Gets a reference to the entity with the given ID, regardless of its
+    /// category.
+    pub fn get_as_entity_ref(&self, id: Id) -> Option<EntityRef<'_>> {
+        with_categories!(get_entity_ref!(self, id,))
+    }
+
+    /// Checks that all references between entities, including those in
+    /// prerequisites and select options, point to entities that exist. The
+    /// dangling references are returned in the order of their categories and
+    /// source IDs.
+    pub fn validate(&self) -> Vec<DanglingReference> {
+        let mut dangling = Vec::new();
+        with_categories!(validate_categories!(self, dangling,));
+        dangling
+    }
+
+    /// Compares this data, as the old version, with the given new version by
+    /// the IDs of their entities. UI strings and the overlay packs entities
+    /// come from are not compared.
+    pub fn diff(&self, new: &OptolithData) -> DataDiff {
+        let mut diff = DataDiff::default();
+        with_categories!(diff_categories!(self, new, diff,));
+        diff
+    }
+
+    /// Collects the errata of all entities in the given locale, in the order
+    /// of their categories and IDs. Only errata of entities themselves are
+    /// included, not those of their parts, such as select options or spell
+    /// enhancements.
+    pub fn errata(&self, locale: &str) -> ErrataFeed {
+        let mut feed = ErrataFeed::default();
+        with_categories!(collect_errata!(self, feed, locale,));
+        feed
+    }
+
+    /// Gets the index of the entities that require each entity in their
+    /// prerequisites. Since this traverses all entities, it is built on the
+    /// first call and kept until the entities are changed by reloading a
+    /// file, removing an entity or applying an overlay pack.
+    pub fn dependent_index(&self) -> &DependentIndex {
+        self.dependent_index.get_or_init(|| {
+            let mut index = DependentIndex::default();
+            with_categories!(index_dependents!(self, index,));
+            index
+        })
     }
-}
 
-fn to_entity<'a, T>(opt_t: Option<&'a T>) -> Option<Entity>
-where
-    Entity: From<&'a T>,
-    T: 'a
-{
-    Some(Entity::from(opt_t?))
+    /// Builds an index for searching all entities by their localized names
+    /// and, depending on the given options, texts.
+    pub fn search_index(&self, options: &SearchOptions) -> SearchIndex {
+        let mut index = SearchIndex::default();
+        with_categories!(index_categories!(self, index, options,));
+
+        // the variants of professions have localizations of their own
+        for profession in self.iter::<Profession>() {
+            index.add_profession_variants(profession, options);
+        }
+
+        index
+    }
 }
 
 /// A trait for localizations for entities. The minimal requirement for each
@@ -1634,11 +1969,42 @@ pub trait Localization {
     fn name_as_select_option(&self) -> &str {
         self.name()
     }
+
+    /// The name to display if the entity is not shown for a specific
+    /// character, such as in links or reports. By default, this is the
+    /// [name](Localization::name).
+    fn display_name(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self.name())
+    }
+
+    /// All forms of the name of the entity, which are equally good matches in
+    /// searches, such as the male and female forms of a profession name. By
+    /// default, this is only the [name](Localization::name).
+    fn name_forms(&self) -> Vec<&str> {
+        vec![self.name()]
+    }
+
+    /// Alternative names of the entity, such as regional names. By default,
+    /// there are none.
+    fn alternative_names(&self) -> Vec<&str> {
+        Vec::new()
+    }
+
+    /// The longer texts of the entity, such as its rules, effect or
+    /// description. By default, there are none.
+    fn texts(&self) -> Vec<&str> {
+        Vec::new()
+    }
+
+    /// The errata of the entity in this locale. By default, there are none.
+    fn errata(&self) -> &[Erratum] {
+        &[]
+    }
 }
 
 impl Localization for String {
     fn name(&self) -> &str {
-        &self
+        self
     }
 }
 
@@ -1650,15 +2016,53 @@ impl<L: Localization> Localization for &L {
     fn name_as_select_option(&self) -> &str {
         (*self).name_as_select_option()
     }
+
+    fn display_name(&self) -> Cow<'_, str> {
+        (*self).display_name()
+    }
+
+    fn name_forms(&self) -> Vec<&str> {
+        (*self).name_forms()
+    }
+
+    fn alternative_names(&self) -> Vec<&str> {
+        (*self).alternative_names()
+    }
+
+    fn texts(&self) -> Vec<&str> {
+        (*self).texts()
+    }
+
+    fn errata(&self) -> &[Erratum] {
+        (*self).errata()
+    }
 }
 
 /// A map of language identifiers to [Localization]s of type `L`.
-pub type Translations<L> = HashMap<String, L>;
+pub type Translations<L> = BTreeMap<String, L>;
 
 /// A trait for entities which are translatable, i.e. for which translations
 /// of some [Localization] type exist.
 pub trait Translatable<'a, L: Localization> {
+
+    /// Gets the translation into exactly the locale with the given name.
     fn translate(&'a self, locale: &str) -> Option<L>;
+
+    /// The names of all locales this entity is translated into.
+    fn locales(&'a self) -> Vec<&'a str>;
+
+    /// Gets the translation into the best available locale according to the
+    /// given [LocaleChain], together with the locale that was used.
+    fn translate_negotiated(&'a self, chain: &LocaleChain)
+            -> Option<Translation<L>> {
+        let locale = chain.negotiate(self.locales())?;
+
+        Some(Translation {
+            localization: self.translate(locale)?,
+            locale: locale.to_owned(),
+            is_fallback: !chain.is_preferred(locale)
+        })
+    }
 }
 
 /// A trait for entities which are translatable by providing [Translations] of
@@ -1678,9 +2082,14 @@ where
     fn translate(&'a self, locale: &str) -> Option<&'a L> {
         self.translations().get(locale)
     }
+
+    fn locales(&'a self) -> Vec<&'a str> {
+        self.translations().keys().map(String::as_str).collect()
+    }
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(untagged)]
 pub enum SingleOrList<T> {
     Single(T),
@@ -1690,6 +2099,7 @@ pub enum SingleOrList<T> {
 pub type Ids = SingleOrList<u32>;
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct SuggestedUnsuitable {
     pub id: u32
 }
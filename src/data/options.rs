@@ -0,0 +1,161 @@
+use crate::id::Category;
+use crate::util;
+
+use std::collections::{HashMap, HashSet};
+
+/// The ID from which on entries are considered placeholders by default.
+pub const DEFAULT_PLACEHOLDER_THRESHOLD: u32 = 1000;
+
+// TODO remove once sorted out
+const EXCLUDED_FILES: &[&str] = &[
+    "25_Waldschrate.yml",
+    "5_Toad-Poison.yml",
+    "53_Mond.yml",
+    "128_Keulenweihe.yml",
+    "129_Keulenwurf.yml"
+];
+
+/// Selects files by their name using glob patterns, which may contain `*` to
+/// match any sequence of characters and `?` to match a single character.
+#[derive(Clone, Default)]
+pub struct FileFilter {
+
+    /// If not empty, only files matching at least one of these patterns are
+    /// selected.
+    pub include: Vec<String>,
+
+    /// Files matching any of these patterns are not selected, even if they
+    /// match an `include` pattern.
+    pub exclude: Vec<String>
+}
+
+impl FileFilter {
+
+    /// Indicates whether the file with the given name passes this filter.
+    pub fn matches(&self, file_name: &str) -> bool {
+        let included = self.include.is_empty() ||
+            self.include.iter().any(|p| util::matches_glob(p, file_name));
+
+        included &&
+            !self.exclude.iter().any(|p| util::matches_glob(p, file_name))
+    }
+}
+
+/// Options that control how the Optolith data is loaded from a directory.
+#[derive(Clone)]
+pub struct LoadOptions {
+
+    /// Files whose ID is at least this value are considered placeholders for
+    /// entries that are still work in progress. Files whose name does not
+    /// start with a numeric ID are always considered placeholders.
+    pub placeholder_threshold: u32,
+
+    /// If `true`, placeholders are loaded like any other file.
+    pub load_placeholders: bool,
+
+    /// A filter applied to the files of every category. By default, it
+    /// excludes some files which currently do not match the model. Clear its
+    /// `exclude` patterns to load them anyway.
+    pub filter: FileFilter,
+
+    /// Filters applied to the files of single categories in addition to
+    /// `filter`.
+    pub category_filters: HashMap<Category, FileFilter>,
+
+    /// If `true`, files that cannot be read or parsed are skipped and reported
+    /// instead of aborting the loading process.
+    pub lenient: bool,
+
+    /// Categories which are always loaded leniently, even if `lenient` is
+    /// `false`. Their files that cannot be read or parsed are reported in the
+    /// [LoadReport](crate::data::LoadReport) without making the whole loading
+    /// process fail. By default, these are [Category::Curricula] and
+    /// [Category::Professions], whose models are the most involved ones, so
+    /// each of their mismatching files is reported on its own.
+    pub lenient_categories: HashSet<Category>,
+
+    /// If set, only translations into these locales are kept in every
+    /// `Translations` map and only UI strings of these locales are loaded,
+    /// which considerably reduces the memory usage. Entities which lack some
+    /// of these locales are listed in the
+    /// [LoadReport](crate::data::LoadReport). Note that errors in the data
+    /// files are reported with the path of the field, but without a line and
+    /// column if this is set.
+    pub locales: Option<HashSet<String>>,
+
+    /// If `true`, categories and the files within them are loaded
+    /// concurrently.
+    #[cfg(feature = "parallel")]
+    pub parallel: bool
+}
+
+impl Default for LoadOptions {
+    fn default() -> LoadOptions {
+        LoadOptions {
+            placeholder_threshold: DEFAULT_PLACEHOLDER_THRESHOLD,
+            load_placeholders: false,
+            filter: FileFilter {
+                include: Vec::new(),
+                exclude: EXCLUDED_FILES.iter()
+                    .map(|&f| String::from(f))
+                    .collect()
+            },
+            category_filters: HashMap::new(),
+            lenient: false,
+            lenient_categories: vec![Category::Curricula, Category::Professions]
+                .into_iter()
+                .collect(),
+            locales: None,
+            #[cfg(feature = "parallel")]
+            parallel: false
+        }
+    }
+}
+
+impl LoadOptions {
+
+    /// Indicates whether the file with the given name is a placeholder
+    /// according to these options.
+    pub fn is_placeholder(&self, file_name: &str) -> bool {
+        match file_name.find('_') {
+            Some(underscore) => match file_name[..underscore].parse::<u32>() {
+                Ok(id) => id >= self.placeholder_threshold,
+                Err(_) => true
+            },
+            None => true
+        }
+    }
+
+    /// Indicates whether files of the given category that cannot be read or
+    /// parsed are skipped and reported with these options.
+    pub fn is_lenient(&self, category: Category) -> bool {
+        self.lenient || self.lenient_categories.contains(&category)
+    }
+
+    /// Indicates whether translations into the locale with the given name are
+    /// loaded with these options.
+    pub fn is_locale_loaded(&self, locale: &str) -> bool {
+        self.locales.as_ref()
+            .map(|locales| locales.contains(locale))
+            .unwrap_or(true)
+    }
+
+    /// Indicates whether the file with the given name in the directory of the
+    /// given category passes `filter` and the filter of the category, if any.
+    /// Unlike [LoadOptions::is_loaded], this does not consider placeholders.
+    pub fn is_selected(&self, category: Category, file_name: &str) -> bool {
+        self.filter.matches(file_name) && self.category_filters.get(&category)
+            .map(|f| f.matches(file_name))
+            .unwrap_or(true)
+    }
+
+    /// Indicates whether the file with the given name in the directory of the
+    /// given category is loaded with these options.
+    pub fn is_loaded(&self, category: Category, file_name: &str) -> bool {
+        if !self.load_placeholders && self.is_placeholder(file_name) {
+            return false;
+        }
+
+        self.is_selected(category, file_name)
+    }
+}
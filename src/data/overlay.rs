@@ -0,0 +1,373 @@
+//! Overlay packs, such as homebrew content or house rules, which are applied
+//! on top of loaded data. An overlay pack is a directory with the same layout
+//! as the Optolith data directory, but it only needs to contain the
+//! categories it changes. Each file of a category directory either
+//!
+//! * defines an entity, which is added to the data or overrides the entity
+//!   with the same ID, or
+//! * patches the translations of an existing entity. Such files are marked
+//!   by the extension `.patch.yml` and only contain the `id` and the
+//!   `translations` of the entity. Their translations are merged into those
+//!   of the entity, so it suffices to specify the changed fields. Since they
+//!   do not define entities, patches are never considered placeholders.
+//!
+//! For every entity, the data records the pack that defined it and the packs
+//! that patched it.
+//!
+//! The UI strings of an overlay pack are merged into those of the data.
+
+use crate::data::{IdMap, IdMapBuilder, MissingLocales, UI, UI_DIR};
+use crate::data::locale_name;
+use crate::error::{
+    self,
+    OptolithDataError,
+    OptolithDataErrorKind,
+    OptolithDataResult
+};
+use crate::id::{Category, Id, Identifiable};
+use crate::source;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_yaml::{Mapping, Value};
+
+use std::collections::BTreeMap;
+use std::io;
+use std::path::Path;
+
+const PATCH_EXTENSION: &str = ".patch.yml";
+
+/// A conflict between overlay packs or between an overlay pack and the data
+/// it is applied to.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum OverlayConflict {
+
+    /// An entity of the data the pack is applied to was defined by an
+    /// overlay pack. The definition of the pack is used.
+    Overridden {
+        id: Id,
+        pack: String
+    },
+
+    /// An entity that was already defined by an overlay pack was defined
+    /// again. The later definition is used.
+    Redefined {
+        id: Id,
+        previous_pack: String,
+        pack: String
+    },
+
+    /// A patch refers to an entity that does not exist. The patch is
+    /// ignored.
+    MissingEntity {
+        id: Id,
+        pack: String
+    }
+}
+
+/// The result of applying one or more overlay packs.
+#[derive(Default)]
+pub struct OverlayReport {
+    pub(super) failures: Vec<OptolithDataError>,
+    pub(super) missing_locales: Vec<MissingLocales>,
+    pub(super) conflicts: Vec<OverlayConflict>
+}
+
+impl OverlayReport {
+
+    /// The errors of all files of lenient categories that could not be
+    /// loaded, sorted by file path. See
+    /// [LoadReport::failures](crate::data::LoadReport::failures).
+    pub fn failures(&self) -> &[OptolithDataError] {
+        &self.failures
+    }
+
+    /// The entities of the overlay packs which lack requested locales. See
+    /// [LoadReport::missing_locales](crate::data::LoadReport::missing_locales).
+    pub fn missing_locales(&self) -> &[MissingLocales] {
+        &self.missing_locales
+    }
+
+    /// The conflicts that occurred, in the order the overlay packs were
+    /// applied.
+    pub fn conflicts(&self) -> &[OverlayConflict] {
+        &self.conflicts
+    }
+
+    /// Appends the contents of the given report to this one.
+    pub fn append(&mut self, mut other: OverlayReport) {
+        self.failures.append(&mut other.failures);
+        self.missing_locales.append(&mut other.missing_locales);
+        self.conflicts.append(&mut other.conflicts);
+    }
+}
+
+/// The overlay packs a single entity comes from.
+#[derive(Clone, Default, Deserialize, Serialize)]
+struct Origin {
+
+    /// The pack that defined the entity, or `None` if it is part of the data
+    /// the packs were applied to.
+    defined_by: Option<String>,
+
+    /// The packs that patched the translations of the entity since it was
+    /// defined, in the order they were applied.
+    patched_by: Vec<String>
+}
+
+/// Records for every entity defined or patched by an overlay pack the names
+/// of these packs. As IDs are no valid keys of JSON objects, this is
+/// serialized as a list of pairs.
+#[derive(Clone, Default)]
+pub(super) struct Origins(BTreeMap<Id, Origin>);
+
+impl Origins {
+    pub(super) fn get(&self, id: Id) -> Option<&str> {
+        self.0.get(&id)?.defined_by.as_deref()
+    }
+
+    pub(super) fn patches(&self, id: Id) -> &[String] {
+        self.0.get(&id)
+            .map(|origin| origin.patched_by.as_slice())
+            .unwrap_or_default()
+    }
+
+    pub(super) fn remove(&mut self, id: Id) {
+        self.0.remove(&id);
+    }
+
+    /// Records that the entity with the given ID was defined by the pack with
+    /// the given name, which replaces any patches. Returns the pack that
+    /// previously defined it, if any.
+    fn define(&mut self, id: Id, pack: &str) -> Option<String> {
+        let origin = Origin {
+            defined_by: Some(String::from(pack)),
+            patched_by: Vec::new()
+        };
+
+        self.0.insert(id, origin)?.defined_by
+    }
+
+    /// Records that the entity with the given ID was patched by the pack with
+    /// the given name.
+    fn patch(&mut self, id: Id, pack: &str) {
+        self.0.entry(id).or_default().patched_by.push(String::from(pack));
+    }
+}
+
+impl Serialize for Origins {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer
+    {
+        serializer.collect_seq(self.0.iter())
+    }
+}
+
+impl<'de> Deserialize<'de> for Origins {
+    fn deserialize<D>(deserializer: D) -> Result<Origins, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        let pairs = Vec::<(Id, Origin)>::deserialize(deserializer)?;
+        Ok(Origins(pairs.into_iter().collect()))
+    }
+}
+
+/// The content of a file that patches the translations of an entity.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TranslationsPatch {
+    id: u32,
+    translations: Mapping
+}
+
+fn is_not_found(e: &OptolithDataError) -> bool {
+    match e.kind() {
+        OptolithDataErrorKind::IOError(e) =>
+            e.kind() == io::ErrorKind::NotFound,
+        _ => false
+    }
+}
+
+/// Merges the given patch into the given value. Mappings are merged key by
+/// key, any other value is replaced.
+fn merge(value: &mut Value, patch: Value) {
+    match (value, patch) {
+        (Value::Mapping(map), Value::Mapping(patch)) => {
+            for (key, patch_value) in patch {
+                match map.get_mut(&key) {
+                    Some(value) => merge(value, patch_value),
+                    None => {
+                        map.insert(key, patch_value);
+                    }
+                }
+            }
+        },
+        (value, patch) => *value = patch
+    }
+}
+
+/// Applies the files of a single overlay pack.
+pub(super) struct OverlayLoader<'a> {
+    pub(super) builder: IdMapBuilder<'a>,
+    pack: &'a str,
+    conflicts: Vec<OverlayConflict>
+}
+
+impl<'a> OverlayLoader<'a> {
+    pub(super) fn new(builder: IdMapBuilder<'a>, pack: &'a str)
+            -> OverlayLoader<'a> {
+        OverlayLoader {
+            builder,
+            pack,
+            conflicts: Vec::new()
+        }
+    }
+
+    /// Lists the files of the directory with the given name, which is empty
+    /// if the pack does not contain the directory.
+    fn list_files(&self, lenient: bool, dir_name: &str)
+            -> OptolithDataResult<Vec<String>> {
+        let dir = source::join(self.builder.path, dir_name);
+
+        match self.builder.source.list_files(&dir) {
+            Ok(files) => Ok(files),
+            Err(e) if is_not_found(&e) => Ok(Vec::new()),
+            Err(e) => Ok(self.builder.check(lenient, Err(e))?
+                .unwrap_or_default())
+        }
+    }
+
+    pub(super) fn apply<V>(&mut self, origins: &mut Origins,
+        map: &mut IdMap<V>, category: Category, dir_name: &str)
+        -> OptolithDataResult<()>
+    where
+        for<'de> V : Deserialize<'de> + Identifiable + Serialize
+    {
+        let options = self.builder.options;
+        let lenient = options.is_lenient(category);
+
+        for file_name in self.list_files(lenient, dir_name)? {
+            let is_patch = file_name.ends_with(PATCH_EXTENSION);
+            let is_loaded = if is_patch {
+                options.is_selected(category, &file_name)
+            }
+            else {
+                options.is_loaded(category, &file_name)
+            };
+
+            if !is_loaded {
+                continue;
+            }
+
+            let result = if is_patch {
+                self.patch(origins, map, category, dir_name, &file_name)
+            }
+            else {
+                self.define(origins, map, category, dir_name, &file_name)
+            };
+
+            self.builder.check(lenient, result)?;
+        }
+
+        Ok(())
+    }
+
+    fn define<V>(&mut self, origins: &mut Origins, map: &mut IdMap<V>,
+        category: Category, dir_name: &str, file_name: &str)
+        -> OptolithDataResult<()>
+    where
+        for<'de> V : Deserialize<'de> + Identifiable
+    {
+        let entity: V =
+            self.builder.load_file(Some(category), dir_name, file_name)?;
+        let id = entity.id();
+        let previous = map.insert(id.internal_id(), entity);
+
+        if let Some(previous_pack) = origins.define(id, self.pack) {
+            self.conflicts.push(OverlayConflict::Redefined {
+                id,
+                previous_pack,
+                pack: String::from(self.pack)
+            });
+        }
+        else if previous.is_some() {
+            self.conflicts.push(OverlayConflict::Overridden {
+                id,
+                pack: String::from(self.pack)
+            });
+        }
+
+        Ok(())
+    }
+
+    fn patch<V>(&mut self, origins: &mut Origins, map: &mut IdMap<V>,
+        category: Category, dir_name: &str, file_name: &str)
+        -> OptolithDataResult<()>
+    where
+        for<'de> V : Deserialize<'de> + Serialize
+    {
+        let TranslationsPatch { id: internal_id, translations } =
+            self.builder.load_file(None, dir_name, file_name)?;
+        let entity = match map.get(&internal_id) {
+            Some(entity) => entity,
+            None => {
+                self.conflicts.push(OverlayConflict::MissingEntity {
+                    id: Id::new(category, internal_id),
+                    pack: String::from(self.pack)
+                });
+                return Ok(());
+            }
+        };
+        let path = source::join(&source::join(self.builder.path, dir_name),
+            file_name);
+        let mut res = serde_yaml::to_value(entity)
+            .map_err(Into::into)
+            .and_then(|mut value| {
+                let patch = Value::Mapping(translations);
+
+                if let Value::Mapping(entity_map) = &mut value {
+                    let translations = entity_map.entry("translations".into())
+                        .or_insert(Value::Mapping(Mapping::new()));
+                    merge(translations, patch);
+                }
+
+                serde_path_to_error::deserialize(value).map_err(Into::into)
+            });
+        error::set_file(&mut res, Path::new(&path));
+        map.insert(internal_id, res?);
+        origins.patch(Id::new(category, internal_id), self.pack);
+        Ok(())
+    }
+
+    pub(super) fn apply_uis(&mut self, uis: &mut BTreeMap<String, UI>)
+            -> OptolithDataResult<()> {
+        let options = self.builder.options;
+
+        for file_name in self.list_files(options.lenient, UI_DIR)? {
+            let locale = locale_name(&file_name);
+
+            if !options.is_locale_loaded(&locale) {
+                continue;
+            }
+
+            let ui = self.builder.load_file::<UI>(None, UI_DIR, &file_name);
+
+            if let Some(ui) = self.builder.check(options.lenient, ui)? {
+                uis.entry(locale).or_default().extend(ui);
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn into_report(self) -> OverlayReport {
+        let (failures, missing_locales) = self.builder.into_findings();
+
+        OverlayReport {
+            failures,
+            missing_locales,
+            conflicts: self.conflicts
+        }
+    }
+}
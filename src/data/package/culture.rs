@@ -4,14 +4,18 @@ use crate::data::{
     TranslationsTranslatable,
     Translations
 };
-use crate::data::errata::Errata;
+use crate::data::errata::{Errata, Erratum};
 use crate::data::package::PackageSkill;
 use crate::data::src::SourceRefs;
 use crate::id::{Category, Id, Identifiable};
 
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum ProfessionsException {
@@ -22,6 +26,7 @@ pub enum ProfessionsException {
 pub type ProfessionsExceptions = Vec<ProfessionsException>;
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct CultureLocalization {
 
@@ -74,9 +79,14 @@ impl Localization for CultureLocalization {
     fn name(&self) -> &str {
         &self.name
     }
+
+    fn errata(&self) -> &[Erratum] {
+        self.errata.as_deref().unwrap_or_default()
+    }
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Culture {
     pub id: u32,
@@ -8,7 +8,7 @@ use crate::data::{
 };
 use crate::data::activatable::SelectOptionId;
 use crate::data::activatable::special_ability::SpecialAbilityId;
-use crate::data::errata::Errata;
+use crate::data::errata::{Errata, Erratum};
 use crate::data::package::PackageSkill;
 use crate::data::prerequisite::ProfessionListPrerequisite;
 use crate::data::skill::combat::CombatTechniqueId;
@@ -19,10 +19,17 @@ use crate::data::skill::non_profane::magical::{
 };
 use crate::data::src::SourceRefs;
 use crate::id::{Category, Id, Identifiable};
+use crate::Sex;
 
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+
+use std::borrow::Cow;
+
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum SkillSpecializationSelectOption {
@@ -34,6 +41,7 @@ pub enum SkillSpecializationSelectOption {
 /// get a specialization for. You can also specify one or multiple skill groups
 /// from which you can choose a spell.
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(untagged)]
 #[serde(deny_unknown_fields)]
 pub enum SkillSpecializationSelectOptions {
@@ -42,6 +50,7 @@ pub enum SkillSpecializationSelectOptions {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct SelectableCombatTechniques {
 
@@ -55,6 +64,7 @@ pub struct SelectableCombatTechniques {
 
 /// Select one or more combat techniques you get a CtR bonus for.
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct CombatTechniqueSelectOptions {
 
@@ -73,6 +83,7 @@ pub struct CombatTechniqueSelectOptions {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct CantripSelectOptions {
 
@@ -84,6 +95,7 @@ pub struct CantripSelectOptions {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct SkillSelectOptions {
 
@@ -95,6 +107,7 @@ pub struct SkillSelectOptions {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct SpellSelectOption {
     pub id: StandardSpellworkId,
@@ -110,6 +123,7 @@ pub type SpellSelectOptions = Vec<Vec<SpellSelectOption>>;
 pub type LiturgicalChantSelectOptions = Vec<Vec<ProfessionLiturgicalChant>>;
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct ProfessionOptions {
     #[serde(rename = "skillSpecialization")]
@@ -137,6 +151,7 @@ pub struct ProfessionOptions {
 
 /// A special ability contained in a profession package.
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct ProfessionSpecialAbility {
     pub id: SpecialAbilityId,
@@ -152,6 +167,7 @@ pub struct ProfessionSpecialAbility {
 pub type ProfessionSpecialAbilities = SingleOrList<ProfessionSpecialAbility>;
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct ProfessionCombatTechnique {
     pub id: CombatTechniqueId,
@@ -162,6 +178,7 @@ pub struct ProfessionCombatTechnique {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct ProfessionSpell {
     pub id: SpellworkId,
@@ -171,6 +188,7 @@ pub struct ProfessionSpell {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct ProfessionLiturgicalChant {
     pub id: KarmalWorksId,
@@ -180,6 +198,7 @@ pub struct ProfessionLiturgicalChant {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum ProfessionVariantSelectOptions<T> {
@@ -188,6 +207,7 @@ pub enum ProfessionVariantSelectOptions<T> {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct ProfessionVariantOptions {
 
@@ -236,6 +256,7 @@ pub struct ProfessionVariantOptions {
 
 /// A special ability contained in or removed by a profession variant package.
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct ProfessionVariantSpecialAbility {
     pub id: SpecialAbilityId,
@@ -252,6 +273,7 @@ pub struct ProfessionVariantSpecialAbility {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct ProfessionVariantCombatTechnique {
     pub id: CombatTechniqueId,
@@ -262,6 +284,7 @@ pub struct ProfessionVariantCombatTechnique {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct ProfessionVariantSkill {
     pub id: u32,
@@ -271,6 +294,7 @@ pub struct ProfessionVariantSkill {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct ProfessionVariantSpell {
     pub id: SpellworkId,
@@ -282,6 +306,7 @@ pub struct ProfessionVariantSpell {
 pub type ProfessionVariantSpells = SingleOrList<ProfessionVariantSpell>;
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct ProfessionVariantLiturgicalChant {
     pub id: KarmalWorksId,
@@ -296,6 +321,7 @@ pub type ProfessionVariantLiturgicalChants =
 /// If a profession name is different for male and female heroes, use this
 /// object.
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct NameBySex {
 
@@ -307,6 +333,7 @@ pub struct NameBySex {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(untagged)]
 #[serde(deny_unknown_fields)]
 pub enum NameMaybeBySex {
@@ -314,7 +341,57 @@ pub enum NameMaybeBySex {
     BySex(NameBySex)
 }
 
+/// The form in which to resolve a name that may differ by sex.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NameForm {
+    Female,
+    Male,
+
+    /// Both forms, separated by a slash, such as `Krieger/Kriegerin`, for
+    /// when no specific character is concerned.
+    Neutral
+}
+
+impl From<Sex> for NameForm {
+    fn from(sex: Sex) -> NameForm {
+        match sex {
+            Sex::Female => NameForm::Female,
+            Sex::Male => NameForm::Male
+        }
+    }
+}
+
+impl NameMaybeBySex {
+
+    /// Gets all distinct forms of the name, which are the male and the female
+    /// form if they differ.
+    pub fn forms(&self) -> Vec<&str> {
+        match self {
+            NameMaybeBySex::Universal(name) => vec![name],
+            NameMaybeBySex::BySex(nbs) if nbs.m == nbs.f => vec![&nbs.m],
+            NameMaybeBySex::BySex(nbs) => vec![&nbs.m, &nbs.f]
+        }
+    }
+
+    /// Gets the name in the given form. A universal name is the same in all
+    /// forms, and so is a name whose male and female forms are equal.
+    pub fn resolve(&self, form: NameForm) -> Cow<'_, str> {
+        match (self, form) {
+            (NameMaybeBySex::Universal(name), _) => Cow::Borrowed(name),
+            (NameMaybeBySex::BySex(nbs), NameForm::Female) =>
+                Cow::Borrowed(&nbs.f),
+            (NameMaybeBySex::BySex(nbs), NameForm::Male) =>
+                Cow::Borrowed(&nbs.m),
+            (NameMaybeBySex::BySex(nbs), NameForm::Neutral) if nbs.m == nbs.f =>
+                Cow::Borrowed(&nbs.m),
+            (NameMaybeBySex::BySex(nbs), NameForm::Neutral) =>
+                Cow::Owned(format!("{}/{}", nbs.m, nbs.f))
+        }
+    }
+}
+
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct ProfessionVariantLocalization {
     pub name: NameMaybeBySex,
@@ -332,7 +409,17 @@ pub struct ProfessionVariantLocalization {
     pub concluding_text: Option<String>,
 }
 
+impl ProfessionVariantLocalization {
+
+    /// Gets the name of the variant in the given form, e.g. for the sex of a
+    /// character.
+    pub fn name_for(&self, form: impl Into<NameForm>) -> Cow<'_, str> {
+        self.name.resolve(form.into())
+    }
+}
+
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct ProfessionVariant {
     pub id: u32,
@@ -383,6 +470,7 @@ pub struct ProfessionVariant {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct ProfessionLocalization {
     pub name: NameMaybeBySex,
@@ -412,18 +500,73 @@ pub struct ProfessionLocalization {
     pub errata: Option<Errata>
 }
 
+impl ProfessionLocalization {
+
+    /// Gets the name of the profession in the given form, e.g. for the sex of
+    /// a character.
+    pub fn name_for(&self, form: impl Into<NameForm>) -> Cow<'_, str> {
+        self.name.resolve(form.into())
+    }
+
+    /// Gets the name addition of the profession in the given form, if there
+    /// is one.
+    pub fn subname_for(&self, form: impl Into<NameForm>)
+            -> Option<Cow<'_, str>> {
+        self.subname.as_ref().map(|subname| subname.resolve(form.into()))
+    }
+
+    /// Gets the name of the profession together with its name addition in
+    /// parentheses, if there is one, such as `Magier (Akademie zu Punin)`,
+    /// both in the given form.
+    pub fn full_name_for(&self, form: impl Into<NameForm>) -> String {
+        let form = form.into();
+        let name = self.name_for(form);
+
+        match self.subname_for(form) {
+            Some(subname) => format!("{} ({})", name, subname),
+            None => name.into_owned()
+        }
+    }
+}
+
 impl Localization for ProfessionLocalization {
-    fn name(&self) -> &str {
-        // TODO make gender-neutral
 
+    /// The male form of the name, since [Localization::name] cannot hold the
+    /// [neutral](NameForm::Neutral) one. It is only used to identify the
+    /// profession, e.g. in file names. Names for display are resolved by
+    /// [ProfessionLocalization::name_for].
+    fn name(&self) -> &str {
         match &self.name {
             NameMaybeBySex::Universal(n) => n,
             NameMaybeBySex::BySex(nbs) => &nbs.m
         }
     }
+
+    fn display_name(&self) -> Cow<'_, str> {
+        Cow::Owned(self.full_name_for(NameForm::Neutral))
+    }
+
+    /// The male and the female form of the name, so that searches find both
+    /// equally well.
+    fn name_forms(&self) -> Vec<&str> {
+        self.name.forms()
+    }
+
+    /// The forms of the name addition, if there is one, so that professions
+    /// can be found by it.
+    fn alternative_names(&self) -> Vec<&str> {
+        self.subname.as_ref()
+            .map(NameMaybeBySex::forms)
+            .unwrap_or_default()
+    }
+
+    fn errata(&self) -> &[Erratum] {
+        self.errata.as_deref().unwrap_or_default()
+    }
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct SubProfession {
     pub id: u32,
@@ -499,6 +642,7 @@ pub struct SubProfession {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Profession {
     pub id: u32,
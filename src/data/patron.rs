@@ -5,7 +5,11 @@ use crate::id::{Category, Id, Identifiable};
 
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type")]
 #[serde(deny_unknown_fields)]
 pub enum AdvantageSkillPatronPower {
@@ -31,6 +35,7 @@ pub enum AdvantageSkillPatronPower {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub enum CombatPatronPowerId {
     Attack,
@@ -42,6 +47,7 @@ pub enum CombatPatronPowerId {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type")]
 #[serde(deny_unknown_fields)]
 pub enum CombatPatronPower {
@@ -54,6 +60,7 @@ pub enum CombatPatronPower {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type")]
 #[serde(deny_unknown_fields)]
 pub enum AttributePatronPower {
@@ -74,6 +81,7 @@ pub type PatronPowers = (
 );
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Patron {
     pub id: u32,
@@ -120,6 +128,7 @@ impl TranslationsTranslatable for Patron {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct PatronCategory {
     pub id: u32,
@@ -1,5 +1,8 @@
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+
 use crate::Sex;
 use crate::data::{Ids, Translations};
 use crate::data::activatable::{
@@ -7,10 +10,12 @@ use crate::data::activatable::{
     ActivatableType,
     SelectOptionId
 };
+use crate::id::Category;
 
 /// This property customizes the appearance of the prerequisite in generated
 /// lists: You can hide them or replace them with a text.
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum DisplayOption {
@@ -25,6 +30,7 @@ pub type SexPrerequisite = Sex;
 /// provide an object to say whether the hero must meet one of the races or
 /// if the entry does not allow one of the races.
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(untagged)]
 #[serde(deny_unknown_fields)]
 pub enum RacePrerequisite {
@@ -41,6 +47,7 @@ pub type CulturePrerequisite = Ids;
 
 /// Requires a specific pact.
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct PactPrerequisite {
 
@@ -62,6 +69,7 @@ pub type SocialStatusPrerequisite = u32;
 pub type StatePrerequisite = Ids;
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum RuleId {
@@ -71,6 +79,7 @@ pub enum RuleId {
 
 /// Requires a specific focus or optional rule to be active.
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct RulePrerequisite {
     pub id: RuleId
@@ -78,6 +87,7 @@ pub struct RulePrerequisite {
 
 /// Requires the primary attribute at a specific value.
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum PrimaryAttributePrerequisite {
@@ -87,6 +97,7 @@ pub enum PrimaryAttributePrerequisite {
 
 /// Requires a specific advantage, disadvantage, special ability.
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct ActivatablePrerequisite {
     pub id: ActivatableId,
@@ -96,6 +107,7 @@ pub struct ActivatablePrerequisite {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct ActivatableMultiId {
     #[serde(rename = "type")]
@@ -105,6 +117,7 @@ pub struct ActivatableMultiId {
 
 /// Require one advantage, disadvantage or special ability from a set.
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct ActivatableMultiEntryPrerequisite {
     pub id: ActivatableMultiId,
@@ -116,6 +129,7 @@ pub struct ActivatableMultiEntryPrerequisite {
 /// Requires one of a set of options on a specific advantage, disadvantage,
 /// special ability.
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct ActivatableMultiSelectPrerequisite {
     pub id: ActivatableId,
@@ -138,6 +152,7 @@ pub struct ActivatableMultiSelectPrerequisite {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub enum IncreasableType {
     Attribute,
@@ -150,7 +165,27 @@ pub enum IncreasableType {
     Ceremony
 }
 
+impl IncreasableType {
+
+    /// The category of the entities of this type.
+    pub fn category(&self) -> Category {
+        match self {
+            IncreasableType::Attribute => Category::Attributes,
+            IncreasableType::Skill => Category::Skills,
+            IncreasableType::MeleeCombatTechnique =>
+                Category::MeleeCombatTechniques,
+            IncreasableType::RangedCombatTechnique =>
+                Category::RangedCombatTechniques,
+            IncreasableType::Spell => Category::Spells,
+            IncreasableType::Ritual => Category::Rituals,
+            IncreasableType::LiturgicalChant => Category::LiturgicalChants,
+            IncreasableType::Ceremony => Category::Ceremonies
+        }
+    }
+}
+
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct IncreasableId {
     #[serde(rename = "type")]
@@ -163,6 +198,7 @@ pub struct IncreasableId {
 /// active automatically, so to require them to be active you can set the value
 /// to 0.
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct IncreasablePrerequisite {
     pub id: IncreasableId,
@@ -170,6 +206,7 @@ pub struct IncreasablePrerequisite {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct IncreasableMultiId {
     #[serde(rename = "type")]
@@ -182,6 +219,7 @@ pub struct IncreasableMultiId {
 /// be active automatically, so to require them to be active you can set the
 /// value to 0.
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct IncreasableMultiEntryPrerequisite {
     pub id: IncreasableMultiId,
@@ -190,6 +228,7 @@ pub struct IncreasableMultiEntryPrerequisite {
 
 /// Requires a specific publication to be active.
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct PublicationPrerequisite {
     pub id: u32
@@ -198,6 +237,7 @@ pub struct PublicationPrerequisite {
 /// The `when` property defines that the prerequisite it is defined for only
 /// takes effect if the prerequisites in this list are matched.
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum WhenSingle {
@@ -207,6 +247,7 @@ pub enum WhenSingle {
 pub type When = Vec<WhenSingle>;
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type")]
 #[serde(deny_unknown_fields)]
 pub enum SpecialActivatablePrerequisite {
@@ -236,6 +277,7 @@ pub enum SpecialActivatablePrerequisite {
 // https://github.com/serde-rs/serde/issues/1547 is fixed.
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 pub enum GeneralPrerequisiteNoDisplayNoWhen {
     Sex(SexPrerequisite),
@@ -258,6 +300,7 @@ pub enum GeneralPrerequisiteNoDisplayNoWhen {
 /// A prerequisite that wraps data of type `P` which is missing an optional
 /// [DisplayOption] field.
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct DisplayPrerequisite<P> {
     #[serde(flatten)]
     pub prerequisite: P,
@@ -268,6 +311,7 @@ pub struct DisplayPrerequisite<P> {
 /// A prerequisite that wraps data of type `P` which is missing an optional
 /// [When] field.
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct WhenPrerequisite<P> {
     #[serde(flatten)]
     pub prerequisite: P,
@@ -280,6 +324,7 @@ pub type GeneralPrerequisite =
 pub type GeneralListPrerequisite = Vec<GeneralPrerequisite>;
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct ByLevelPrerequisiteSingle<P> {
     pub level: u32,
@@ -290,6 +335,7 @@ pub type GeneralByLevelPrerequisite =
     Vec<ByLevelPrerequisiteSingle<GeneralListPrerequisite>>;
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum ListOrByLevelPrerequisite<L, B> {
@@ -303,6 +349,7 @@ pub type GeneralListOrByLevelPrerequisite =
         GeneralByLevelPrerequisite>;
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 pub enum TraitPrerequisiteNoDisplayNoWhen {
     CommonSuggestedByRCP,
@@ -334,6 +381,7 @@ pub type TraitlListOrByLevelPrerequisite =
     ListOrByLevelPrerequisite<TraitListPrerequisite, TraitByLevelPrerequisite>;
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum ArcaneTraditionPrerequisiteNoDisplay {
@@ -345,6 +393,7 @@ pub type ArcaneTraditionPrerequisite =
     DisplayPrerequisite<ArcaneTraditionPrerequisiteNoDisplay>;
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum ArcaneTraditionListPrerequisite {
@@ -353,6 +402,7 @@ pub enum ArcaneTraditionListPrerequisite {
 
 /// A prerequisite enumeration that only contains an increasable prerequisite.
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum OnlyIncreasablePrerequisiteNoDisplay {
@@ -363,6 +413,7 @@ pub type OnlyIncreasablePrerequisite =
     DisplayPrerequisite<OnlyIncreasablePrerequisiteNoDisplay>;
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum IncreasableListPrerequisite {
@@ -371,6 +422,7 @@ pub enum IncreasableListPrerequisite {
 
 /// A prerequisite enumeration that only contains an activatable prerequisite.
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum OnlyActivatablePrerequisiteNoDisplay {
@@ -381,6 +433,7 @@ pub type OnlyActivatablePrerequisite =
     DisplayPrerequisite<OnlyActivatablePrerequisiteNoDisplay>;
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum ActivatableListPrerequisite {
@@ -389,6 +442,7 @@ pub enum ActivatableListPrerequisite {
 
 /// Require a previous enhancement.
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum EnhancementPrerequisite {
@@ -398,6 +452,7 @@ pub enum EnhancementPrerequisite {
 pub type EnhancementListPrerequisite = Vec<EnhancementPrerequisite>;
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum InfluencePrerequisiteNoDisplay {
@@ -418,6 +473,7 @@ pub type InfluencePrerequisite =
     DisplayPrerequisite<InfluencePrerequisiteNoDisplay>;
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum InfluenceListPrerequisite {
@@ -425,6 +481,7 @@ pub enum InfluenceListPrerequisite {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum ProfessionPrerequisiteNoDisplay {
@@ -439,6 +496,7 @@ pub type ProfessionPrerequisite =
     DisplayPrerequisite<ProfessionPrerequisiteNoDisplay>;
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum ProfessionListPrerequisite {
@@ -446,6 +504,7 @@ pub enum ProfessionListPrerequisite {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum PersonalityTraitPrerequisiteNoDisplay {
@@ -457,6 +516,7 @@ pub type PersonalityTraitPrerequisite =
     DisplayPrerequisite<PersonalityTraitPrerequisiteNoDisplay>;
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum PersonalityTraitListPrerequisite {
@@ -464,6 +524,7 @@ pub enum PersonalityTraitListPrerequisite {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum LiturgicalChantPrerequisite {
@@ -471,6 +532,7 @@ pub enum LiturgicalChantPrerequisite {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum LiturgicalChantListPrerequisite {
@@ -478,6 +540,7 @@ pub enum LiturgicalChantListPrerequisite {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum LanguagePrerequisiteNoDisplay {
@@ -489,6 +552,7 @@ pub type LanguagePrerequisite =
     DisplayPrerequisite<LanguagePrerequisiteNoDisplay>;
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum IneligiblePrerequisiteNoDisplay {
@@ -499,6 +563,7 @@ pub type IneligiblePrerequisite =
     DisplayPrerequisite<IneligiblePrerequisiteNoDisplay>;
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(untagged)]
 #[serde(deny_unknown_fields)]
 pub enum LanguageListPrerequisite {
@@ -514,6 +579,7 @@ pub type LanguageListOrByLevelPrerequisite =
         LanguageByLevelPrerequisite>;
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(untagged)]
 #[serde(deny_unknown_fields)]
 pub enum MagicalTraditionPrerequisite {
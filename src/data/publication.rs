@@ -3,7 +3,11 @@ use crate::id::{Category, Id, Identifiable};
 
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub enum PublicationType {
     CoreRules,
@@ -14,6 +18,7 @@ pub enum PublicationType {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct PrintingLocalization {
 
@@ -22,6 +27,7 @@ pub struct PrintingLocalization {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Printing {
 
@@ -31,6 +37,7 @@ pub struct Printing {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct PublicationLocalization {
 
@@ -61,6 +68,7 @@ impl Localization for PublicationLocalization {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Publication {
     pub id: u32,
@@ -84,6 +92,17 @@ pub struct Publication {
     pub translations: Translations<PublicationLocalization>
 }
 
+impl Publication {
+
+    /// Gets the index of the printing with the given universal printing
+    /// identifier within the printings of this publication.
+    pub fn printing_index(&self, printing: u32) -> Option<usize> {
+        self.printings.as_ref()
+            .and_then(|printings|
+                printings.iter().position(|pr| pr.id == printing))
+    }
+}
+
 impl Identifiable for Publication {
     fn id(&self) -> Id {
         Id::new(Category::Publications, self.id)
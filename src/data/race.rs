@@ -1,17 +1,24 @@
 use serde::{Deserialize, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
 
+#[cfg(feature = "schema")]
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+#[cfg(feature = "schema")]
+use std::borrow::Cow;
+
 use crate::data::{
     Localization,
     SuggestedUnsuitable,
     TranslationsTranslatable,
     Translations
 };
-use crate::data::errata::Errata;
+use crate::data::errata::{Errata, Erratum};
 use crate::data::src::SourceRefs;
 use crate::id::{Category, Id, Identifiable};
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct AttributeAdjustment {
     pub id: u32,
@@ -26,7 +33,22 @@ pub enum DieSides {
     Twenty = 20
 }
 
+#[cfg(feature = "schema")]
+impl JsonSchema for DieSides {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("DieSides")
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "integer",
+            "enum": [3, 6, 20]
+        })
+    }
+}
+
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Die {
     pub amount: i32,
@@ -34,6 +56,7 @@ pub struct Die {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct StartingAge {
     #[serde(rename = "experienceLevelId")]
@@ -43,6 +66,7 @@ pub struct StartingAge {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct RaceVariantLocalization {
     pub name: String,
@@ -63,6 +87,7 @@ impl Localization for RaceVariantLocalization {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Phenotype {
     #[serde(rename = "commonCultures")]
@@ -78,6 +103,7 @@ pub struct Phenotype {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct RaceVariant {
     pub id: u32,
@@ -95,6 +121,7 @@ pub struct RaceVariant {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum TypeSpecificData {
@@ -105,6 +132,7 @@ pub enum TypeSpecificData {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct RaceLocalization {
     pub name: String,
@@ -131,9 +159,14 @@ impl Localization for RaceLocalization {
     fn name(&self) -> &str {
         &self.name
     }
+
+    fn errata(&self) -> &[Erratum] {
+        self.errata.as_deref().unwrap_or_default()
+    }
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Race {
     pub id: u32,
@@ -0,0 +1,62 @@
+//! JSON Schemas of the files in the data directory, which are generated from
+//! the entity types. Since they describe the same fields as the loader
+//! expects, they can be used to validate data files in editors before they
+//! are loaded.
+
+use crate::data::{category_schemas, UIValue, UI_DIR};
+use crate::error::OptolithDataResult;
+use crate::id::Category;
+use crate::util;
+
+use schemars::Schema;
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// The JSON Schema which all files in a directory of the data conform to.
+pub struct DirectorySchema {
+
+    /// The name of the directory, such as `Spells`.
+    pub dir: &'static str,
+
+    /// The category of the entities in the directory, or `None` for the
+    /// directory of UI strings.
+    pub category: Option<Category>,
+
+    /// The schema of a single file in the directory.
+    pub schema: Schema
+}
+
+/// Generates the JSON Schemas of the files in all directories that are loaded
+/// into an [OptolithData](crate::data::OptolithData), starting with the UI
+/// directory followed by the categories.
+pub fn schemas() -> Vec<DirectorySchema> {
+    let ui = DirectorySchema {
+        dir: UI_DIR,
+        category: None,
+        schema: schemars::schema_for!(BTreeMap<String, UIValue>)
+    };
+    let categories = category_schemas().into_iter()
+        .map(|(category, dir, schema)| DirectorySchema {
+            dir,
+            category: Some(category),
+            schema
+        });
+
+    std::iter::once(ui).chain(categories).collect()
+}
+
+/// Writes the JSON Schemas of all directories to the directory with the given
+/// path, one file named like `Spells.schema.json` per directory. The directory
+/// is created if it does not exist.
+pub fn write_schemas(path: &str) -> OptolithDataResult<()> {
+    let dir = Path::new(path);
+    util::create_dir_all(dir)?;
+
+    for DirectorySchema { dir: name, schema, .. } in schemas() {
+        let file = dir.join(format!("{}.schema.json", name));
+        util::serialize_json_file(&file, &schema)?;
+    }
+
+    Ok(())
+}
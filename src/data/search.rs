@@ -0,0 +1,326 @@
+//! A search index over the localized names and texts of all entities. Search
+//! terms and indexed texts are normalized, so that case, diacritics and the
+//! different spellings of German umlauts do not matter, and search terms
+//! match words with small typos as well.
+
+use crate::data::Localization;
+use crate::data::package::profession::Profession;
+use crate::id::{Id, Identifiable};
+
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+
+/// The default maximum number of results of a search.
+pub const DEFAULT_RESULT_LIMIT: usize = 20;
+
+/// Options that control which information is included in a [SearchIndex].
+#[derive(Clone, Default)]
+pub struct SearchOptions {
+
+    /// If set, only translations into these locales are indexed. Otherwise,
+    /// all locales into which any entity is translated are indexed.
+    pub locales: Option<HashSet<String>>,
+
+    /// If `true`, the longer texts of entities, such as their rules, effect or
+    /// description, are indexed in addition to their names.
+    pub include_texts: bool
+}
+
+impl SearchOptions {
+
+    /// Indicates whether translations into the locale with the given name are
+    /// indexed with these options.
+    pub fn is_locale_indexed(&self, locale: &str) -> bool {
+        self.locales.as_ref()
+            .map(|locales| locales.contains(locale))
+            .unwrap_or(true)
+    }
+}
+
+/// The kind of localized text of an entity that matched a search.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SearchField {
+    Name,
+    AlternativeName,
+    Text
+}
+
+impl SearchField {
+
+    /// The factor by which the score of matches in this field is multiplied.
+    fn weight(self) -> f64 {
+        match self {
+            SearchField::Name => 1.0,
+            SearchField::AlternativeName => 0.9,
+            SearchField::Text => 0.5
+        }
+    }
+}
+
+/// An entity that matched a search.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SearchHit {
+
+    /// The ID of the entity.
+    pub id: Id,
+
+    /// How well the entity matched, from 0 (exclusive) to 1 (an exact match
+    /// of its name).
+    pub score: f64,
+
+    /// The field of the best match.
+    pub field: SearchField
+}
+
+struct Document {
+    id: Id,
+    field: SearchField,
+    text: String,
+    words: Vec<String>
+}
+
+/// An index for searching entities by their localized names and, optionally,
+/// texts. It is created by
+/// [OptolithData::search_index](crate::data::OptolithData::search_index).
+#[derive(Default)]
+pub struct SearchIndex {
+    documents: HashMap<String, Vec<Document>>
+}
+
+impl SearchIndex {
+    fn add_text(&mut self, id: Id, locale: &str, field: SearchField,
+            text: &str) {
+        let text = normalize(text);
+
+        if !text.is_empty() {
+            let words = text.split(' ').map(String::from).collect();
+            self.documents.entry(locale.to_owned()).or_default()
+                .push(Document { id, field, text, words });
+        }
+    }
+
+    pub(crate) fn add(&mut self, id: Id, locale: &str,
+            localization: &impl Localization, options: &SearchOptions) {
+        self.documents.entry(locale.to_owned()).or_default();
+
+        for name in localization.name_forms() {
+            self.add_text(id, locale, SearchField::Name, name);
+        }
+
+        for name in localization.alternative_names() {
+            self.add_text(id, locale, SearchField::AlternativeName, name);
+        }
+
+        if options.include_texts {
+            for text in localization.texts() {
+                self.add_text(id, locale, SearchField::Text, text);
+            }
+        }
+    }
+
+    /// Adds the names of the variants of all instances of the given
+    /// profession in the locales selected by the given options as alternative
+    /// names of the profession.
+    pub(crate) fn add_profession_variants(&mut self, profession: &Profession,
+            options: &SearchOptions) {
+        let id = profession.id();
+        let localizations = profession.instances.iter()
+            .flat_map(|instance| instance.variants.iter().flatten())
+            .flat_map(|variant| &variant.translations)
+            .filter(|(locale, _)| options.is_locale_indexed(locale));
+
+        for (locale, localization) in localizations {
+            for name in localization.name.forms() {
+                self.add_text(id, locale, SearchField::AlternativeName, name);
+            }
+        }
+    }
+
+    /// Indicates whether translations into the locale with the given name
+    /// are indexed.
+    pub fn contains_locale(&self, locale: &str) -> bool {
+        self.documents.contains_key(locale)
+    }
+
+    /// Searches the translations into the given locale for the given query
+    /// and returns at most [DEFAULT_RESULT_LIMIT] hits, best first.
+    pub fn search(&self, locale: &str, query: &str) -> Vec<SearchHit> {
+        self.search_limited(locale, query, DEFAULT_RESULT_LIMIT)
+    }
+
+    /// Searches the translations into the given locale for the given query
+    /// and returns at most `limit` hits, best first. Hits with the same score
+    /// are ordered by their IDs.
+    pub fn search_limited(&self, locale: &str, query: &str, limit: usize)
+            -> Vec<SearchHit> {
+        let query = normalize(query);
+        let documents = match self.documents.get(locale) {
+            Some(documents) if !query.is_empty() => documents,
+            _ => return Vec::new()
+        };
+        let terms = query.split(' ').collect::<Vec<_>>();
+        let mut best: HashMap<Id, SearchHit> = HashMap::new();
+
+        for document in documents {
+            let score = score(document, &query, &terms) *
+                document.field.weight();
+
+            if score <= 0.0 {
+                continue;
+            }
+
+            let hit = best.entry(document.id).or_insert(SearchHit {
+                id: document.id,
+                score,
+                field: document.field
+            });
+
+            if score > hit.score {
+                hit.score = score;
+                hit.field = document.field;
+            }
+        }
+
+        let mut hits = best.into_values().collect::<Vec<_>>();
+        hits.sort_by(|a, b| b.score.partial_cmp(&a.score)
+            .unwrap_or(Ordering::Equal)
+            .then(a.id.cmp(&b.id)));
+        hits.truncate(limit);
+        hits
+    }
+}
+
+/// Scores how well the given normalized query, which is split into the given
+/// terms, matches the given document, from 0 (no match) to 1.
+fn score(document: &Document, query: &str, terms: &[&str]) -> f64 {
+    if document.text == query {
+        return 1.0;
+    }
+
+    if document.text.starts_with(query) {
+        return 0.9;
+    }
+
+    // every term must match some word of the document
+    let mut sum = 0.0;
+
+    for term in terms {
+        let best = document.words.iter()
+            .map(|word| score_word(word, term))
+            .fold(0.0, f64::max);
+
+        if best <= 0.0 {
+            return 0.0;
+        }
+
+        sum += best;
+    }
+
+    0.8 * sum / terms.len() as f64
+}
+
+/// Scores how well the given term matches the given word, from 0 (no match)
+/// to 1 (equality).
+fn score_word(word: &str, term: &str) -> f64 {
+    if word == term {
+        return 1.0;
+    }
+
+    if word.starts_with(term) {
+        return 0.8;
+    }
+
+    if word.contains(term) && term.chars().count() >= 3 {
+        return 0.6;
+    }
+
+    let tolerance = match term.chars().count() {
+        0..=3 => return 0.0,
+        4..=7 => 1,
+        _ => 2
+    };
+    let distance = edit_distance(word, term);
+
+    if distance <= tolerance {
+        0.5 - 0.1 * distance as f64
+    }
+    else {
+        // words starting with a slightly misspelled term
+        let prefix = word.chars().take(term.chars().count())
+            .collect::<String>();
+
+        if edit_distance(&prefix, term) <= tolerance { 0.3 } else { 0.0 }
+    }
+}
+
+/// Computes the Levenshtein distance between the given strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, ca) in a.chars().enumerate() {
+        current[0] = i + 1;
+
+        for (j, cb) in b.iter().enumerate() {
+            let cost = if ca == *cb { 0 } else { 1 };
+            current[j + 1] = (previous[j] + cost)
+                .min(previous[j + 1] + 1)
+                .min(current[j] + 1);
+        }
+
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
+
+/// Normalizes the given text for searching. The text is converted to lower
+/// case, German umlauts and ß are replaced by their transcriptions (such as
+/// `ae` for `ä`), other letters with diacritics by their base letters, and all
+/// sequences of other characters than letters and digits by single spaces.
+pub fn normalize(text: &str) -> String {
+    let mut normalized = String::with_capacity(text.len());
+    let mut pending_space = false;
+
+    for c in text.chars().flat_map(char::to_lowercase) {
+        let replacement = match c {
+            'ä' | 'æ' => "ae",
+            'ö' | 'œ' => "oe",
+            'ü' => "ue",
+            'ß' => "ss",
+            'à' | 'á' | 'â' | 'ã' | 'å' | 'ā' => "a",
+            'ç' | 'č' => "c",
+            'è' | 'é' | 'ê' | 'ë' | 'ē' => "e",
+            'ì' | 'í' | 'î' | 'ï' | 'ī' => "i",
+            'ñ' => "n",
+            'ò' | 'ó' | 'ô' | 'õ' | 'ø' | 'ō' => "o",
+            'ù' | 'ú' | 'û' | 'ū' => "u",
+            'ý' | 'ÿ' => "y",
+            'š' => "s",
+            'ž' => "z",
+            c if c.is_alphanumeric() => {
+                if pending_space && !normalized.is_empty() {
+                    normalized.push(' ');
+                }
+
+                pending_space = false;
+                normalized.push(c);
+                continue;
+            },
+            _ => {
+                pending_space = true;
+                continue;
+            }
+        };
+
+        if pending_space && !normalized.is_empty() {
+            normalized.push(' ');
+        }
+
+        pending_space = false;
+        normalized.push_str(replacement);
+    }
+
+    normalized
+}
@@ -3,10 +3,14 @@ use crate::id::{Category, CategoryProvider, Id, Identifiable};
 
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+
 use std::marker::PhantomData;
 
 /// A [Localization] that consists only of a string.
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct SimpleLocalization {
     pub name: String
@@ -23,6 +27,7 @@ pub type SimpleTranslations = Translations<SimpleLocalization>;
 /// A data entity that consists of an ID and
 /// [SimpleTranslations](crate::data::SimpleTranslations).
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct SimpleEntity<C: CategoryProvider> {
     pub id: u32,
@@ -1,7 +1,10 @@
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+
 use crate::data::{Localization, TranslationsTranslatable, Translations};
-use crate::data::errata::Errata;
+use crate::data::errata::{Errata, Erratum};
 use crate::data::simple::SimpleTranslations;
 use crate::data::src::SourceRefs;
 use crate::id::{Category, Id, Identifiable};
@@ -11,6 +14,7 @@ pub mod non_profane;
 
 /// This is one data structure used for "applications" and "uses".
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct ExplicitApplication {
     pub id: i32,
@@ -18,6 +22,7 @@ pub struct ExplicitApplication {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub enum DerivedApplications {
     Regions,
@@ -25,6 +30,7 @@ pub enum DerivedApplications {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum Applications {
@@ -33,6 +39,7 @@ pub enum Applications {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub enum ImprovementCost {
     A,
@@ -42,6 +49,7 @@ pub enum ImprovementCost {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub enum EncumbranceAffected {
     #[serde(rename = "true")]
@@ -53,6 +61,7 @@ pub enum EncumbranceAffected {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct SkillLocalization {
     pub name: String,
@@ -72,9 +81,14 @@ impl Localization for SkillLocalization {
     fn name(&self) -> &str {
         &self.name
     }
+
+    fn errata(&self) -> &[Erratum] {
+        self.errata.as_deref().unwrap_or_default()
+    }
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Skill {
     pub id: u32,
@@ -102,6 +116,7 @@ impl TranslationsTranslatable for Skill {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct SkillGroupLocalization {
     pub name: String,
@@ -116,6 +131,7 @@ impl Localization for SkillGroupLocalization {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct SkillGroup {
     pub id: u32,
@@ -12,9 +12,13 @@ use crate::id::{Category, CategoryProvider, Id, Identifiable};
 
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+
 use std::marker::PhantomData;
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct ImprovableKarmalSkill<C: CategoryProvider> {
     pub id: u32,
@@ -93,6 +97,7 @@ impl CategoryProvider for CeremonyCategory {
 pub type Ceremony = ImprovableKarmalSkill<CeremonyCategory>;
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Blessing {
     pub id: u32,
@@ -115,6 +120,7 @@ impl TranslationsTranslatable for Blessing {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum KarmalWorksId {
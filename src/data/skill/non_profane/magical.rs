@@ -1,5 +1,5 @@
 use crate::data::{Ids, Localization, TranslationsTranslatable, Translations};
-use crate::data::errata::Errata;
+use crate::data::errata::{Errata, Erratum};
 use crate::data::skill::non_profane::{
     CheckMod,
     Enhancements,
@@ -19,10 +19,14 @@ use crate::id::{Category, CategoryProvider, Id, Identifiable};
 
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+
 use std::marker::PhantomData;
 
 /// A spell or ritual.
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct MagicalSkill<C: CategoryProvider> {
     pub id: u32,
@@ -103,6 +107,7 @@ impl CategoryProvider for RitualCategory {
 pub type Ritual = MagicalSkill<RitualCategory>;
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Cantrip {
     pub id: u32,
@@ -137,6 +142,7 @@ impl TranslationsTranslatable for Cantrip {
 /// Stores the information about a music tradition in the context of a magical
 /// dance/melody.
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct MusicTraditionSpecificData {
 
@@ -149,6 +155,7 @@ pub struct MusicTraditionSpecificData {
 
 /// A localization of a magical dance or melody.
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct MusicalMagicLocalization {
 
@@ -180,9 +187,18 @@ impl Localization for MusicalMagicLocalization {
     fn name(&self) -> &str {
         &self.name
     }
+
+    fn texts(&self) -> Vec<&str> {
+        vec![&self.effect]
+    }
+
+    fn errata(&self) -> &[Erratum] {
+        self.errata.as_deref().unwrap_or_default()
+    }
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct MagicalDance {
     pub id: u32,
@@ -215,6 +231,7 @@ impl TranslationsTranslatable for MagicalDance {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct MagicalMelody {
     pub id: u32,
@@ -250,6 +267,7 @@ impl TranslationsTranslatable for MagicalMelody {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct ElvenMagicalSongLocalization {
 
@@ -268,9 +286,18 @@ impl Localization for ElvenMagicalSongLocalization {
     fn name(&self) -> &str {
         &self.name
     }
+
+    fn texts(&self) -> Vec<&str> {
+        vec![&self.effect]
+    }
+
+    fn errata(&self) -> &[Erratum] {
+        self.errata.as_deref().unwrap_or_default()
+    }
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct ElvenMagicalSong {
     pub id: u32,
@@ -309,6 +336,7 @@ impl TranslationsTranslatable for ElvenMagicalSong {
 /// A struct which contains localizations for spells or rituals which have no
 /// specified range or casting time.
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct NoRangeTimeLocalization {
     pub name: String,
@@ -322,11 +350,20 @@ impl Localization for NoRangeTimeLocalization {
     fn name(&self) -> &str {
         &self.name
     }
+
+    fn texts(&self) -> Vec<&str> {
+        vec![&self.effect]
+    }
+
+    fn errata(&self) -> &[Erratum] {
+        self.errata.as_deref().unwrap_or_default()
+    }
 }
 
 /// A struct for rituals for one specific tradition that share many rules such
 /// that they do not appear in the schema.
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct SimpleMagicalSkill<C: CategoryProvider, L: Localization> {
     pub id: u32,
@@ -401,6 +438,7 @@ pub type GeodeRitual =
     SimpleMagicalSkill<GeodeRitualCategory, NonProfaneSkillLocalization>;
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct ZibiljaRitual {
     pub id: u32,
@@ -450,6 +488,7 @@ impl TranslationsTranslatable for ZibiljaRitual {
 }
 
 #[derive(Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct AnimistPower {
     pub id: u32,
@@ -481,6 +520,7 @@ impl TranslationsTranslatable for AnimistPower {
 }
 
 #[derive(Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct JesterTrick {
     pub id: u32,
@@ -514,12 +554,14 @@ impl TranslationsTranslatable for JesterTrick {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub enum MagicalRuneCheckMod {
     CombatTechnique
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct SpeedSeparatedMainParameterLocalization {
     pub slow: MainParameterLocalization,
@@ -527,6 +569,7 @@ pub struct SpeedSeparatedMainParameterLocalization {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct MagicalRuneLocalization {
 
@@ -566,9 +609,18 @@ impl Localization for MagicalRuneLocalization {
     fn name(&self) -> &str {
         &self.name
     }
+
+    fn texts(&self) -> Vec<&str> {
+        vec![&self.effect]
+    }
+
+    fn errata(&self) -> &[Erratum] {
+        self.errata.as_deref().unwrap_or_default()
+    }
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct MagicalRune {
     pub id: u32,
@@ -600,6 +652,7 @@ impl TranslationsTranslatable for MagicalRune {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum StandardSpellworkId {
@@ -608,6 +661,7 @@ pub enum StandardSpellworkId {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum SpellworkId {
@@ -1,20 +1,25 @@
 use crate::data::{Localization, TranslationsTranslatable, Translations};
-use crate::data::errata::Errata;
+use crate::data::errata::{Errata, Erratum};
 use crate::data::prerequisite::EnhancementListPrerequisite;
 use crate::data::src::SourceRefs;
 
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+
 pub mod karmal;
 pub mod magical;
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub enum CheckModTarget {
     Creature
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub enum SimpleCheckMod {
     #[serde(rename = "SPI")]
@@ -30,6 +35,7 @@ pub enum SimpleCheckMod {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct CheckModOf {
     #[serde(rename = "type")]
@@ -41,6 +47,7 @@ pub struct CheckModOf {
 /// respectively. If the higher is the characteristic to choose, insert an
 /// array with both instead.
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(untagged)]
 #[serde(deny_unknown_fields)]
 pub enum CheckMod {
@@ -49,6 +56,7 @@ pub enum CheckMod {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct EnhancementLocalization {
     pub name: String,
@@ -60,9 +68,18 @@ impl Localization for EnhancementLocalization {
     fn name(&self) -> &str {
         &self.name
     }
+
+    fn texts(&self) -> Vec<&str> {
+        vec![&self.effect]
+    }
+
+    fn errata(&self) -> &[Erratum] {
+        self.errata.as_deref().unwrap_or_default()
+    }
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Enhancement {
     pub id: u32,
@@ -90,6 +107,7 @@ pub type Enhancements = Vec<Enhancement>;
 /// Effect descriptions for a reached QL. You can set an effect for each QL or
 /// for each 2 QL.
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(untagged)]
 #[serde(deny_unknown_fields)]
 pub enum QualityLevelEffectLocalization {
@@ -104,6 +122,7 @@ pub enum QualityLevelEffectLocalization {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct MainParameterLocalization {
 
@@ -115,6 +134,7 @@ pub struct MainParameterLocalization {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct NonProfaneSkillLocalization {
 
@@ -161,10 +181,19 @@ impl Localization for NonProfaneSkillLocalization {
     fn name(&self) -> &str {
         &self.name
     }
+
+    fn texts(&self) -> Vec<&str> {
+        vec![&self.effect]
+    }
+
+    fn errata(&self) -> &[Erratum] {
+        self.errata.as_deref().unwrap_or_default()
+    }
 }
 
 /// A localization for blessings and cantrips.
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct SmallNonProfaneSkillLocalization {
 
@@ -192,4 +221,12 @@ impl Localization for SmallNonProfaneSkillLocalization {
     fn name(&self) -> &str {
         &self.name
     }
+
+    fn texts(&self) -> Vec<&str> {
+        vec![&self.effect]
+    }
+
+    fn errata(&self) -> &[Erratum] {
+        self.errata.as_deref().unwrap_or_default()
+    }
 }
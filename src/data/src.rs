@@ -1,8 +1,14 @@
+use crate::data::publication::Publication;
+
 use serde::{Deserialize, Serialize};
 
-use std::collections::HashMap;
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+
+use std::collections::BTreeMap;
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Occurrence {
     #[serde(rename = "firstPage")]
@@ -12,6 +18,7 @@ pub struct Occurrence {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(untagged)]
 #[serde(deny_unknown_fields)]
 pub enum Occurrences {
@@ -19,7 +26,20 @@ pub enum Occurrences {
     Many(Vec<Occurrence>)
 }
 
+impl Occurrences {
+
+    /// The occurrences as a slice, which contains a single element for
+    /// [Occurrences::One].
+    pub fn as_slice(&self) -> &[Occurrence] {
+        match self {
+            Occurrences::One(occurrence) => std::slice::from_ref(occurrence),
+            Occurrences::Many(occurrences) => occurrences
+        }
+    }
+}
+
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct SourceRef {
     pub id: u32,
@@ -33,7 +53,29 @@ pub struct SourceRef {
     pub deprecated: Option<u32>,
 
     /// Maps the language ID to the occurrences in the books of that language.
-    pub occurrences: HashMap<String, Occurrences>
+    pub occurrences: BTreeMap<String, Occurrences>
+}
+
+impl SourceRef {
+
+    /// Indicates whether the referenced entry is present in the printing with
+    /// the given universal printing identifier of the given publication,
+    /// according to [SourceRef::since] and [SourceRef::deprecated]. If the
+    /// publication has no such printing, this is always `true`.
+    pub fn is_in_printing(&self, publication: &Publication, printing: u32)
+            -> bool {
+        let selected = match publication.printing_index(printing) {
+            Some(selected) => selected,
+            None => return true
+        };
+        let since = self.since
+            .and_then(|since| publication.printing_index(since))
+            .unwrap_or(0);
+        let deprecated = self.deprecated
+            .and_then(|deprecated| publication.printing_index(deprecated));
+
+        since <= selected && deprecated.map(|d| selected < d).unwrap_or(true)
+    }
 }
 
 pub type SourceRefs = Vec<SourceRef>;
@@ -1,11 +1,15 @@
 use crate::data::{Localization, Translations, TranslationsTranslatable};
-use crate::data::errata::Errata;
+use crate::data::errata::{Errata, Erratum};
 use crate::data::src::SourceRefs;
 use crate::id::{Category, Id, Identifiable};
 
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct ConditionLocalization {
     pub name: String,
@@ -20,9 +24,18 @@ impl Localization for ConditionLocalization {
     fn name(&self) -> &str {
         &self.name
     }
+
+    fn texts(&self) -> Vec<&str> {
+        self.description.iter().map(String::as_str).collect()
+    }
+
+    fn errata(&self) -> &[Erratum] {
+        self.errata.as_deref().unwrap_or_default()
+    }
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Condition {
     pub id: u32,
@@ -45,6 +58,7 @@ impl TranslationsTranslatable for Condition {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct StateLocalization {
     pub name: String,
@@ -56,9 +70,18 @@ impl Localization for StateLocalization {
     fn name(&self) -> &str {
         &self.name
     }
+
+    fn texts(&self) -> Vec<&str> {
+        vec![&self.description]
+    }
+
+    fn errata(&self) -> &[Erratum] {
+        self.errata.as_deref().unwrap_or_default()
+    }
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct State {
     pub id: u32,
@@ -87,6 +110,7 @@ impl TranslationsTranslatable for State {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub enum Resistance {
     Spirit,
@@ -94,6 +118,7 @@ pub enum Resistance {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct DiseaseCauseLocalization {
 
@@ -108,6 +133,7 @@ pub struct DiseaseCauseLocalization {
 
 /// A single disease cause.
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct DiseaseCause {
 
@@ -117,6 +143,7 @@ pub struct DiseaseCause {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(untagged)]
 #[serde(deny_unknown_fields)]
 pub enum AlternativeName {
@@ -129,7 +156,20 @@ pub enum AlternativeName {
     }
 }
 
+impl AlternativeName {
+
+    /// The alternative name, regardless of whether it is used in a specific
+    /// region.
+    pub fn name(&self) -> &str {
+        match self {
+            AlternativeName::General(name) => name,
+            AlternativeName::InRegion { name, .. } => name
+        }
+    }
+}
+
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct DiseaseProgressDependent {
 
@@ -141,6 +181,7 @@ pub struct DiseaseProgressDependent {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct DiseaseLocalization {
     pub name: String,
@@ -186,9 +227,21 @@ impl Localization for DiseaseLocalization {
     fn name(&self) -> &str {
         &self.name
     }
+
+    fn alternative_names(&self) -> Vec<&str> {
+        self.alternative_names.iter()
+            .flatten()
+            .map(AlternativeName::name)
+            .collect()
+    }
+
+    fn errata(&self) -> &[Erratum] {
+        self.errata.as_deref().unwrap_or_default()
+    }
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Disease {
     pub id: u32,
@@ -225,6 +278,7 @@ impl TranslationsTranslatable for Disease {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct CommunicationCauseLocalization {
 
@@ -242,6 +296,7 @@ pub struct CommunicationCauseLocalization {
 
 /// A single cause.
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct CommunicationCause {
 
@@ -251,6 +306,7 @@ pub struct CommunicationCause {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum Communicability {
@@ -259,6 +315,7 @@ pub enum Communicability {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct AnimalDisease {
     pub id: u32,
@@ -303,6 +360,7 @@ impl TranslationsTranslatable for AnimalDisease {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "type", content = "value")]
 #[serde(deny_unknown_fields)]
 pub enum PoisonLevel {
@@ -311,6 +369,7 @@ pub enum PoisonLevel {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub enum PoisonApplicationType {
     Weapon,
@@ -320,6 +379,7 @@ pub enum PoisonApplicationType {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub enum Legality {
     Legal,
@@ -327,6 +387,7 @@ pub enum Legality {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct IntoxicantLocalization {
 
@@ -345,6 +406,7 @@ pub struct IntoxicantLocalization {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "category")]
 #[serde(deny_unknown_fields)]
 pub enum PlantPoisonData {
@@ -358,6 +420,7 @@ pub enum PlantPoisonData {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(tag = "sourceType", content = "categorySpecific")]
 #[serde(deny_unknown_fields)]
 pub enum PoisonSourceType {
@@ -368,6 +431,7 @@ pub enum PoisonSourceType {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct PoisonProgressDependent {
 
@@ -379,6 +443,7 @@ pub struct PoisonProgressDependent {
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct PoisonLocalization {
 
@@ -407,9 +472,27 @@ impl Localization for PoisonLocalization {
     fn name(&self) -> &str {
         &self.name
     }
+
+    fn alternative_names(&self) -> Vec<&str> {
+        self.alternative_names.iter()
+            .flatten()
+            .map(AlternativeName::name)
+            .collect()
+    }
+
+    fn texts(&self) -> Vec<&str> {
+        self.effect.iter()
+            .flat_map(|e| vec![e.default.as_str(), e.degraded.as_str()])
+            .collect()
+    }
+
+    fn errata(&self) -> &[Erratum] {
+        self.errata.as_deref().unwrap_or_default()
+    }
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Poison {
     pub id: u32,
@@ -0,0 +1,288 @@
+//! Lookup and formatting of the UI strings, which are stored per locale in
+//! the UI directory.
+//!
+//! Keys of nested strings consist of the key of the map and the key within
+//! the map, separated by a dot, such as `nested.a`. Strings may contain
+//! placeholders for positional arguments, such as `{0}`, and named arguments,
+//! such as `{name}`. Literal braces are written as `{{` and `}}`.
+//!
+//! A map which contains the key `other` and otherwise only the plural
+//! categories `zero`, `one`, `two`, `few` and `many` contains the plural forms
+//! of a string, one of which is selected by the count given in the
+//! [MessageArgs]. The plural rules of German, English, Spanish, Italian,
+//! Dutch, French and Polish are supported. All other languages use the
+//! English rule. In any language, the `zero` form is used for the count 0 if
+//! it exists.
+
+use crate::data::{OptolithData, UIValue, UI};
+use crate::data::locale::LocaleChain;
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// The arguments to format a UI string with.
+#[derive(Clone, Debug, Default)]
+pub struct MessageArgs {
+    positional: Vec<String>,
+    named: HashMap<String, String>,
+    count: Option<i64>
+}
+
+impl MessageArgs {
+
+    /// Creates new arguments without any values.
+    pub fn new() -> MessageArgs {
+        MessageArgs::default()
+    }
+
+    /// Adds the next positional argument, i.e. `{0}` for the first one.
+    pub fn arg(mut self, value: impl ToString) -> MessageArgs {
+        self.positional.push(value.to_string());
+        self
+    }
+
+    /// Adds the named argument with the given name, i.e. `{name}`.
+    pub fn named(mut self, name: impl Into<String>, value: impl ToString)
+            -> MessageArgs {
+        self.named.insert(name.into(), value.to_string());
+        self
+    }
+
+    /// Sets the count which selects the plural form of the string. It is
+    /// also available as the named argument `{count}`.
+    pub fn count(mut self, count: i64) -> MessageArgs {
+        self.count = Some(count);
+        self.named("count", count)
+    }
+
+    fn get(&self, placeholder: &str) -> Option<&str> {
+        match placeholder.parse::<usize>() {
+            Ok(index) => self.positional.get(index),
+            Err(_) => self.named.get(placeholder)
+        }.map(String::as_str)
+    }
+}
+
+/// A formatted UI string together with the locale it was taken from.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Message {
+
+    /// The string with all placeholders replaced by their arguments.
+    pub text: String,
+
+    /// The name of the locale the string was taken from.
+    pub locale: String,
+
+    /// Whether the locale does not match the most preferred one of the
+    /// [LocaleChain], i.e. whether the string is not translated into its
+    /// language.
+    pub is_fallback: bool
+}
+
+/// Looks up UI strings by their keys in the best available locale according
+/// to a [LocaleChain]. It is created by
+/// [OptolithData::messages](crate::data::OptolithData::messages).
+pub struct Messages<'a> {
+    data: &'a OptolithData,
+    chain: LocaleChain
+}
+
+impl<'a> Messages<'a> {
+    pub(crate) fn new(data: &'a OptolithData, chain: LocaleChain)
+            -> Messages<'a> {
+        Messages {
+            data,
+            chain
+        }
+    }
+
+    /// The chain by which the locale of each string is selected.
+    pub fn chain(&self) -> &LocaleChain {
+        &self.chain
+    }
+
+    /// Gets the UI string with the given key without formatting it. For
+    /// plural forms, the `other` form is returned.
+    pub fn get(&self, key: &str) -> Option<Message> {
+        let (locale, template) = self.find(key, None)?;
+
+        Some(self.message(locale, template.to_owned()))
+    }
+
+    /// Gets the UI string with the given key and replaces its placeholders by
+    /// the given arguments. Placeholders without an argument are kept as they
+    /// are. The string is taken from the best locale of the chain that
+    /// contains the key, or, for plural forms, the required form.
+    pub fn format(&self, key: &str, args: &MessageArgs) -> Option<Message> {
+        let (locale, template) = self.find(key, args.count)?;
+
+        Some(self.message(locale, interpolate(template, args)))
+    }
+
+    fn find(&self, key: &str, count: Option<i64>)
+            -> Option<(&'a str, &'a str)> {
+        let uis = &self.data.uis;
+        let available = uis.iter()
+            .filter(|(locale, ui)| resolve(ui, locale, key, count).is_some())
+            .map(|(locale, _)| locale.as_str());
+        let locale = self.chain.negotiate(available)?;
+        let template = resolve(&uis[locale], locale, key, count)?;
+
+        Some((locale, template))
+    }
+
+    fn message(&self, locale: &str, text: String) -> Message {
+        Message {
+            text,
+            locale: locale.to_owned(),
+            is_fallback: !self.chain.is_preferred(locale)
+        }
+    }
+}
+
+/// Gets the string with the given key from the UI strings of the given
+/// locale, selecting the plural form for the given count if the key refers to
+/// plural forms.
+fn resolve<'a>(ui: &'a UI, locale: &str, key: &str, count: Option<i64>)
+        -> Option<&'a str> {
+    match ui.get(key) {
+        Some(UIValue::Simple(s)) => return Some(s),
+        Some(UIValue::SubMap(forms)) if is_plural(forms) =>
+            return plural_form(forms, locale, count),
+        _ => { }
+    }
+
+    let (key, sub_key) = key.split_once('.')?;
+
+    match ui.get(key)? {
+        UIValue::SubMap(map) => map.get(sub_key).map(String::as_str),
+        UIValue::Simple(_) => None
+    }
+}
+
+const PLURAL_CATEGORIES: &[&str] = &["zero", "one", "two", "few", "many",
+    "other"];
+
+/// Indicates whether the given map contains the plural forms of a string
+/// rather than nested strings, see the [module documentation](self).
+fn is_plural(map: &BTreeMap<String, String>) -> bool {
+    map.contains_key("other") &&
+        map.keys().all(|key| PLURAL_CATEGORIES.contains(&key.as_str()))
+}
+
+/// Gets the plural category of the given count in the language of the given
+/// locale according to the CLDR plural rules for integers. Languages without
+/// a rule of their own use the English rule.
+fn plural_category(locale: &str, count: i64) -> &'static str {
+    let language = locale.split('-').next().unwrap().to_ascii_lowercase();
+    let n = count.unsigned_abs();
+
+    match language.as_str() {
+        "fr" if n <= 1 => "one",
+        "pl" if n == 1 => "one",
+        "pl" if (2..=4).contains(&(n % 10)) &&
+            !(12..=14).contains(&(n % 100)) => "few",
+        "pl" => "many",
+        _ if n == 1 => "one",
+        _ => "other"
+    }
+}
+
+/// Selects the plural form for the given count from the given map. The
+/// `zero` form is used for 0 if it exists, otherwise the form of the plural
+/// category of the count in the language of the given locale. If that does
+/// not exist or no count is given, the `other` form is used.
+fn plural_form<'a>(forms: &'a BTreeMap<String, String>, locale: &str,
+        count: Option<i64>) -> Option<&'a str> {
+    let candidates = match count {
+        Some(0) => vec!["zero", plural_category(locale, 0), "other"],
+        Some(count) => vec![plural_category(locale, count), "other"],
+        None => vec!["other"]
+    };
+
+    candidates.iter().find_map(|key| forms.get(*key)).map(String::as_str)
+}
+
+/// Replaces the placeholders in the given string by the given arguments. A
+/// placeholder is the name of a named argument or the index of a positional
+/// one enclosed in braces, such as `{name}` or `{0}`. Placeholders without a
+/// matching argument are kept as they are. `{{` and `}}` are replaced by
+/// single braces and any other brace without a partner is kept as it is, so
+/// `{{0}}` results in `{0}` and `a } b` stays unchanged.
+pub fn interpolate(template: &str, args: &MessageArgs) -> String {
+    let mut result = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(index) = rest.find(['{', '}']) {
+        result.push_str(&rest[..index]);
+        let tail = &rest[index..];
+
+        if tail.starts_with("{{") || tail.starts_with("}}") {
+            result.push_str(&tail[..1]);
+            rest = &tail[2..];
+            continue;
+        }
+
+        let end = if tail.starts_with('{') { tail.find('}') } else { None };
+
+        match end {
+            Some(end) => {
+                match args.get(&tail[1..end]) {
+                    Some(value) => result.push_str(value),
+                    None => result.push_str(&tail[..=end])
+                }
+
+                rest = &tail[end + 1..];
+            },
+            None => {
+                result.push_str(&tail[..1]);
+                rest = &tail[1..];
+            }
+        }
+    }
+
+    result.push_str(rest);
+    result
+}
+
+/// Lists the keys of all strings of the given UI strings, including the keys
+/// of nested strings like `nested.a`.
+fn keys(ui: &UI) -> BTreeSet<String> {
+    let mut keys = BTreeSet::new();
+
+    for (key, value) in ui {
+        match value {
+            UIValue::Simple(_) => {
+                keys.insert(key.clone());
+            },
+            UIValue::SubMap(map) => {
+                for sub_key in map.keys() {
+                    keys.insert(format!("{}.{}", key, sub_key));
+                }
+            }
+        }
+    }
+
+    keys
+}
+
+pub(crate) fn missing_keys(uis: &BTreeMap<String, UI>)
+        -> BTreeMap<String, Vec<String>> {
+    let keys = uis.iter()
+        .map(|(locale, ui)| (locale, keys(ui)))
+        .collect::<Vec<_>>();
+    let all = keys.iter()
+        .flat_map(|(_, keys)| keys.iter())
+        .collect::<BTreeSet<_>>();
+
+    keys.iter()
+        .map(|(locale, keys)| {
+            let missing = all.iter()
+                .filter(|key| !keys.contains(key.as_str()))
+                .map(|key| (*key).clone())
+                .collect::<Vec<_>>();
+
+            ((*locale).clone(), missing)
+        })
+        .filter(|(_, missing)| !missing.is_empty())
+        .collect()
+}
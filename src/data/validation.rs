@@ -0,0 +1,2155 @@
+//! Validation of the references between entities. Every entity type
+//! implements [References] to report the IDs of all entities it refers to,
+//! including those in nested prerequisites and select options. Based on this,
+//! [OptolithData::validate](crate::data::OptolithData::validate) finds all
+//! references to entities that do not exist.
+
+use crate::data::{Ids, OptolithData, SingleOrList, SuggestedUnsuitable};
+use crate::data::academy::{
+    Curriculum,
+    ElectiveSpellwork,
+    ElectiveSpellworkRestriction,
+    Guideline,
+    Influence,
+    LessonPackage,
+    LessonPackageSkill,
+    LessonPackageSkillId,
+    RestrictedSpellwork,
+    SpellworkAdjustment,
+    SpellworkChange
+};
+use crate::data::activatable::{
+    ActivatableId,
+    CategoryPrerequisite,
+    CategorySelectOptionPrerequisite,
+    DerivedFromAbilityAPValue,
+    DerivedSelectOption,
+    ExplicitSelectOption,
+    SelectOptionAbility,
+    SelectOptionId,
+    SelectOptions,
+    SkillApplication,
+    SkillUse
+};
+use crate::data::activatable::character_trait::{
+    Advantage,
+    Disadvantage,
+    Trait
+};
+use crate::data::activatable::special_ability::{
+    AdvancedSpecialAbilities,
+    AdvancedSpecialAbility,
+    ComplexAdvancedSpecialAbility,
+    ExternalEntry,
+    FromOption,
+    Mapping,
+    RestrictOption,
+    RestrictOptions,
+    SimpleSpecialAbility,
+    SkillInfluencingSpecialAbility,
+    SpecialAbilityId
+};
+use crate::data::activatable::special_ability::ceremonial_item::{
+    CeremonialItemSpecialAbility
+};
+use crate::data::activatable::special_ability::combat::{
+    AllCombatTechniqueRestriction,
+    AllMeleeCombatTechniqueRestriction,
+    AllRangedCombatTechniqueRestriction,
+    ApplicableCombatTechniques,
+    CombatSpecialAbilitySuper,
+    CombatStyleSpecialAbility,
+    SpecificCombatTechnique,
+    SpecificCombatTechniqueRestriction
+};
+use crate::data::activatable::special_ability::enchantment::{
+    AnimalShape,
+    AnimalShapeSize,
+    BasicEnchantment,
+    CauldronEnchantment,
+    Property as EnchantmentProperty
+};
+use crate::data::activatable::special_ability::familiars_trick::FamiliarsTrick;
+use crate::data::activatable::special_ability::gift::{
+    AutomaticEntry,
+    AutomaticEntryTarget,
+    PactCategory,
+    PactGift
+};
+use crate::data::activatable::special_ability::non_profane::{
+    LiturgicalStyleSpecialAbility,
+    MagicStyleSpecialAbility
+};
+use crate::data::activatable::special_ability::skill::SkillStyleSpecialAbility;
+use crate::data::activatable::special_ability::trade_secret::TradeSecret;
+use crate::data::activatable::special_ability::tradition::{
+    ArcaneProfessionTradition,
+    BlessedTradition,
+    FavoredCombatTechniques,
+    FavoredSkillsSelection,
+    MagicalTradition
+};
+use crate::data::aspect::Aspect;
+use crate::data::attribute::Attribute;
+use crate::data::derived_characteristic::DerivedCharacteristic;
+use crate::data::experience_level::ExperienceLevel;
+use crate::data::item::EquipmentPackage;
+use crate::data::language::{AssociatedLanguage, Language, Script};
+use crate::data::package::PackageSkill;
+use crate::data::package::culture::{Culture, ProfessionsException};
+use crate::data::package::profession::{
+    CantripSelectOptions,
+    CombatTechniqueSelectOptions,
+    Profession,
+    ProfessionCombatTechnique,
+    ProfessionLiturgicalChant,
+    ProfessionOptions,
+    ProfessionSpecialAbility,
+    ProfessionSpell,
+    ProfessionVariant,
+    ProfessionVariantCombatTechnique,
+    ProfessionVariantLiturgicalChant,
+    ProfessionVariantOptions,
+    ProfessionVariantSelectOptions,
+    ProfessionVariantSkill,
+    ProfessionVariantSpecialAbility,
+    ProfessionVariantSpell,
+    SkillSelectOptions,
+    SkillSpecializationSelectOption,
+    SkillSpecializationSelectOptions,
+    SpellSelectOption,
+    SubProfession
+};
+use crate::data::patron::{
+    AdvantageSkillPatronPower,
+    AttributePatronPower,
+    Patron,
+    PatronCategory
+};
+use crate::data::personality_trait::PersonalityTrait;
+use crate::data::prerequisite::{
+    ActivatableListPrerequisite,
+    ActivatableMultiEntryPrerequisite,
+    ActivatableMultiId,
+    ActivatableMultiSelectPrerequisite,
+    ActivatablePrerequisite,
+    ArcaneTraditionListPrerequisite,
+    ArcaneTraditionPrerequisiteNoDisplay,
+    ByLevelPrerequisiteSingle,
+    DisplayPrerequisite,
+    GeneralPrerequisiteNoDisplayNoWhen,
+    IncreasableId,
+    IncreasableListPrerequisite,
+    IncreasableMultiEntryPrerequisite,
+    IncreasableMultiId,
+    IncreasablePrerequisite,
+    InfluenceListPrerequisite,
+    InfluencePrerequisiteNoDisplay,
+    LanguageListPrerequisite,
+    LanguagePrerequisiteNoDisplay,
+    ListOrByLevelPrerequisite,
+    LiturgicalChantListPrerequisite,
+    LiturgicalChantPrerequisite,
+    MagicalTraditionPrerequisite,
+    OnlyActivatablePrerequisiteNoDisplay,
+    OnlyIncreasablePrerequisiteNoDisplay,
+    PactPrerequisite,
+    PersonalityTraitListPrerequisite,
+    PersonalityTraitPrerequisiteNoDisplay,
+    ProfessionListPrerequisite,
+    ProfessionPrerequisiteNoDisplay,
+    PublicationPrerequisite,
+    RacePrerequisite,
+    RuleId,
+    RulePrerequisite,
+    TraitPrerequisiteNoDisplayNoWhen,
+    WhenPrerequisite,
+    WhenSingle
+};
+use crate::data::property::Property;
+use crate::data::publication::Publication;
+use crate::data::race::{
+    AttributeAdjustment,
+    Phenotype,
+    Race,
+    RaceVariant,
+    StartingAge,
+    TypeSpecificData
+};
+use crate::data::rule::{FocusRule, OptionalRule};
+use crate::data::service::Service;
+use crate::data::sex::SexPractice;
+use crate::data::simple::SimpleEntity;
+use crate::data::skill::{Skill, SkillGroup};
+use crate::data::skill::combat::{
+    CombatTechniqueId,
+    MeleeCombatTechnique,
+    RangedCombatTechnique
+};
+use crate::data::skill::non_profane::Enhancement;
+use crate::data::skill::non_profane::karmal::{
+    Blessing,
+    ImprovableKarmalSkill,
+    KarmalWorksId
+};
+use crate::data::skill::non_profane::magical::{
+    AnimistPower,
+    Cantrip,
+    ElvenMagicalSong,
+    JesterTrick,
+    MagicalDance,
+    MagicalMelody,
+    MagicalRune,
+    MagicalSkill,
+    MusicTraditionSpecificData,
+    SimpleMagicalSkill,
+    SpellworkId,
+    StandardSpellworkId,
+    ZibiljaRitual
+};
+use crate::data::src::SourceRef;
+use crate::data::status_effect::{
+    AnimalDisease,
+    Condition,
+    Disease,
+    Poison,
+    State
+};
+use crate::data::Localization;
+use crate::id::{Category, CategoryProvider, Id, Identifiable};
+
+/// A reference from some value to an entity.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Reference {
+
+    /// The path of the field that contains the reference, relative to the
+    /// value, such as `selectOptions.value[2].specific[0]`.
+    pub path: String,
+
+    /// The ID of the entity that is referred to.
+    pub target: Id,
+
+    /// What the value requires of the referred entity if the reference is
+    /// part of a prerequisite.
+    pub requirement: Option<Requirement>
+}
+
+/// What a prerequisite requires of the entity it refers to.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RequirementKind {
+
+    /// The entity has to be active, at the given level if specified.
+    Active {
+        level: Option<u32>
+    },
+
+    /// The entity must not be active, at the given level if specified.
+    Inactive {
+        level: Option<u32>
+    },
+
+    /// The entity has to be at least at the given value.
+    MinimumValue(u32),
+
+    /// The entity has to be chosen as a select option of the activatable
+    /// entity required by the same prerequisite.
+    SelectOption,
+
+    /// The entity has to apply to the character, for example a race, a
+    /// culture or a rule. Prerequisites that allow one of several entities
+    /// refer to each of them with this requirement.
+    Applies
+}
+
+impl RequirementKind {
+    fn activatable(active: bool, level: Option<u32>) -> RequirementKind {
+        if active {
+            RequirementKind::Active { level }
+        }
+        else {
+            RequirementKind::Inactive { level }
+        }
+    }
+}
+
+/// A requirement of a prerequisite on the entity it refers to.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Requirement {
+
+    /// What is required of the entity.
+    pub kind: RequirementKind,
+
+    /// If the prerequisites of the requiring entity differ by its level, the
+    /// level from which on the prerequisite applies.
+    pub from_level: Option<u32>
+}
+
+/// A reference from an entity to another entity that does not exist.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DanglingReference {
+
+    /// The ID of the entity that contains the reference.
+    pub source: Id,
+
+    /// The path of the field within the source entity that contains the
+    /// reference, such as `selectOptions.value[2].specific[0]`.
+    pub path: String,
+
+    /// The ID of the entity that is referred to.
+    pub target: Id
+}
+
+/// Collects the references found in a value while keeping track of the path
+/// of the field that is currently visited.
+#[derive(Default)]
+pub struct ReferenceVisitor {
+    path: String,
+    requirement: Option<RequirementKind>,
+    level: Option<u32>,
+    references: Vec<Reference>
+}
+
+impl ReferenceVisitor {
+    fn in_segment(&mut self, segment: &str, f: impl FnOnce(&mut Self)) {
+        let len = self.path.len();
+        self.path.push_str(segment);
+        f(self);
+        self.path.truncate(len);
+    }
+
+    /// Calls the given function while visiting the field with the given name.
+    pub fn in_field(&mut self, name: &str, f: impl FnOnce(&mut Self)) {
+        if self.path.is_empty() {
+            self.in_segment(name, f)
+        }
+        else {
+            self.in_segment(&format!(".{}", name), f)
+        }
+    }
+
+    /// Calls the given function while visiting the list element with the
+    /// given index.
+    pub fn in_index(&mut self, index: usize, f: impl FnOnce(&mut Self)) {
+        self.in_segment(&format!("[{}]", index), f)
+    }
+
+    /// Calls the given function while visiting the content of a value that is
+    /// tagged by its `type`, which is stored in the field `value`.
+    pub fn in_value(&mut self, f: impl FnOnce(&mut Self)) {
+        self.in_field("value", f)
+    }
+
+    /// Calls the given function while visiting a part of a prerequisite that
+    /// has the given requirement on the entities it refers to.
+    pub fn requiring(&mut self, requirement: RequirementKind,
+            f: impl FnOnce(&mut Self)) {
+        let previous = self.requirement.replace(requirement);
+        f(self);
+        self.requirement = previous;
+    }
+
+    /// Calls the given function while visiting the prerequisites that apply
+    /// from the given level of the visited entity on.
+    pub fn from_level(&mut self, level: u32, f: impl FnOnce(&mut Self)) {
+        let previous = self.level.replace(level);
+        f(self);
+        self.level = previous;
+    }
+
+    /// Visits the references of the field with the given name.
+    pub fn field<T>(&mut self, name: &str, value: &T)
+    where
+        T: References + ?Sized
+    {
+        self.in_field(name, |v| value.visit_references(v))
+    }
+
+    /// Records a reference to the entity of the given category with the given
+    /// ID at the current path.
+    pub fn target(&mut self, category: Category, id: u32) {
+        let from_level = self.level;
+
+        self.references.push(Reference {
+            path: self.path.clone(),
+            target: Id::new(category, id),
+            requirement: self.requirement.map(|kind| Requirement {
+                kind,
+                from_level
+            })
+        });
+    }
+
+    /// Records references to the entities of the given category with the
+    /// given IDs as elements of the list at the current path.
+    pub fn targets<'a>(&mut self, category: Category,
+            ids: impl IntoIterator<Item = &'a u32>) {
+        for (i, &id) in ids.into_iter().enumerate() {
+            self.in_index(i, |v| v.target(category, id));
+        }
+    }
+
+    /// Records references to the entities of the given category with the
+    /// given IDs at the current path.
+    pub fn ids(&mut self, category: Category, ids: &Ids) {
+        match ids {
+            SingleOrList::Single(id) => self.target(category, *id),
+            SingleOrList::List(ids) => self.targets(category, ids)
+        }
+    }
+
+    /// Records a reference to the entity of the given category with the given
+    /// ID in the field with the given name.
+    pub fn reference(&mut self, name: &str, category: Category, id: u32) {
+        self.in_field(name, |v| v.target(category, id))
+    }
+
+    /// Records references to the entities of the given category with the
+    /// given IDs in the list field with the given name.
+    pub fn references<'a>(&mut self, name: &str, category: Category,
+            ids: impl IntoIterator<Item = &'a u32>) {
+        self.in_field(name, |v| v.targets(category, ids))
+    }
+}
+
+/// A trait for values that may refer to entities by their IDs.
+pub trait References {
+
+    /// Visits all references of this value with the given visitor. The
+    /// default implementation is for values without references.
+    fn visit_references(&self, _visitor: &mut ReferenceVisitor) { }
+}
+
+/// Collects all references of the given value.
+pub fn collect_references<T>(value: &T) -> Vec<Reference>
+where
+    T: References + ?Sized
+{
+    let mut visitor = ReferenceVisitor::default();
+    value.visit_references(&mut visitor);
+    visitor.references
+}
+
+/// Appends the references of the given entity that refer to entities missing
+/// from the given data to `dangling`.
+pub(super) fn find_dangling<T>(data: &OptolithData, entity: &T,
+    dangling: &mut Vec<DanglingReference>)
+where
+    T: Identifiable + References
+{
+    let source = entity.id();
+
+    for Reference { path, target, .. } in collect_references(entity) {
+        if data.get_as_entity_ref(target).is_none() {
+            dangling.push(DanglingReference {
+                source,
+                path,
+                target
+            });
+        }
+    }
+}
+
+impl<T: References> References for Option<T> {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        if let Some(value) = self {
+            value.visit_references(visitor);
+        }
+    }
+}
+
+impl<T: References> References for Vec<T> {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        for (i, value) in self.iter().enumerate() {
+            visitor.in_index(i, |v| value.visit_references(v));
+        }
+    }
+}
+
+impl<T: References> References for SingleOrList<T> {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        match self {
+            SingleOrList::Single(value) => value.visit_references(visitor),
+            SingleOrList::List(values) => values.visit_references(visitor)
+        }
+    }
+}
+
+fn visit_suggested(visitor: &mut ReferenceVisitor, name: &str,
+        category: Category, entries: &Option<Vec<SuggestedUnsuitable>>) {
+    visitor.in_field(name, |v| {
+        for (i, entry) in entries.iter().flatten().enumerate() {
+            v.in_index(i, |v| v.reference("id", category, entry.id));
+        }
+    })
+}
+
+impl References for SourceRef {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.reference("id", Category::Publications, self.id);
+    }
+}
+
+// Prerequisites
+
+impl References for RacePrerequisite {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        match self {
+            RacePrerequisite::Single(id) =>
+                visitor.target(Category::Races, *id),
+            RacePrerequisite::List(ids) =>
+                visitor.targets(Category::Races, ids),
+            RacePrerequisite::Object { races, .. } =>
+                visitor.in_field("races", |v| v.ids(Category::Races, races))
+        }
+    }
+}
+
+impl References for PactPrerequisite {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.reference("category", Category::PactCategories,
+            self.category);
+    }
+}
+
+impl References for RuleId {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.in_value(|v| match self {
+            RuleId::FocusRule(id) => v.target(Category::FocusRules, *id),
+            RuleId::OptionalRule(id) =>
+                v.target(Category::OptionalRules, *id)
+        });
+    }
+}
+
+impl References for RulePrerequisite {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.field("id", &self.id);
+    }
+}
+
+impl References for ActivatablePrerequisite {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.requiring(
+            RequirementKind::activatable(self.active, self.level),
+            |v| v.field("id", &self.id));
+        visitor.requiring(RequirementKind::SelectOption,
+            |v| v.field("options", &self.options));
+    }
+}
+
+impl References for ActivatableMultiId {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.references("value", self.act_type.category(), &self.value);
+    }
+}
+
+impl References for ActivatableMultiEntryPrerequisite {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.requiring(
+            RequirementKind::activatable(self.active, self.level),
+            |v| v.field("id", &self.id));
+        visitor.requiring(RequirementKind::SelectOption,
+            |v| v.field("options", &self.options));
+    }
+}
+
+impl References for ActivatableMultiSelectPrerequisite {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.requiring(
+            RequirementKind::activatable(self.active, self.level),
+            |v| v.field("id", &self.id));
+        visitor.requiring(RequirementKind::SelectOption,
+            |v| v.field("otherOptions", &self.other_options));
+    }
+}
+
+impl References for IncreasableId {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.reference("value", self.inc_type.category(), self.value);
+    }
+}
+
+impl References for IncreasablePrerequisite {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.requiring(RequirementKind::MinimumValue(self.value),
+            |v| v.field("id", &self.id));
+    }
+}
+
+impl References for IncreasableMultiId {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.references("value", self.inc_type.category(), &self.value);
+    }
+}
+
+impl References for IncreasableMultiEntryPrerequisite {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.requiring(RequirementKind::MinimumValue(self.value),
+            |v| v.field("id", &self.id));
+    }
+}
+
+impl References for PublicationPrerequisite {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.reference("id", Category::Publications, self.id);
+    }
+}
+
+impl References for WhenSingle {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.in_value(|v| match self {
+            WhenSingle::Publication(p) => p.visit_references(v)
+        });
+    }
+}
+
+impl References for GeneralPrerequisiteNoDisplayNoWhen {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.requiring(RequirementKind::Applies, |v| v.in_value(|v| {
+            match self {
+                GeneralPrerequisiteNoDisplayNoWhen::Race(p) =>
+                    p.visit_references(v),
+                GeneralPrerequisiteNoDisplayNoWhen::Culture(ids) =>
+                    v.ids(Category::Cultures, ids),
+                GeneralPrerequisiteNoDisplayNoWhen::Pact(p) =>
+                    p.visit_references(v),
+                GeneralPrerequisiteNoDisplayNoWhen::SocialStatus(id) =>
+                    v.target(Category::SocialStatuses, *id),
+                GeneralPrerequisiteNoDisplayNoWhen::State(ids) =>
+                    v.ids(Category::States, ids),
+                GeneralPrerequisiteNoDisplayNoWhen::Rule(p) =>
+                    p.visit_references(v),
+                GeneralPrerequisiteNoDisplayNoWhen::Activatable(p) =>
+                    p.visit_references(v),
+                GeneralPrerequisiteNoDisplayNoWhen::ActivatableMultiEntry(p) =>
+                    p.visit_references(v),
+                GeneralPrerequisiteNoDisplayNoWhen::ActivatableMultiSelect(p) =>
+                    p.visit_references(v),
+                GeneralPrerequisiteNoDisplayNoWhen::Increasable(p) =>
+                    p.visit_references(v),
+                GeneralPrerequisiteNoDisplayNoWhen::IncreasableMultiEntry(p) =>
+                    p.visit_references(v),
+                GeneralPrerequisiteNoDisplayNoWhen::Sex(_) |
+                GeneralPrerequisiteNoDisplayNoWhen::PrimaryAttribute(_) |
+                GeneralPrerequisiteNoDisplayNoWhen::ActivatableSpecial(_) |
+                GeneralPrerequisiteNoDisplayNoWhen::TraditionCanUseRituals =>
+                    { }
+            }
+        }));
+    }
+}
+
+impl<P: References> References for DisplayPrerequisite<P> {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        self.prerequisite.visit_references(visitor);
+    }
+}
+
+impl<P: References> References for WhenPrerequisite<P> {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        self.prerequisite.visit_references(visitor);
+        visitor.field("when", &self.when);
+    }
+}
+
+impl<P: References> References for ByLevelPrerequisiteSingle<P> {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.from_level(self.level,
+            |v| v.field("prerequisites", &self.prerequisites));
+    }
+}
+
+impl<L, B> References for ListOrByLevelPrerequisite<L, B>
+where
+    L: References,
+    B: References
+{
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.in_value(|v| match self {
+            ListOrByLevelPrerequisite::Plain(p) => p.visit_references(v),
+            ListOrByLevelPrerequisite::ByLevel(p) =>
+                p.visit_references(v)
+        });
+    }
+}
+
+impl References for TraitPrerequisiteNoDisplayNoWhen {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.requiring(RequirementKind::Applies, |v| v.in_value(|v| {
+            match self {
+                TraitPrerequisiteNoDisplayNoWhen::Race(p) =>
+                    p.visit_references(v),
+                TraitPrerequisiteNoDisplayNoWhen::Culture(ids) =>
+                    v.ids(Category::Cultures, ids),
+                TraitPrerequisiteNoDisplayNoWhen::Pact(p) =>
+                    p.visit_references(v),
+                TraitPrerequisiteNoDisplayNoWhen::SocialStatus(id) =>
+                    v.target(Category::SocialStatuses, *id),
+                TraitPrerequisiteNoDisplayNoWhen::State(ids) =>
+                    v.ids(Category::States, ids),
+                TraitPrerequisiteNoDisplayNoWhen::Rule(p) =>
+                    p.visit_references(v),
+                TraitPrerequisiteNoDisplayNoWhen::Activatable(p) =>
+                    p.visit_references(v),
+                TraitPrerequisiteNoDisplayNoWhen::ActivatableMultiEntry(p) =>
+                    p.visit_references(v),
+                TraitPrerequisiteNoDisplayNoWhen::ActivatableMultiSelect(p) =>
+                    p.visit_references(v),
+                TraitPrerequisiteNoDisplayNoWhen::Increasable(p) =>
+                    p.visit_references(v),
+                TraitPrerequisiteNoDisplayNoWhen::IncreasableMultiEntry(p) =>
+                    p.visit_references(v),
+                TraitPrerequisiteNoDisplayNoWhen::CommonSuggestedByRCP |
+                TraitPrerequisiteNoDisplayNoWhen::Sex(_) |
+                TraitPrerequisiteNoDisplayNoWhen::PrimaryAttribute(_) |
+                TraitPrerequisiteNoDisplayNoWhen::TraditionCanUseRituals => { }
+            }
+        }));
+    }
+}
+
+impl References for ArcaneTraditionPrerequisiteNoDisplay {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.requiring(RequirementKind::Applies, |v| v.in_value(|v| {
+            match self {
+                ArcaneTraditionPrerequisiteNoDisplay::Culture(ids) =>
+                    v.ids(Category::Cultures, ids),
+                ArcaneTraditionPrerequisiteNoDisplay::Sex(_) => { }
+            }
+        }));
+    }
+}
+
+impl References for ArcaneTraditionListPrerequisite {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.in_value(|v| match self {
+            ArcaneTraditionListPrerequisite::Plain(p) =>
+                p.visit_references(v)
+        });
+    }
+}
+
+impl References for OnlyIncreasablePrerequisiteNoDisplay {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.in_value(|v| match self {
+            OnlyIncreasablePrerequisiteNoDisplay::Increasable(p) =>
+                p.visit_references(v)
+        });
+    }
+}
+
+impl References for IncreasableListPrerequisite {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.in_value(|v| match self {
+            IncreasableListPrerequisite::Plain(p) =>
+                p.visit_references(v)
+        });
+    }
+}
+
+impl References for OnlyActivatablePrerequisiteNoDisplay {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.in_value(|v| match self {
+            OnlyActivatablePrerequisiteNoDisplay::Activatable(p) =>
+                p.visit_references(v)
+        });
+    }
+}
+
+impl References for ActivatableListPrerequisite {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.in_value(|v| match self {
+            ActivatableListPrerequisite::Plain(p) =>
+                p.visit_references(v)
+        });
+    }
+}
+
+impl References for InfluencePrerequisiteNoDisplay {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.requiring(RequirementKind::Applies, |v| v.in_value(|v| {
+            match self {
+                InfluencePrerequisiteNoDisplay::MagicalTradition { id } =>
+                    v.reference("id", Category::MagicalTraditions, *id),
+                InfluencePrerequisiteNoDisplay::BlessedTradition { id } =>
+                    v.reference("id", Category::BlessedTraditions, *id),
+                InfluencePrerequisiteNoDisplay::Influence { id, active } =>
+                    v.requiring(RequirementKind::activatable(*active, None),
+                        |v| v.reference("id", Category::Influences, *id)),
+                InfluencePrerequisiteNoDisplay::Special => { }
+            }
+        }));
+    }
+}
+
+impl References for InfluenceListPrerequisite {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.in_value(|v| match self {
+            InfluenceListPrerequisite::Plain(p) => p.visit_references(v)
+        });
+    }
+}
+
+impl References for ProfessionPrerequisiteNoDisplay {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.requiring(RequirementKind::Applies, |v| v.in_value(|v| {
+            match self {
+                ProfessionPrerequisiteNoDisplay::Race(p) =>
+                    p.visit_references(v),
+                ProfessionPrerequisiteNoDisplay::Culture(ids) =>
+                    v.ids(Category::Cultures, ids),
+                ProfessionPrerequisiteNoDisplay::Activatable(p) =>
+                    p.visit_references(v),
+                ProfessionPrerequisiteNoDisplay::Increasable(p) =>
+                    p.visit_references(v),
+                ProfessionPrerequisiteNoDisplay::Sex(_) => { }
+            }
+        }));
+    }
+}
+
+impl References for ProfessionListPrerequisite {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.in_value(|v| match self {
+            ProfessionListPrerequisite::Plain(p) =>
+                p.visit_references(v)
+        });
+    }
+}
+
+impl References for PersonalityTraitPrerequisiteNoDisplay {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.requiring(RequirementKind::Applies, |v| v.in_value(|v| {
+            match self {
+                PersonalityTraitPrerequisiteNoDisplay::Culture(ids) =>
+                    v.ids(Category::Cultures, ids),
+                PersonalityTraitPrerequisiteNoDisplay::Special => { }
+            }
+        }));
+    }
+}
+
+impl References for PersonalityTraitListPrerequisite {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.in_value(|v| match self {
+            PersonalityTraitListPrerequisite::Plain(p) =>
+                p.visit_references(v)
+        });
+    }
+}
+
+impl References for LiturgicalChantPrerequisite {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.requiring(RequirementKind::Applies, |v| v.in_value(|v| {
+            match self {
+                LiturgicalChantPrerequisite::Rule(p) => p.visit_references(v)
+            }
+        }));
+    }
+}
+
+impl References for LiturgicalChantListPrerequisite {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.in_value(|v| match self {
+            LiturgicalChantListPrerequisite::Plain(p) =>
+                p.visit_references(v)
+        });
+    }
+}
+
+impl References for LanguagePrerequisiteNoDisplay {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.requiring(RequirementKind::Applies, |v| v.in_value(|v| {
+            match self {
+                LanguagePrerequisiteNoDisplay::Race(p) =>
+                    p.visit_references(v),
+                LanguagePrerequisiteNoDisplay::Activatable(p) =>
+                    p.visit_references(v)
+            }
+        }));
+    }
+}
+
+impl References for LanguageListPrerequisite {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        match self {
+            LanguageListPrerequisite::Language(p) =>
+                p.visit_references(visitor),
+            LanguageListPrerequisite::Ineligible(_) => { }
+        }
+    }
+}
+
+impl References for MagicalTraditionPrerequisite {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        match self {
+            MagicalTraditionPrerequisite::General(p) =>
+                p.visit_references(visitor),
+            MagicalTraditionPrerequisite::Ineligible(_) => { }
+        }
+    }
+}
+
+// Select options and other parts of activatables
+
+impl References for ActivatableId {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.reference("value", self.act_type.category(), self.value);
+    }
+}
+
+impl References for SelectOptionId {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        if let SelectOptionId::Ability { ability_type, value } = self {
+            let category = match ability_type {
+                SelectOptionAbility::Skill => Category::Skills,
+                SelectOptionAbility::MeleeCombatTechnique =>
+                    Category::MeleeCombatTechniques,
+                SelectOptionAbility::RangedCombatTechnique =>
+                    Category::RangedCombatTechniques
+            };
+
+            visitor.reference("value", category, *value);
+        }
+    }
+}
+
+impl References for CategorySelectOptionPrerequisite {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.requiring(
+            RequirementKind::activatable(self.active, self.level),
+            |v| v.field("target", &self.target));
+    }
+}
+
+impl References for CategoryPrerequisite {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.in_value(|v| match self {
+            CategoryPrerequisite::SelectOption(p) =>
+                p.visit_references(v),
+            CategoryPrerequisite::SelfRating(_) => { }
+        });
+    }
+}
+
+impl References for DerivedSelectOption {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        let category = self.category;
+
+        visitor.references("specific", category,
+            self.specific.iter().flatten());
+
+        if category == Category::Skills {
+            visitor.references("groups", Category::SkillGroups,
+                self.groups.iter().flatten());
+        }
+
+        visitor.references("exclude", category, self.exclude.iter().flatten());
+        visitor.field("prerequisites", &self.prerequisites);
+
+        if let Some(DerivedFromAbilityAPValue::Fixed { list, .. }) =
+                &self.ap_value {
+            visitor.in_field("apValue", |v| v.in_field("list", |v| {
+                for (i, entry) in list.iter().enumerate() {
+                    v.in_index(i, |v| v.reference("id", category, entry.id));
+                }
+            }));
+        }
+    }
+}
+
+impl References for ExplicitSelectOption {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.field("id", &self.id);
+        visitor.field("prerequisites", &self.prerequisites);
+        visitor.field("skillApplications", &self.skill_applications);
+        visitor.field("skillUses", &self.skill_uses);
+        visitor.field("src", &self.src);
+    }
+}
+
+impl References for SelectOptions {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.in_value(|v| match self {
+            SelectOptions::Derived(options) =>
+                options.visit_references(v),
+            SelectOptions::Explicit(options) =>
+                options.visit_references(v)
+        });
+    }
+}
+
+impl References for SkillApplication {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.in_field("skillId",
+            |v| v.ids(Category::Skills, &self.skill_id));
+    }
+}
+
+impl References for SkillUse {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.in_field("skillId",
+            |v| v.ids(Category::Skills, &self.skill_id));
+    }
+}
+
+impl References for SpecialAbilityId {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.reference("value", self.sa_type.category(), self.value);
+    }
+}
+
+impl References for RestrictOption {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        // direct options refer to select options, which are not entities
+        if let RestrictOption::ByType { value, .. } = self {
+            visitor.reference("value", Category::Elements, *value);
+        }
+    }
+}
+
+impl References for RestrictOptions {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        match self {
+            RestrictOptions::Single(option) =>
+                option.visit_references(visitor),
+            RestrictOptions::Multiple(options) =>
+                options.visit_references(visitor)
+        }
+    }
+}
+
+impl References for Mapping {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        match self.from_option {
+            FromOption::Patron(id) =>
+                visitor.reference("fromOption", Category::Patrons, id)
+        }
+    }
+}
+
+/// Visits the advanced special abilities of a style special ability, which
+/// are of the given category.
+fn visit_advanced(visitor: &mut ReferenceVisitor, category: Category,
+        advanced: &AdvancedSpecialAbilities) {
+    visitor.in_field("advanced", |v| {
+        for (i, advanced) in advanced.iter().enumerate() {
+            v.in_index(i, |v| match advanced {
+                AdvancedSpecialAbility::Simple(id) => v.target(category, *id),
+                AdvancedSpecialAbility::SimpleWithOptions { id, option } |
+                AdvancedSpecialAbility::Complex(
+                    ComplexAdvancedSpecialAbility::RestrictOptions {
+                        id,
+                        option
+                    }) => {
+                    v.reference("id", category, *id);
+                    v.field("option", option);
+                },
+                AdvancedSpecialAbility::Complex(
+                    ComplexAdvancedSpecialAbility::OneOf { options, .. }) =>
+                    v.references("options", category, options),
+                AdvancedSpecialAbility::Complex(
+                    ComplexAdvancedSpecialAbility::DeriveFromExternalOption {
+                        external_entry,
+                        mappings
+                    }) => {
+                    match external_entry {
+                        ExternalEntry::MagicalTradition(id) =>
+                            v.in_field("externalEntry", |v| v.reference(
+                                "value", Category::MagicalTraditions, *id))
+                    }
+
+                    v.in_field("mappings", |v| {
+                        for (i, mapping) in mappings.iter().enumerate() {
+                            v.in_index(i, |v| {
+                                mapping.visit_references(v);
+                                v.reference("toAdvanced", category,
+                                    mapping.to_advanced);
+                            });
+                        }
+                    });
+                }
+            });
+        }
+    });
+}
+
+impl References for Trait {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.field("skillApplications", &self.skill_applications);
+        visitor.field("skillUses", &self.skill_uses);
+        visitor.field("selectOptions", &self.select_options);
+        visitor.field("prerequisites", &self.prerequisites);
+        visitor.field("src", &self.src);
+    }
+}
+
+impl References for Advantage {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        self.data.visit_references(visitor);
+    }
+}
+
+impl References for Disadvantage {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        self.data.visit_references(visitor);
+    }
+}
+
+impl<C, L> References for SimpleSpecialAbility<C, L>
+where
+    C: CategoryProvider,
+    L: Localization
+{
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.field("selectOptions", &self.select_options);
+        visitor.field("prerequisites", &self.prerequisites);
+        visitor.field("src", &self.src);
+    }
+}
+
+impl<C, L> References for SkillInfluencingSpecialAbility<C, L>
+where
+    C: CategoryProvider,
+    L: Localization
+{
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.field("skillApplications", &self.skill_applications);
+        visitor.field("skillUses", &self.skill_uses);
+        visitor.field("selectOptions", &self.select_options);
+        visitor.field("prerequisites", &self.prerequisites);
+        visitor.field("src", &self.src);
+    }
+}
+
+impl References for CeremonialItemSpecialAbility {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.field("skillApplications", &self.skill_applications);
+        visitor.field("skillUses", &self.skill_uses);
+        visitor.field("selectOptions", &self.select_options);
+
+        if let Some(aspect) = self.aspect {
+            visitor.reference("aspect", Category::Aspects, aspect);
+        }
+
+        visitor.field("prerequisites", &self.prerequisites);
+        visitor.field("src", &self.src);
+    }
+}
+
+impl References for CombatTechniqueId {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.in_value(|v| match self {
+            CombatTechniqueId::MeleeCombatTechnique(id) =>
+                v.target(Category::MeleeCombatTechniques, *id),
+            CombatTechniqueId::RangedCombatTechnique(id) =>
+                v.target(Category::RangedCombatTechniques, *id)
+        });
+    }
+}
+
+impl References for AllCombatTechniqueRestriction {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.in_value(|v| match self {
+            AllCombatTechniqueRestriction::Race(id) =>
+                v.target(Category::Races, *id),
+            AllCombatTechniqueRestriction::ExcludeTechniques(ids) =>
+                ids.visit_references(v),
+            _ => { }
+        });
+    }
+}
+
+impl References for AllMeleeCombatTechniqueRestriction {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.in_value(|v| match self {
+            AllMeleeCombatTechniqueRestriction::Race(id) =>
+                v.target(Category::Races, *id),
+            AllMeleeCombatTechniqueRestriction::ExcludeTechniques(ids) =>
+                v.targets(Category::MeleeCombatTechniques, ids),
+            _ => { }
+        });
+    }
+}
+
+impl References for AllRangedCombatTechniqueRestriction {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.in_value(|v| match self {
+            AllRangedCombatTechniqueRestriction::Race(id) =>
+                v.target(Category::Races, *id),
+            AllRangedCombatTechniqueRestriction::ExcludeTechniques(ids) =>
+                v.targets(Category::RangedCombatTechniques, ids),
+            _ => { }
+        });
+    }
+}
+
+impl References for SpecificCombatTechniqueRestriction {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        if let SpecificCombatTechniqueRestriction::Race(id) = self {
+            visitor.reference("value", Category::Races, *id);
+        }
+    }
+}
+
+impl References for SpecificCombatTechnique {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.field("id", &self.id);
+        visitor.field("restrictions", &self.restrictions);
+    }
+}
+
+impl References for ApplicableCombatTechniques {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.in_value(|v| match self {
+            ApplicableCombatTechniques::All(r) => r.visit_references(v),
+            ApplicableCombatTechniques::AllMelee(r) =>
+                r.visit_references(v),
+            ApplicableCombatTechniques::AllRanged(r) =>
+                r.visit_references(v),
+            ApplicableCombatTechniques::Specific(r) =>
+                r.visit_references(v),
+            ApplicableCombatTechniques::None |
+            ApplicableCombatTechniques::DependingOnCombatStyle => { }
+        });
+    }
+}
+
+impl<C: CategoryProvider> References for CombatSpecialAbilitySuper<C> {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.field("skillApplications", &self.skill_applications);
+        visitor.field("skillUses", &self.skill_uses);
+        visitor.field("selectOptions", &self.select_options);
+        visitor.field("prerequisites", &self.prerequisites);
+        visitor.field("combatTechniques", &self.combat_techniques);
+        visitor.field("src", &self.src);
+    }
+}
+
+impl References for CombatStyleSpecialAbility {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.field("selectOptions", &self.select_options);
+        visit_advanced(visitor, Category::AdvancedCombatSpecialAbilities,
+            &self.advanced);
+        visitor.field("prerequisites", &self.prerequisites);
+        visitor.field("combatTechniques", &self.combat_techniques);
+        visitor.field("src", &self.src);
+    }
+}
+
+impl References for EnchantmentProperty {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        if let EnchantmentProperty::Single(Some(id)) = self {
+            visitor.target(Category::Properties, *id);
+        }
+    }
+}
+
+impl<C, L> References for BasicEnchantment<C, L>
+where
+    C: CategoryProvider,
+    L: Localization
+{
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.field("selectOptions", &self.select_options);
+        visitor.field("property", &self.property);
+        visitor.field("prerequisites", &self.prerequisites);
+        visitor.field("src", &self.src);
+    }
+}
+
+impl References for CauldronEnchantment {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.field("selectOptions", &self.select_options);
+        visitor.field("property", &self.property);
+        visitor.reference("brew", Category::Brews, self.brew);
+        visitor.field("prerequisites", &self.prerequisites);
+        visitor.field("src", &self.src);
+    }
+}
+
+impl References for AnimalShape {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.reference("path", Category::AnimalShapePaths, self.path);
+        visitor.reference("size", Category::AnimalShapeSizes, self.size);
+    }
+}
+
+impl References for AnimalShapeSize { }
+
+impl References for FamiliarsTrick {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.references("animalTypes", Category::AnimalTypes,
+            self.animal_types.iter().flatten());
+        visitor.reference("property", Category::Properties, self.property);
+        visitor.field("src", &self.src);
+    }
+}
+
+impl References for AutomaticEntryTarget {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        if let AutomaticEntryTarget::Fixed { target_type, value } = self {
+            visitor.in_value(
+                |v| v.reference("value", target_type.category(), *value));
+        }
+    }
+}
+
+impl References for AutomaticEntry {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.field("target", &self.target);
+    }
+}
+
+impl References for PactGift {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.field("selectOptions", &self.select_options);
+        visitor.field("automaticEntries", &self.automatic_entries);
+        visitor.field("prerequisites", &self.prerequisites);
+        visitor.field("src", &self.src);
+    }
+}
+
+impl References for PactCategory {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.field("src", &self.src);
+    }
+}
+
+impl References for MagicStyleSpecialAbility {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        self.data.visit_references(visitor);
+        visit_advanced(visitor, Category::AdvancedMagicalSpecialAbilities,
+            &self.advanced);
+    }
+}
+
+impl References for LiturgicalStyleSpecialAbility {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        self.data.visit_references(visitor);
+        visit_advanced(visitor, Category::AdvancedKarmaSpecialAbilities,
+            &self.advanced);
+    }
+}
+
+impl References for SkillStyleSpecialAbility {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.field("selectOptions", &self.select_options);
+        visit_advanced(visitor, Category::AdvancedSkillSpecialAbilities,
+            &self.advanced);
+        visitor.field("prerequisites", &self.prerequisites);
+        visitor.field("src", &self.src);
+    }
+}
+
+impl References for TradeSecret {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.field("prerequisites", &self.prerequisites);
+        visitor.field("src", &self.src);
+    }
+}
+
+impl References for FavoredCombatTechniques {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        if let FavoredCombatTechniques::List(ids) = self {
+            visitor.in_value(|v| ids.visit_references(v));
+        }
+    }
+}
+
+impl References for FavoredSkillsSelection {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.references("options", Category::Skills, &self.options);
+    }
+}
+
+impl References for BlessedTradition {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.field("skillApplications", &self.skill_applications);
+        visitor.field("skillUses", &self.skill_uses);
+        visitor.field("selectOptions", &self.select_options);
+        visitor.reference("primary", Category::Attributes, self.primary);
+        visitor.references("aspects", Category::Aspects,
+            self.aspects.iter().flatten());
+        visitor.references("restrictedBlessings", Category::Blessings,
+            self.restricted_blessings.iter().flatten());
+        visitor.field("favoredCombatTechniques",
+            &self.favored_combat_techniques);
+        visitor.references("favoredSkills", Category::Skills,
+            &self.favored_skills);
+        visitor.field("favoredSkillsSelection",
+            &self.favored_skills_selection);
+        visitor.field("prerequisites", &self.prerequisites);
+        visitor.field("src", &self.src);
+    }
+}
+
+impl References for MagicalTradition {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.field("selectOptions", &self.select_options);
+
+        if let Some(primary) = self.primary {
+            visitor.reference("primary", Category::Attributes, primary);
+        }
+
+        if let Some(id) = self.use_arcane_spellworks_from_tradition {
+            visitor.reference("useArcaneSpellworksFromTradition",
+                Category::MagicalTraditions, id);
+        }
+
+        visitor.field("prerequisites", &self.prerequisites);
+        visitor.field("src", &self.src);
+    }
+}
+
+impl<C: CategoryProvider> References for ArcaneProfessionTradition<C> {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.field("prerequisites", &self.prerequisites);
+    }
+}
+
+// Skills
+
+impl References for Skill {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.references("check", Category::Attributes, &self.check);
+        visitor.reference("gr", Category::SkillGroups, self.gr);
+        visitor.field("src", &self.src);
+    }
+}
+
+impl References for SkillGroup {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.references("check", Category::Attributes, &self.check);
+    }
+}
+
+impl References for MeleeCombatTechnique {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.references("primary", Category::Attributes, &self.primary);
+        visitor.field("src", &self.src);
+    }
+}
+
+impl References for RangedCombatTechnique {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.references("primary", Category::Attributes, &self.primary);
+        visitor.field("src", &self.src);
+    }
+}
+
+impl References for Enhancement {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        // the prerequisites refer to enhancements of the same entity
+        visitor.field("src", &self.src);
+    }
+}
+
+impl<C: CategoryProvider> References for ImprovableKarmalSkill<C> {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.references("check", Category::Attributes, &self.check);
+        visitor.references("traditions", Category::BlessedTraditions,
+            &self.traditions);
+        visitor.references("aspects", Category::Aspects,
+            self.aspects.iter().flatten());
+        visitor.field("enhancements", &self.enhancements);
+        visitor.field("prerequisites", &self.prerequisites);
+        visitor.field("src", &self.src);
+    }
+}
+
+impl References for Blessing {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.field("src", &self.src);
+    }
+}
+
+impl References for KarmalWorksId {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.in_value(|v| match self {
+            KarmalWorksId::LiturgicalChant(id) =>
+                v.target(Category::LiturgicalChants, *id),
+            KarmalWorksId::Ceremony(id) =>
+                v.target(Category::Ceremonies, *id)
+        });
+    }
+}
+
+impl<C: CategoryProvider> References for MagicalSkill<C> {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.references("check", Category::Attributes, &self.check);
+        visitor.references("traditions", Category::MagicalTraditions,
+            &self.traditions);
+        visitor.references("traditionPlaceholders",
+            Category::MagicalTraditionPlaceholders,
+            self.tradition_placeholders.iter().flatten());
+        visitor.reference("property", Category::Properties, self.property);
+        visitor.field("prerequisites", &self.prerequisites);
+        visitor.field("enhancements", &self.enhancements);
+        visitor.field("src", &self.src);
+    }
+}
+
+impl References for Cantrip {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.references("traditions", Category::MagicalTraditions,
+            &self.traditions);
+        visitor.reference("property", Category::Properties, self.property);
+        visitor.field("prerequisites", &self.prerequisites);
+        visitor.field("src", &self.src);
+    }
+}
+
+/// Visits the music traditions of musical magic, which are arcane
+/// traditions of the given category.
+fn visit_music_traditions(visitor: &mut ReferenceVisitor, category: Category,
+        traditions: &[MusicTraditionSpecificData]) {
+    visitor.in_field("musicTradition", |v| {
+        for (i, tradition) in traditions.iter().enumerate() {
+            v.in_index(i, |v| v.reference("id", category, tradition.id));
+        }
+    });
+}
+
+impl References for MagicalDance {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.references("check", Category::Attributes, &self.check);
+        visitor.reference("property", Category::Properties, self.property);
+        visit_music_traditions(visitor, Category::ArcaneDancerTraditions,
+            &self.music_tradition);
+        visitor.field("src", &self.src);
+    }
+}
+
+impl References for MagicalMelody {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.references("check", Category::Attributes, &self.check);
+        visitor.in_field("skill", |v| v.ids(Category::Skills, &self.skill));
+        visitor.reference("property", Category::Properties, self.property);
+        visit_music_traditions(visitor, Category::ArcaneBardTraditions,
+            &self.music_tradition);
+        visitor.field("src", &self.src);
+    }
+}
+
+impl References for ElvenMagicalSong {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.references("check", Category::Attributes, &self.check);
+        visitor.in_field("skill", |v| v.ids(Category::Skills, &self.skill));
+        visitor.reference("property", Category::Properties, self.property);
+        visitor.field("src", &self.src);
+    }
+}
+
+impl<C, L> References for SimpleMagicalSkill<C, L>
+where
+    C: CategoryProvider,
+    L: Localization
+{
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.references("check", Category::Attributes, &self.check);
+        visitor.reference("property", Category::Properties, self.property);
+        visitor.field("prerequisites", &self.prerequisites);
+        visitor.field("src", &self.src);
+    }
+}
+
+impl References for ZibiljaRitual {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.references("check", Category::Attributes, &self.check);
+        visitor.reference("property", Category::Properties, self.property);
+        visitor.field("src", &self.src);
+    }
+}
+
+impl References for AnimistPower {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.references("check", Category::Attributes, &self.check);
+        visitor.reference("property", Category::Properties, self.property);
+        visitor.references("tribes", Category::Tribes,
+            self.tribes.iter().flatten());
+        visitor.field("src", &self.src);
+    }
+}
+
+impl References for JesterTrick {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.references("check", Category::Attributes, &self.check);
+        visitor.reference("property", Category::Properties, self.property);
+        visitor.field("src", &self.src);
+    }
+}
+
+impl References for MagicalRune {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.references("check", Category::Attributes, &self.check);
+        visitor.reference("property", Category::Properties, self.property);
+        visitor.field("src", &self.src);
+    }
+}
+
+impl References for StandardSpellworkId {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.in_value(|v| match self {
+            StandardSpellworkId::Spell(id) =>
+                v.target(Category::Spells, *id),
+            StandardSpellworkId::Ritual(id) =>
+                v.target(Category::Rituals, *id)
+        });
+    }
+}
+
+impl References for SpellworkId {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        let (category, id) = match self {
+            SpellworkId::Spell(id) => (Category::Spells, id),
+            SpellworkId::Ritual(id) => (Category::Rituals, id),
+            SpellworkId::Curse(id) => (Category::Curses, id),
+            SpellworkId::ElvenMagicalSong(id) =>
+                (Category::ElvenMagicalSongs, id),
+            SpellworkId::DominationRitual(id) =>
+                (Category::DominationRituals, id),
+            SpellworkId::MagicalMelody(id) => (Category::MagicalMelodies, id),
+            SpellworkId::MagicalDance(id) => (Category::MagicalDances, id),
+            SpellworkId::JesterTrick(id) => (Category::JesterTricks, id),
+            SpellworkId::AnimistPower(id) => (Category::AnimistPowers, id),
+            SpellworkId::GeodeRitual(id) => (Category::GeodeRituals, id),
+            SpellworkId::ZibiljaRitual(id) => (Category::ZibiljaRituals, id)
+        };
+
+        visitor.reference("value", category, *id);
+    }
+}
+
+// Packages
+
+impl References for PackageSkill {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.reference("id", Category::Skills, self.id);
+    }
+}
+
+impl References for ProfessionsException {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        // groups are no entities
+        if let ProfessionsException::Single(id) = self {
+            visitor.reference("value", Category::Professions, *id);
+        }
+    }
+}
+
+impl References for Culture {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.references("languages", Category::Languages, &self.languages);
+        visitor.references("literacy", Category::Scripts,
+            self.literacy.iter().flatten());
+        visitor.references("social", Category::SocialStatuses, &self.social);
+        visitor.field("commonMundaneProfessionsExceptions",
+            &self.common_mundane_professions_exceptions);
+        visitor.field("commonMagicalProfessionsExceptions",
+            &self.common_magical_professions_exceptions);
+        visitor.field("commonBlessedProfessionsExceptions",
+            &self.common_blessed_professions_exceptions);
+        visit_suggested(visitor, "commonAdvantages", Category::Advantages,
+            &self.common_advantages);
+        visit_suggested(visitor, "commonDisadvantages",
+            Category::Disadvantages, &self.common_disadvantages);
+        visit_suggested(visitor, "uncommonAdvantages", Category::Advantages,
+            &self.uncommon_advantages);
+        visit_suggested(visitor, "uncommonDisadvantages",
+            Category::Disadvantages, &self.uncommon_disadvantages);
+        visitor.references("commonSkills", Category::Skills,
+            &self.common_skills);
+        visitor.references("uncommonSkills", Category::Skills,
+            self.uncommon_skills.iter().flatten());
+        visitor.field("culturalPackageSkills",
+            &self.cultural_package_skills);
+        visitor.field("src", &self.src);
+    }
+}
+
+impl References for SkillSpecializationSelectOption {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.in_value(|v| match self {
+            SkillSpecializationSelectOption::Single(ids) =>
+                v.ids(Category::Skills, ids),
+            SkillSpecializationSelectOption::Group(ids) =>
+                v.ids(Category::SkillGroups, ids)
+        });
+    }
+}
+
+impl References for SkillSpecializationSelectOptions {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        match self {
+            SkillSpecializationSelectOptions::Single(option) =>
+                option.visit_references(visitor),
+            SkillSpecializationSelectOptions::Multiple(options) =>
+                options.visit_references(visitor)
+        }
+    }
+}
+
+impl References for CombatTechniqueSelectOptions {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.field("targets", &self.targets);
+    }
+}
+
+impl References for CantripSelectOptions {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.references("targets", Category::Cantrips, &self.targets);
+    }
+}
+
+impl References for SkillSelectOptions {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        if let Some(gr) = self.gr {
+            visitor.reference("gr", Category::SkillGroups, gr);
+        }
+    }
+}
+
+impl References for SpellSelectOption {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.field("id", &self.id);
+    }
+}
+
+impl References for ProfessionOptions {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.field("skillSpecialization", &self.skill_specialization);
+        visitor.field("combatTechniques", &self.combat_techniques);
+        visitor.field("cantrips", &self.cantrips);
+        visitor.field("skills", &self.skills);
+        visitor.field("spells", &self.spells);
+        visitor.field("liturgicalChants", &self.liturgical_chants);
+    }
+}
+
+impl References for ProfessionSpecialAbility {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.field("id", &self.id);
+        visitor.field("options", &self.options);
+    }
+}
+
+impl References for ProfessionCombatTechnique {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.field("id", &self.id);
+    }
+}
+
+impl References for ProfessionSpell {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.field("id", &self.id);
+    }
+}
+
+impl References for ProfessionLiturgicalChant {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.field("id", &self.id);
+    }
+}
+
+impl<T: References> References for ProfessionVariantSelectOptions<T> {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        if let ProfessionVariantSelectOptions::Override(options) = self {
+            visitor.in_value(|v| options.visit_references(v));
+        }
+    }
+}
+
+impl References for ProfessionVariantOptions {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.field("skillSpecialization", &self.skill_specialization);
+        visitor.field("combatTechniques", &self.combat_techniques);
+        visitor.field("cantrips", &self.cantrips);
+        visitor.field("skills", &self.skills);
+        visitor.field("spells", &self.spells);
+        visitor.field("liturgicalChants", &self.liturgical_chants);
+    }
+}
+
+impl References for ProfessionVariantSpecialAbility {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.field("id", &self.id);
+        visitor.field("options", &self.options);
+    }
+}
+
+impl References for ProfessionVariantCombatTechnique {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.field("id", &self.id);
+    }
+}
+
+impl References for ProfessionVariantSkill {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.reference("id", Category::Skills, self.id);
+    }
+}
+
+impl References for ProfessionVariantSpell {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.field("id", &self.id);
+    }
+}
+
+impl References for ProfessionVariantLiturgicalChant {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.field("id", &self.id);
+    }
+}
+
+impl References for ProfessionVariant {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.field("prerequisites", &self.prerequisites);
+        visitor.field("options", &self.options);
+        visitor.field("specialAbilities", &self.special_abilities);
+        visitor.field("combatTechniques", &self.combat_techniques);
+        visitor.field("skills", &self.skills);
+        visitor.field("spells", &self.spells);
+        visitor.field("liturgicalChants", &self.liturgical_chants);
+        visitor.references("blessings", Category::Blessings,
+            self.blessings.iter().flatten());
+    }
+}
+
+impl References for SubProfession {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.field("prerequisites", &self.prerequisites);
+        visitor.field("options", &self.options);
+        visitor.field("specialAbilities", &self.special_abilities);
+        visitor.field("combatTechniques", &self.combat_techniques);
+        visitor.field("skills", &self.skills);
+        visitor.field("spells", &self.spells);
+        visitor.field("liturgicalChants", &self.liturgical_chants);
+        visitor.references("blessings", Category::Blessings,
+            self.blessings.iter().flatten());
+        visit_suggested(visitor, "suggestedAdvantages", Category::Advantages,
+            &self.suggested_advantages);
+        visit_suggested(visitor, "suggestedDisadvantages",
+            Category::Disadvantages, &self.suggested_disadvantages);
+        visit_suggested(visitor, "unsuitableAdvantages",
+            Category::Advantages, &self.unsuitable_advantages);
+        visit_suggested(visitor, "unsuitableDisadvantages",
+            Category::Disadvantages, &self.unsuitable_disadvantages);
+        visitor.field("variants", &self.variants);
+
+        if let Some(curriculum) = self.curriculum {
+            visitor.reference("curriculum", Category::Curricula, curriculum);
+        }
+
+        visitor.field("src", &self.src);
+    }
+}
+
+impl References for Profession {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.field("instances", &self.instances);
+    }
+}
+
+// Races
+
+impl References for AttributeAdjustment {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.reference("id", Category::Attributes, self.id);
+    }
+}
+
+impl References for StartingAge {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.reference("experienceLevelId", Category::ExperienceLevels,
+            self.experience_level_id);
+    }
+}
+
+impl References for Phenotype {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.references("commonCultures", Category::Cultures,
+            &self.common_cultures);
+        visitor.references("hairColors", Category::HairColors,
+            &self.hair_colors);
+        visitor.references("eyeColors", Category::EyeColors,
+            &self.eye_colors);
+    }
+}
+
+impl References for RaceVariant {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visit_suggested(visitor, "commonAdvantages", Category::Advantages,
+            &self.common_advantages);
+        visit_suggested(visitor, "commonDisadvantages",
+            Category::Disadvantages, &self.common_disadvantages);
+        visit_suggested(visitor, "uncommonAdvantages", Category::Advantages,
+            &self.uncommon_advantages);
+        visit_suggested(visitor, "uncommonDisadvantages",
+            Category::Disadvantages, &self.uncommon_disadvantages);
+
+        // The fields of the phenotype are flattened into the variant.
+        self.phenotype.visit_references(visitor);
+    }
+}
+
+impl References for TypeSpecificData {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.in_value(|v| match self {
+            TypeSpecificData::WithVariants { variants } =>
+                v.field("variants", variants),
+            TypeSpecificData::WithoutVariants(phenotype) =>
+                phenotype.visit_references(v)
+        });
+    }
+}
+
+impl References for Race {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.field("attributeAdjustments", &self.attribute_adjustments);
+        visitor.references("attributeAdjustmentsSelectionList",
+            Category::Attributes, &self.attribute_adjustments_selection_list);
+        visitor.references("automaticAdvantages", Category::Advantages,
+            self.automatic_advantages.iter().flatten());
+        visit_suggested(visitor, "stronglyRecommendedAdvantages",
+            Category::Advantages, &self.strongly_recommended_advantages);
+        visit_suggested(visitor, "stronglyRecommendedDisadvantages",
+            Category::Disadvantages, &self.strongly_recommended_disadvantages);
+        visit_suggested(visitor, "commonAdvantages", Category::Advantages,
+            &self.common_advantages);
+        visit_suggested(visitor, "commonDisadvantages",
+            Category::Disadvantages, &self.common_disadvantages);
+        visit_suggested(visitor, "uncommonAdvantages", Category::Advantages,
+            &self.uncommon_advantages);
+        visit_suggested(visitor, "uncommonDisadvantages",
+            Category::Disadvantages, &self.uncommon_disadvantages);
+        visitor.field("startingAge", &self.starting_age);
+        visitor.field("typeSpecific", &self.type_specific);
+        visitor.field("src", &self.src);
+    }
+}
+
+// Patrons
+
+impl References for AdvantageSkillPatronPower {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        match self {
+            AdvantageSkillPatronPower::Advantage { id, .. } =>
+                visitor.reference("id", Category::Advantages, *id),
+            AdvantageSkillPatronPower::Skill { id, .. } =>
+                visitor.reference("id", Category::Skills, *id)
+        }
+    }
+}
+
+impl References for AttributePatronPower {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        match self {
+            AttributePatronPower::Attribute { id, .. } =>
+                visitor.reference("id", Category::Attributes, *id)
+        }
+    }
+}
+
+impl References for Patron {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.reference("category", Category::PatronCategories,
+            self.category);
+        visitor.references("skills", Category::Skills, &self.skills);
+        visitor.references("limitedToCultures", Category::Cultures,
+            self.limited_to_cultures.iter().flatten());
+
+        if let Some((advantages_skills, _, attributes)) = &self.powers {
+            visitor.in_field("powers", |v| {
+                v.in_index(0, |v| advantages_skills.visit_references(v));
+                v.in_index(2, |v| attributes.visit_references(v));
+            });
+        }
+    }
+}
+
+impl References for PatronCategory {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.references("primaryPatronCultures", Category::Cultures,
+            &self.primary_patron_cultures);
+    }
+}
+
+// Academies
+
+impl References for ElectiveSpellworkRestriction {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.in_value(|v| match self {
+            ElectiveSpellworkRestriction::Element(id) =>
+                v.target(Category::Elements, *id)
+        });
+    }
+}
+
+impl References for ElectiveSpellwork {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        // The fields of the spellwork ID are flattened into this one.
+        self.spellwork.visit_references(visitor);
+        visitor.field("restriction", &self.restriction);
+    }
+}
+
+impl References for RestrictedSpellwork {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.in_value(|v| match self {
+            RestrictedSpellwork::Property { id, exclude } => {
+                v.reference("id", Category::Properties, *id);
+                v.field("exclude", exclude);
+            },
+            RestrictedSpellwork::Spell(id) =>
+                v.target(Category::Spells, *id),
+            RestrictedSpellwork::Ritual(id) =>
+                v.target(Category::Rituals, *id),
+            RestrictedSpellwork::DemonSummoning |
+            RestrictedSpellwork::Borbaradian |
+            RestrictedSpellwork::DamageIntelligent => { }
+        });
+    }
+}
+
+impl References for SpellworkAdjustment {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.field("id", &self.id);
+    }
+}
+
+impl References for SpellworkChange {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.field("replacement", &self.replacement);
+        visitor.field("base", &self.base);
+    }
+}
+
+impl References for LessonPackageSkillId {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        let (category, id) = match self {
+            LessonPackageSkillId::MeleeCombatTechnique(id) =>
+                (Category::MeleeCombatTechniques, id),
+            LessonPackageSkillId::RangedCombatTechnique(id) =>
+                (Category::RangedCombatTechniques, id),
+            LessonPackageSkillId::Skill(id) => (Category::Skills, id),
+            LessonPackageSkillId::Spell(id) => (Category::Spells, id),
+            LessonPackageSkillId::Ritual(id) => (Category::Rituals, id)
+        };
+
+        visitor.reference("value", category, *id);
+    }
+}
+
+impl References for LessonPackageSkill {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.field("id", &self.id);
+    }
+}
+
+impl References for LessonPackage {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.field("spellworkChanges", &self.spellwork_changes);
+        visitor.field("skills", &self.skills);
+    }
+}
+
+impl References for Curriculum {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.reference("guideline", Category::Guidelines, self.guideline);
+        visitor.field("electiveSpellworks", &self.elective_spellworks);
+        visitor.field("restrictedSpellworks", &self.restricted_spellworks);
+        visitor.field("lessonPackages", &self.lesson_packages);
+    }
+}
+
+impl References for Influence {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.field("prerequisites", &self.prerequisites);
+        visitor.field("src", &self.src);
+    }
+}
+
+// Remaining entities
+
+impl References for AnimalDisease {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.references("animalTypes", Category::AnimalTypes,
+            &self.animal_types);
+        visitor.field("src", &self.src);
+    }
+}
+
+impl References for AssociatedLanguage {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.reference("id", Category::Languages, self.id);
+    }
+}
+
+impl References for Language {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.field("prerequisites", &self.prerequisites);
+        visitor.field("src", &self.src);
+    }
+}
+
+impl References for Script {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.field("associatedLanguages", &self.associated_languages);
+        visitor.field("src", &self.src);
+    }
+}
+
+impl References for PersonalityTrait {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.field("prerequisites", &self.prerequisites);
+        visitor.references("canBeCombinedWith", Category::PersonalityTraits,
+            self.can_be_combined_with.iter().flatten());
+        visitor.field("src", &self.src);
+    }
+}
+
+impl References for FocusRule {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.reference("subject", Category::Subjects, self.subject);
+        visitor.field("src", &self.src);
+    }
+}
+
+impl References for Property {
+    fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+        visitor.references("check", Category::Attributes,
+            self.check.iter().flatten());
+    }
+}
+
+/// Implements [References] for entity types whose only references are their
+/// sources.
+macro_rules! impl_src_references {
+    ($($entity:ty),*) => {
+        $(
+            impl References for $entity {
+                fn visit_references(&self, visitor: &mut ReferenceVisitor) {
+                    visitor.field("src", &self.src);
+                }
+            }
+        )*
+    };
+}
+
+impl_src_references!(Condition, Disease, EquipmentPackage, OptionalRule,
+    Poison, Service, SexPractice, State);
+
+impl<C: CategoryProvider> References for SimpleEntity<C> { }
+
+impl References for Aspect { }
+
+impl References for Attribute { }
+
+impl References for DerivedCharacteristic { }
+
+impl References for ExperienceLevel { }
+
+impl References for Guideline { }
+
+impl References for Publication { }
@@ -0,0 +1,444 @@
+//! Views on the loaded data that only expose the entities from a selection of
+//! publications, such as the rule books enabled for a character.
+
+use crate::character::Rules;
+use crate::data::{OptolithData, Stored};
+use crate::data::academy::{Curriculum, Guideline, Influence};
+use crate::data::activatable::character_trait::{
+    Advantage,
+    Disadvantage,
+    Trait
+};
+use crate::data::activatable::special_ability::{
+    SimpleSpecialAbility,
+    SkillInfluencingSpecialAbility
+};
+use crate::data::activatable::special_ability::ceremonial_item::{
+    CeremonialItemSpecialAbility
+};
+use crate::data::activatable::special_ability::combat::{
+    CombatSpecialAbilitySuper,
+    CombatStyleSpecialAbility
+};
+use crate::data::activatable::special_ability::enchantment::{
+    AnimalShape,
+    AnimalShapeSize,
+    BasicEnchantment,
+    CauldronEnchantment
+};
+use crate::data::activatable::special_ability::familiars_trick::FamiliarsTrick;
+use crate::data::activatable::special_ability::gift::{PactCategory, PactGift};
+use crate::data::activatable::special_ability::non_profane::{
+    NonProfaneStyleSpecialAbility
+};
+use crate::data::activatable::special_ability::skill::SkillStyleSpecialAbility;
+use crate::data::activatable::special_ability::trade_secret::TradeSecret;
+use crate::data::activatable::special_ability::tradition::{
+    ArcaneProfessionTradition,
+    BlessedTradition,
+    MagicalTradition
+};
+use crate::data::aspect::Aspect;
+use crate::data::attribute::Attribute;
+use crate::data::derived_characteristic::DerivedCharacteristic;
+use crate::data::experience_level::ExperienceLevel;
+use crate::data::item::EquipmentPackage;
+use crate::data::language::{Language, Script};
+use crate::data::package::culture::Culture;
+use crate::data::package::profession::{Profession, SubProfession};
+use crate::data::patron::{Patron, PatronCategory};
+use crate::data::personality_trait::PersonalityTrait;
+use crate::data::prerequisite::{WhenPrerequisite, WhenSingle};
+use crate::data::property::Property;
+use crate::data::publication::{Publication, PublicationType};
+use crate::data::race::Race;
+use crate::data::rule::{FocusRule, OptionalRule};
+use crate::data::service::Service;
+use crate::data::sex::SexPractice;
+use crate::data::simple::SimpleEntity;
+use crate::data::skill::{Skill, SkillGroup};
+use crate::data::skill::combat::{MeleeCombatTechnique, RangedCombatTechnique};
+use crate::data::skill::non_profane::karmal::{
+    Blessing,
+    ImprovableKarmalSkill
+};
+use crate::data::skill::non_profane::magical::{
+    AnimistPower,
+    Cantrip,
+    ElvenMagicalSong,
+    JesterTrick,
+    MagicalDance,
+    MagicalMelody,
+    MagicalRune,
+    MagicalSkill,
+    SimpleMagicalSkill,
+    ZibiljaRitual
+};
+use crate::data::src::SourceRef;
+use crate::data::status_effect::{
+    AnimalDisease,
+    Condition,
+    Disease,
+    Poison,
+    State
+};
+use crate::data::Localization;
+use crate::id::CategoryProvider;
+
+use std::collections::{HashMap, HashSet};
+
+/// A view on an [OptolithData] that only exposes the entities which are
+/// contained in at least one of a selection of publications. Publications
+/// which are marked as missing their implementation are never selected.
+/// Entities without any source references, such as attributes, are always
+/// exposed.
+///
+/// For single publications, the view can additionally be restricted to a
+/// specific printing. Then, entities of these publications are only exposed
+/// if they are present in that printing, according to the `since` and
+/// `deprecated` printings of their source references.
+#[derive(Clone)]
+pub struct DataView<'a> {
+    data: &'a OptolithData,
+    publications: HashSet<u32>,
+
+    /// Maps the IDs of the publications restricted to a specific printing to
+    /// the universal identifier of that printing.
+    printings: HashMap<u32, u32>
+}
+
+impl<'a> DataView<'a> {
+
+    /// Creates a view that exposes the entities from the publications with
+    /// the given IDs.
+    pub fn new(data: &'a OptolithData,
+            publications: impl IntoIterator<Item = u32>) -> DataView<'a> {
+        let publications = publications.into_iter()
+            .filter(|&id| data.get::<Publication>(id)
+                .map(|p| !p.is_missing_implementation.unwrap_or(false))
+                .unwrap_or(false))
+            .collect();
+
+        DataView {
+            data,
+            publications,
+            printings: HashMap::new()
+        }
+    }
+
+    /// Creates a view that exposes the entities from all publications.
+    pub fn all(data: &'a OptolithData) -> DataView<'a> {
+        DataView::new(data, data.iter::<Publication>().map(|p| p.id))
+    }
+
+    /// Creates a view that exposes the entities from the rule books enabled
+    /// in the given character rules. Enabled rule books are specified by
+    /// their publisher IDs in any language. The core rules are always
+    /// enabled.
+    pub fn from_rules(data: &'a OptolithData, rules: &Rules) -> DataView<'a> {
+        if rules.enable_all_rule_books.unwrap_or(false) {
+            return DataView::all(data);
+        }
+
+        let enabled = rules.enabled_rule_books.iter()
+            .flatten()
+            .map(String::as_str)
+            .collect::<HashSet<_>>();
+        let publications = data.iter::<Publication>()
+            .filter(|p| match p.pub_type {
+                PublicationType::CoreRules => true,
+                _ => p.translations.values()
+                    .filter_map(|l| l.id.as_deref())
+                    .any(|id| enabled.contains(id))
+            })
+            .map(|p| p.id);
+
+        DataView::new(data, publications)
+    }
+
+    /// Creates a view that only exposes the entities from the core rules.
+    pub fn core_rules(data: &'a OptolithData) -> DataView<'a> {
+        DataView::all(data).retain_publications(|p|
+            matches!(p.pub_type, PublicationType::CoreRules))
+    }
+
+    /// Deselects all publications for which the given predicate returns
+    /// `false`.
+    pub fn retain_publications<F>(mut self, mut predicate: F) -> DataView<'a>
+    where
+        F: FnMut(&Publication) -> bool
+    {
+        let data = self.data;
+
+        self.publications.retain(|&id| data.get::<Publication>(id)
+            .map(&mut predicate)
+            .unwrap_or(false));
+        let publications = &self.publications;
+        self.printings.retain(|id, _| publications.contains(id));
+        self
+    }
+
+    /// Deselects all publications with explicit or adult content, so that
+    /// entities which are only contained in such publications, for example
+    /// sex practices, are hidden.
+    pub fn without_adult_content(self) -> DataView<'a> {
+        self.retain_publications(|p| !p.is_adult_content)
+    }
+
+    /// Restricts this view to the printing with the given universal printing
+    /// identifier for the publication that contains it. That publication is
+    /// selected if it is not marked as missing its implementation. If no
+    /// publication has a printing with the given identifier, the view is
+    /// returned unchanged.
+    pub fn with_printing(mut self, printing: u32) -> DataView<'a> {
+        let found = self.data.iter::<Publication>()
+            .filter(|p| !p.is_missing_implementation.unwrap_or(false))
+            .find(|p| p.printings.iter()
+                .flatten()
+                .any(|pr| pr.id == printing));
+
+        if let Some(publication) = found {
+            self.publications.insert(publication.id);
+            self.printings.insert(publication.id, printing);
+        }
+
+        self
+    }
+
+    /// The underlying data.
+    pub fn data(&self) -> &'a OptolithData {
+        self.data
+    }
+
+    /// Indicates whether the publication with the given ID is selected in
+    /// this view.
+    pub fn is_publication_enabled(&self, id: u32) -> bool {
+        self.publications.contains(&id)
+    }
+
+    /// Indicates whether the given source reference refers to a selected
+    /// publication and, if the view is restricted to a printing of it,
+    /// whether the referenced entry is present in that printing.
+    pub fn includes_source(&self, src: &SourceRef) -> bool {
+        if !self.is_publication_enabled(src.id) {
+            return false;
+        }
+
+        match self.printings.get(&src.id) {
+            Some(&selected) => self.data.get::<Publication>(src.id)
+                .map(|publication| src.is_in_printing(publication, selected))
+                .unwrap_or(true),
+            None => true
+        }
+    }
+
+    /// Indicates whether any of the given source references is included in
+    /// this view. If there are no source references, this is always `true`.
+    pub fn includes_sources(&self, src: &[SourceRef]) -> bool {
+        src.is_empty() || src.iter().any(|src| self.includes_source(src))
+    }
+
+    /// Indicates whether the given `when` conditions of a prerequisite are
+    /// satisfied by this view, that is, whether all publications they require
+    /// are selected.
+    pub fn satisfies_when(&self, when: &[WhenSingle]) -> bool {
+        when.iter().all(|w| match w {
+            WhenSingle::Publication(p) => self.is_publication_enabled(p.id)
+        })
+    }
+
+    /// Indicates whether the given prerequisite takes effect in this view.
+    pub fn applies<P>(&self, prerequisite: &WhenPrerequisite<P>) -> bool {
+        prerequisite.when.as_ref()
+            .map(|when| self.satisfies_when(when))
+            .unwrap_or(true)
+    }
+
+    /// Iterates over the prerequisites from the given list which take effect
+    /// in this view.
+    pub fn prerequisites<'p, P>(&'p self,
+            prerequisites: &'p [WhenPrerequisite<P>])
+            -> impl Iterator<Item = &'p P> + 'p {
+        prerequisites.iter()
+            .filter(move |p| self.applies(p))
+            .map(|p| &p.prerequisite)
+    }
+
+    /// Gets the entity of type `T` with the given internal ID, if it exists
+    /// and is exposed by this view.
+    pub fn get<T: Stored>(&self, id: u32) -> Option<&'a T> {
+        self.data.get::<T>(id).filter(|e| e.is_available(self))
+    }
+
+    /// Iterates over all entities of type `T` exposed by this view in
+    /// ascending order of their IDs.
+    pub fn iter<T: Stored + 'a>(&self) -> impl Iterator<Item = &'a T> + '_ {
+        self.data.iter::<T>().filter(move |e| e.is_available(self))
+    }
+
+    /// Gets the number of entities of type `T` exposed by this view.
+    pub fn len<T: Stored + 'a>(&self) -> usize {
+        self.iter::<T>().count()
+    }
+
+    /// Indicates whether no entities of type `T` are exposed by this view.
+    pub fn is_empty<T: Stored + 'a>(&self) -> bool {
+        self.iter::<T>().next().is_none()
+    }
+}
+
+/// A trait for entities which may be contained in publications. It decides
+/// whether an entity is exposed by a [DataView].
+pub trait Sourced {
+
+    /// Indicates whether this entity is exposed by the given view. The
+    /// default implementation is for entities without source references,
+    /// which are always exposed.
+    fn is_available(&self, _view: &DataView<'_>) -> bool {
+        true
+    }
+}
+
+/// Implements [Sourced] for entity types whose source references are stored
+/// in their `src` field.
+macro_rules! impl_sourced {
+    ($($entity:ty),*) => {
+        $(
+            impl Sourced for $entity {
+                fn is_available(&self, view: &DataView<'_>) -> bool {
+                    view.includes_sources(&self.src)
+                }
+            }
+        )*
+    };
+}
+
+impl_sourced!(AnimalDisease, AnimistPower, BlessedTradition, Blessing,
+    Cantrip, CauldronEnchantment, CeremonialItemSpecialAbility,
+    CombatStyleSpecialAbility, Condition, Culture, Disease, ElvenMagicalSong,
+    EquipmentPackage, FamiliarsTrick, FocusRule, Influence, JesterTrick,
+    Language, MagicalDance, MagicalMelody, MagicalRune, MagicalTradition,
+    MeleeCombatTechnique, OptionalRule, PactCategory, PactGift,
+    PersonalityTrait, Poison, Race, RangedCombatTechnique, Script, Service,
+    SexPractice, Skill, SkillStyleSpecialAbility, State, SubProfession,
+    TradeSecret, Trait, ZibiljaRitual);
+
+impl<C, L> Sourced for BasicEnchantment<C, L>
+where
+    C: CategoryProvider,
+    L: Localization
+{
+    fn is_available(&self, view: &DataView<'_>) -> bool {
+        view.includes_sources(&self.src)
+    }
+}
+
+impl<C: CategoryProvider> Sourced for CombatSpecialAbilitySuper<C> {
+    fn is_available(&self, view: &DataView<'_>) -> bool {
+        view.includes_sources(&self.src)
+    }
+}
+
+impl<C: CategoryProvider> Sourced for ImprovableKarmalSkill<C> {
+    fn is_available(&self, view: &DataView<'_>) -> bool {
+        view.includes_sources(&self.src)
+    }
+}
+
+impl<C: CategoryProvider> Sourced for MagicalSkill<C> {
+    fn is_available(&self, view: &DataView<'_>) -> bool {
+        view.includes_sources(&self.src)
+    }
+}
+
+impl<C, L> Sourced for SimpleMagicalSkill<C, L>
+where
+    C: CategoryProvider,
+    L: Localization
+{
+    fn is_available(&self, view: &DataView<'_>) -> bool {
+        view.includes_sources(&self.src)
+    }
+}
+
+impl<C, L> Sourced for SimpleSpecialAbility<C, L>
+where
+    C: CategoryProvider,
+    L: Localization
+{
+    fn is_available(&self, view: &DataView<'_>) -> bool {
+        view.includes_sources(&self.src)
+    }
+}
+
+impl<C, L> Sourced for SkillInfluencingSpecialAbility<C, L>
+where
+    C: CategoryProvider,
+    L: Localization
+{
+    fn is_available(&self, view: &DataView<'_>) -> bool {
+        view.includes_sources(&self.src)
+    }
+}
+
+impl Sourced for Advantage {
+    fn is_available(&self, view: &DataView<'_>) -> bool {
+        self.data.is_available(view)
+    }
+}
+
+impl Sourced for Disadvantage {
+    fn is_available(&self, view: &DataView<'_>) -> bool {
+        self.data.is_available(view)
+    }
+}
+
+impl<C: CategoryProvider> Sourced for NonProfaneStyleSpecialAbility<C> {
+    fn is_available(&self, view: &DataView<'_>) -> bool {
+        self.data.is_available(view)
+    }
+}
+
+impl Sourced for Profession {
+
+    /// A profession is exposed if any of its instances is.
+    fn is_available(&self, view: &DataView<'_>) -> bool {
+        self.instances.iter().any(|i| i.is_available(view))
+    }
+}
+
+impl Sourced for Publication {
+
+    /// A publication is exposed if it is selected in the view.
+    fn is_available(&self, view: &DataView<'_>) -> bool {
+        view.is_publication_enabled(self.id)
+    }
+}
+
+impl<C: CategoryProvider> Sourced for ArcaneProfessionTradition<C> { }
+
+impl<C: CategoryProvider> Sourced for SimpleEntity<C> { }
+
+impl Sourced for AnimalShape { }
+
+impl Sourced for AnimalShapeSize { }
+
+impl Sourced for Aspect { }
+
+impl Sourced for Attribute { }
+
+impl Sourced for Curriculum { }
+
+impl Sourced for DerivedCharacteristic { }
+
+impl Sourced for ExperienceLevel { }
+
+impl Sourced for Guideline { }
+
+impl Sourced for Patron { }
+
+impl Sourced for PatronCategory { }
+
+impl Sourced for Property { }
+
+impl Sourced for SkillGroup { }
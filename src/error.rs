@@ -1,14 +1,23 @@
 use std::io;
 use std::ffi::OsString;
 use std::fmt::{self, Display, Formatter};
-use std::path::PathBuf;
+use std::path::Path;
 
 /// An enumeration of all kinds errors that can occur in this program.
 #[derive(Debug)]
 pub enum OptolithDataErrorKind {
     IOError(io::Error),
     JSONError(serde_json::Error),
-    YAMLError(serde_yaml::Error)
+    YAMLError(serde_yaml::Error),
+    MessagePackEncodeError(rmp_serde::encode::Error),
+    MessagePackDecodeError(rmp_serde::decode::Error),
+    #[cfg(feature = "zip")]
+    ZipError(zip::result::ZipError),
+    #[cfg(feature = "watch")]
+    WatchError(notify::Error),
+
+    /// A file is not a cache file or was written in an unsupported format.
+    InvalidCache
 }
 
 impl Display for OptolithDataErrorKind {
@@ -16,16 +25,63 @@ impl Display for OptolithDataErrorKind {
         match self {
             OptolithDataErrorKind::IOError(e) => e.fmt(f),
             OptolithDataErrorKind::JSONError(e) => e.fmt(f),
-            OptolithDataErrorKind::YAMLError(e) => e.fmt(f)
+            OptolithDataErrorKind::YAMLError(e) => e.fmt(f),
+            OptolithDataErrorKind::MessagePackEncodeError(e) => e.fmt(f),
+            OptolithDataErrorKind::MessagePackDecodeError(e) => e.fmt(f),
+            #[cfg(feature = "zip")]
+            OptolithDataErrorKind::ZipError(e) => e.fmt(f),
+            #[cfg(feature = "watch")]
+            OptolithDataErrorKind::WatchError(e) => e.fmt(f),
+            OptolithDataErrorKind::InvalidCache =>
+                write!(f, "not a cache file of a supported format")
+        }
+    }
+}
+
+impl OptolithDataErrorKind {
+
+    /// The position in the parsed input where the error occurred, if the
+    /// parser reported one.
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            OptolithDataErrorKind::JSONError(e) if e.line() > 0 =>
+                Some(Location {
+                    line: e.line(),
+                    column: e.column()
+                }),
+            OptolithDataErrorKind::YAMLError(e) =>
+                e.location().map(|l| Location {
+                    line: l.line(),
+                    column: l.column()
+                }),
+            _ => None
         }
     }
 }
 
-/// Wraps an error kind with the file where the error occurred.
+/// A position within a parsed file.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Location {
+
+    /// The one-based line number.
+    pub line: usize,
+
+    /// The one-based column number.
+    pub column: usize
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+/// Wraps an error kind with the file and the field where the error occurred.
 #[derive(Debug)]
 pub struct OptolithDataError {
     kind: OptolithDataErrorKind,
-    file: Option<OsString>
+    file: Option<OsString>,
+    path: Option<String>
 }
 
 impl OptolithDataError {
@@ -40,13 +96,56 @@ impl OptolithDataError {
     pub fn file(&self) -> &Option<OsString> {
         &self.file
     }
+
+    /// The path of the field in the parsed file where the error occurred,
+    /// such as `translations.de-DE.name`, if known.
+    pub fn path(&self) -> Option<&str> {
+        self.path.as_deref()
+    }
+
+    /// The position in the file where the error occurred, if known.
+    pub fn location(&self) -> Option<Location> {
+        self.kind.location()
+    }
+}
+
+impl Display for OptolithDataError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}: ", file.to_string_lossy())?;
+        }
+
+        let message = self.kind.to_string();
+
+        if let Some(path) = &self.path {
+            if !starts_with_path(&message, path) {
+                write!(f, "{}: ", path)?;
+            }
+        }
+
+        f.write_str(&message)
+    }
+}
+
+/// Indicates whether the given message already starts with the given field
+/// path or one of its ancestors, followed by a colon. The YAML parser adds
+/// the path of the enclosing map to some of its messages.
+fn starts_with_path(message: &str, path: &str) -> bool {
+    path.char_indices()
+        .filter(|&(_, c)| c == '.' || c == '[')
+        .map(|(index, _)| &path[..index])
+        .chain(Some(path))
+        .any(|prefix| message.strip_prefix(prefix)
+            .map(|rest| rest.starts_with(": "))
+            .unwrap_or(false))
 }
 
 impl From<io::Error> for OptolithDataError {
     fn from(e: io::Error) -> Self {
         OptolithDataError {
             kind: OptolithDataErrorKind::IOError(e),
-            file: None
+            file: None,
+            path: None
         }
     }
 }
@@ -55,7 +154,8 @@ impl From<serde_json::Error> for OptolithDataError {
     fn from(e: serde_json::Error) -> Self {
         OptolithDataError {
             kind: OptolithDataErrorKind::JSONError(e),
-            file: None
+            file: None,
+            path: None
         }
     }
 }
@@ -64,7 +164,58 @@ impl From<serde_yaml::Error> for OptolithDataError {
     fn from(e: serde_yaml::Error) -> Self {
         OptolithDataError {
             kind: OptolithDataErrorKind::YAMLError(e),
-            file: None
+            file: None,
+            path: None
+        }
+    }
+}
+
+impl From<serde_path_to_error::Error<serde_yaml::Error>>
+for OptolithDataError {
+    fn from(e: serde_path_to_error::Error<serde_yaml::Error>) -> Self {
+        let path = e.path().to_string();
+        let mut error = OptolithDataError::from(e.into_inner());
+
+        if path != "." {
+            error.path = Some(path);
+        }
+
+        error
+    }
+}
+
+impl From<rmp_serde::encode::Error> for OptolithDataError {
+    fn from(e: rmp_serde::encode::Error) -> Self {
+        OptolithDataErrorKind::MessagePackEncodeError(e).into()
+    }
+}
+
+impl From<rmp_serde::decode::Error> for OptolithDataError {
+    fn from(e: rmp_serde::decode::Error) -> Self {
+        OptolithDataErrorKind::MessagePackDecodeError(e).into()
+    }
+}
+
+#[cfg(feature = "zip")]
+impl From<zip::result::ZipError> for OptolithDataError {
+    fn from(e: zip::result::ZipError) -> Self {
+        OptolithDataErrorKind::ZipError(e).into()
+    }
+}
+
+#[cfg(feature = "watch")]
+impl From<notify::Error> for OptolithDataError {
+    fn from(e: notify::Error) -> Self {
+        OptolithDataErrorKind::WatchError(e).into()
+    }
+}
+
+impl From<OptolithDataErrorKind> for OptolithDataError {
+    fn from(kind: OptolithDataErrorKind) -> Self {
+        OptolithDataError {
+            kind,
+            file: None,
+            path: None
         }
     }
 }
@@ -72,7 +223,7 @@ impl From<serde_yaml::Error> for OptolithDataError {
 /// Syntactic sugar for a result value that can also be an [OptolithDataError].
 pub type OptolithDataResult<T> = Result<T, OptolithDataError>;
 
-pub fn set_file<T>(r: &mut OptolithDataResult<T>, file: &PathBuf) {
+pub fn set_file<T>(r: &mut OptolithDataResult<T>, file: &Path) {
     match r {
         Ok(_) => { },
         Err(e) => e.set_file(OsString::from(file.as_os_str()))
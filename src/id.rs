@@ -1,139 +1,168 @@
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+
 pub const PREFIX_ADV: &str = "ADV";
 pub const PREFIX_DISADV: &str = "DISADV";
 pub const PREFIX_SA: &str = "SA";
 
-/// An enumeration of all categories of data that may be referenced.
-#[derive(Copy, Clone, Deserialize, Eq, Hash, PartialEq, Serialize)]
-pub enum Category {
-    AdvancedCombatSpecialAbilities,
-    AdvancedKarmaSpecialAbilities,
-    AdvancedMagicalSpecialAbilities,
-    AdvancedSkillSpecialAbilities,
-    Advantages,
-    AncestorGlyphs,
-    AnimalDiseases,
-    AnimalShapePaths,
-    AnimalShapes,
-    AnimalShapeSizes,
-    AnimalTypes,
-    AnimistPowers,
-    ArcaneBardTraditions,
-    ArcaneDancerTraditions,
-    ArcaneOrbEnchantments,
-    Armors,
-    ArmorTypes,
-    Aspects,
-    AttireEnchantments,
-    Attributes,
-    BlessedTraditions,
-    Blessings,
-    BowlEnchantments,
-    BrawlingSpecialAbilities,
-    Brews,
-    Cantrips,
-    CauldronEnchantments,
-    CeremonialItemSpecialAbilities,
-    Ceremonies,
-    ChronicleEnchantments,
-    CombatSpecialAbilities,
-    CombatSpecialAbilityGroups,
-    CombatStyleSpecialAbilities,
-    CombatTechniqueGroups,
-    CommandSpecialAbilities,
-    Conditions,
-    Cultures,
-    Curricula,
-    Curses,
-    DaggerRituals,
-    DerivedCharacteristics,
-    Disadvantages,
-    Diseases,
-    DominationRituals,
-    Elements,
-    ElvenMagicalSongs,
-    EquipmentPackages,
-    ExperienceLevels,
-    EyeColors,
-    FamiliarSpecialAbilities,
-    FamiliarsTricks,
-    FatePointSexSpecialAbilities,
-    FatePointSpecialAbilities,
-    FocusRules,
-    FoolsHatEnchantments,
-    GeneralSpecialAbilities,
-    GeodeRituals,
-    Guidelines,
-    HairColors,
-    Influences,
-    InstrumentEnchantments,
-    ItemGroups,
-    JesterTricks,
-    KarmaSpecialAbilities,
-    Krallenkettenzauber,
-    Languages,
-    LiturgicalChantGroups,
-    LiturgicalChants,
-    LiturgicalStyleSpecialAbilities,
-    LycantropicGifts,
-    MagicalDances,
-    MagicalMelodies,
-    MagicalRunes,
-    MagicalSpecialAbilities,
-    MagicalTraditions,
-    MagicalTraditionPlaceholders,
-    MagicStyleSpecialAbilities,
-    MeleeCombatTechniques,
-    OptionalRules,
-    OrbEnchantments,
-    PactCategories,
-    PactGifts,
-    PatronCategories,
-    Patrons,
-    PersonalityTraits,
-    Poisons,
-    Professions,
-    Properties,
-    ProtectiveWardingCircleSpecialAbilities,
-    Publications,
-    Races,
-    RangedCombatTechniques,
-    Reaches,
-    Regions,
-    RingEnchantments,
-    Rituals,
-    Scripts,
-    Sermons,
-    Services,
-    SexPractices,
-    SexSpecialAbilities,
-    SickleRituals,
-    SikaryanDrainSpecialAbilities,
-    Skills,
-    SkillGroups,
-    SkillStyleSpecialAbilities,
-    SocialStatuses,
-    SpecialAbilityGroups,
-    SpellGroups,
-    Spells,
-    SpellSwordEnchantments,
-    StaffEnchantments,
-    States,
-    Subjects,
-    ToyEnchantments,
-    TradeSecrets,
-    Tribes,
-    Trinkhornzauber,
-    VampiricGifts,
-    Visions,
-    WandEnchantments,
-    WeaponEnchantments,
-    ZibiljaRituals
+/// Defines the [Category] enum with the given variants together with
+/// [Category::ALL], which lists them in the same order.
+macro_rules! categories {
+    (
+        $(#[$attr:meta])*
+        pub enum Category {
+            $($variant:ident),*
+        }
+    ) => {
+        $(#[$attr])*
+        pub enum Category {
+            $($variant),*
+        }
+
+        impl Category {
+
+            /// All categories in the order of their declaration.
+            pub const ALL: &'static [Category] = &[$(Category::$variant),*];
+        }
+    };
+}
+
+categories! {
+    /// An enumeration of all categories of data that may be referenced.
+    #[derive(Copy, Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq,
+        PartialOrd, Serialize)]
+    #[cfg_attr(feature = "schema", derive(JsonSchema))]
+    pub enum Category {
+        AdvancedCombatSpecialAbilities,
+        AdvancedKarmaSpecialAbilities,
+        AdvancedMagicalSpecialAbilities,
+        AdvancedSkillSpecialAbilities,
+        Advantages,
+        AncestorGlyphs,
+        AnimalDiseases,
+        AnimalShapePaths,
+        AnimalShapes,
+        AnimalShapeSizes,
+        AnimalTypes,
+        AnimistPowers,
+        ArcaneBardTraditions,
+        ArcaneDancerTraditions,
+        ArcaneOrbEnchantments,
+        ArmorTypes,
+        Aspects,
+        AttireEnchantments,
+        Attributes,
+        BlessedTraditions,
+        Blessings,
+        BowlEnchantments,
+        BrawlingSpecialAbilities,
+        Brews,
+        Cantrips,
+        CauldronEnchantments,
+        CeremonialItemSpecialAbilities,
+        Ceremonies,
+        ChronicleEnchantments,
+        CombatSpecialAbilities,
+        CombatSpecialAbilityGroups,
+        CombatStyleSpecialAbilities,
+        CombatTechniqueGroups,
+        CommandSpecialAbilities,
+        Conditions,
+        Cultures,
+        Curricula,
+        Curses,
+        DaggerRituals,
+        DerivedCharacteristics,
+        Disadvantages,
+        Diseases,
+        DominationRituals,
+        Elements,
+        ElvenMagicalSongs,
+        EquipmentPackages,
+        ExperienceLevels,
+        EyeColors,
+        FamiliarSpecialAbilities,
+        FamiliarsTricks,
+        FatePointSexSpecialAbilities,
+        FatePointSpecialAbilities,
+        FocusRules,
+        FoolsHatEnchantments,
+        GeneralSpecialAbilities,
+        GeodeRituals,
+        Guidelines,
+        HairColors,
+        Influences,
+        InstrumentEnchantments,
+        ItemGroups,
+        JesterTricks,
+        KarmaSpecialAbilities,
+        Krallenkettenzauber,
+        Languages,
+        LiturgicalChantGroups,
+        LiturgicalChants,
+        LiturgicalStyleSpecialAbilities,
+        LycantropicGifts,
+        MagicalDances,
+        MagicalMelodies,
+        MagicalRunes,
+        MagicalSpecialAbilities,
+        MagicalTraditions,
+        MagicalTraditionPlaceholders,
+        MagicStyleSpecialAbilities,
+        MeleeCombatTechniques,
+        OptionalRules,
+        OrbEnchantments,
+        PactCategories,
+        PactGifts,
+        PatronCategories,
+        Patrons,
+        PersonalityTraits,
+        Poisons,
+        Professions,
+        Properties,
+        ProtectiveWardingCircleSpecialAbilities,
+        Publications,
+        Races,
+        RangedCombatTechniques,
+        Reaches,
+        Regions,
+        RingEnchantments,
+        Rituals,
+        Scripts,
+        Sermons,
+        Services,
+        SexPractices,
+        SexSpecialAbilities,
+        SickleRituals,
+        SikaryanDrainSpecialAbilities,
+        Skills,
+        SkillGroups,
+        SkillStyleSpecialAbilities,
+        SocialStatuses,
+        SpecialAbilityGroups,
+        SpellGroups,
+        Spells,
+        SpellSwordEnchantments,
+        StaffEnchantments,
+        States,
+        Subjects,
+        ToyEnchantments,
+        TradeSecrets,
+        Tribes,
+        Trinkhornzauber,
+        VampiricGifts,
+        Visions,
+        WandEnchantments,
+        WeaponEnchantments,
+        ZibiljaRituals
+    }
 }
 
 /// A universal ID for data entries.
-#[derive(Copy, Clone, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Copy, Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd,
+    Serialize)]
 pub struct Id {
     category: Category,
     internal_id: u32
@@ -184,5 +213,5 @@ pub fn get_prefix(s: &str) -> &str {
 
 /// Converts a string-ID parsed from a character file to a numeric ID.
 pub fn to_id(s: &str) -> u32 {
-    s.split('_').last().unwrap().parse().unwrap()
+    s.split('_').next_back().unwrap().parse().unwrap()
 }
@@ -1,13 +1,22 @@
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+
+pub mod cache;
 pub mod character;
 pub mod compatibility;
 pub mod data;
 pub mod error;
 pub mod id;
+pub mod source;
 pub mod util;
 
-#[derive(Clone, Deserialize, Serialize)]
+#[cfg(feature = "watch")]
+pub mod watch;
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub enum Sex {
     #[serde(rename = "f")]
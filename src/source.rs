@@ -0,0 +1,252 @@
+//! Sources from which the Optolith data files can be read. Paths within a
+//! source always use `/` as the separator.
+
+use crate::error::{self, OptolithDataResult};
+
+use serde::Deserialize;
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::Path;
+
+#[cfg(any(feature = "tar", feature = "zip"))]
+use std::io::Read;
+
+#[cfg(feature = "zip")]
+use std::io::Seek;
+
+/// A tree of files from which the Optolith data can be loaded.
+pub trait DataSource : Sync {
+
+    /// Lists the names of all files, but not subdirectories, in the directory
+    /// with the given path, in ascending order.
+    fn list_files(&self, dir: &str) -> OptolithDataResult<Vec<String>>;
+
+    /// Reads the entire content of the file with the given path.
+    fn read_file(&self, path: &str) -> OptolithDataResult<Vec<u8>>;
+}
+
+/// Joins a directory path and a file name into a path within a
+/// [DataSource].
+pub fn join(dir: &str, name: &str) -> String {
+    if dir.is_empty() {
+        String::from(name)
+    }
+    else {
+        format!("{}/{}", dir.trim_end_matches('/'), name)
+    }
+}
+
+/// Reads the file with the given path from the given source and parses its
+/// content as YAML into an instance of type `T`.
+pub fn deserialize_yaml_file<T>(source: &dyn DataSource, path: &str)
+    -> OptolithDataResult<T>
+where
+    for<'de> T : Deserialize<'de>
+{
+    let content = source.read_file(path)?;
+    let deserializer = serde_yaml::Deserializer::from_slice(&content);
+    let mut res =
+        serde_path_to_error::deserialize(deserializer).map_err(Into::into);
+    error::set_file(&mut res, Path::new(path));
+    res
+}
+
+fn normalize(path: &str) -> String {
+    let path = path.replace('\\', "/");
+    let mut path = path.as_str();
+
+    while let Some(rest) = path.strip_prefix("./") {
+        path = rest;
+    }
+
+    String::from(path.trim_matches('/'))
+}
+
+/// A [DataSource] that reads from the file system. Paths are interpreted like
+/// any other path of the platform, i.e. relative to the working directory
+/// unless they are absolute.
+pub struct FileSystem;
+
+fn list_files_do(dir: &Path) -> OptolithDataResult<Vec<String>> {
+    let mut files = Vec::new();
+
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+
+        if !entry.file_type()?.is_dir() {
+            files.push(entry.file_name().to_string_lossy().into_owned());
+        }
+    }
+
+    files.sort();
+    Ok(files)
+}
+
+impl DataSource for FileSystem {
+    fn list_files(&self, dir: &str) -> OptolithDataResult<Vec<String>> {
+        let dir = Path::new(dir);
+        let mut res = list_files_do(dir);
+        error::set_file(&mut res, dir);
+        res
+    }
+
+    fn read_file(&self, path: &str) -> OptolithDataResult<Vec<u8>> {
+        let path = Path::new(path);
+        let mut res = fs::read(path).map_err(Into::into);
+        error::set_file(&mut res, path);
+        res
+    }
+}
+
+/// A [DataSource] that holds all files in memory. It can be assembled file by
+/// file, e.g. for tests, or read from a zip or tar archive.
+#[derive(Clone, Default)]
+pub struct MemorySource {
+    files: BTreeMap<String, Vec<u8>>,
+
+    /// All directories, including those that contain no files. The root
+    /// directory is not contained.
+    dirs: BTreeSet<String>
+}
+
+impl MemorySource {
+
+    /// Creates a new, empty memory source.
+    pub fn new() -> MemorySource {
+        MemorySource::default()
+    }
+
+    /// Adds a file with the given path and content, replacing any file with
+    /// the same path.
+    pub fn insert(&mut self, path: &str, content: impl Into<Vec<u8>>) {
+        let path = normalize(path);
+
+        if let Some(slash) = path.rfind('/') {
+            self.create_dir(&path[..slash]);
+        }
+
+        self.files.insert(path, content.into());
+    }
+
+    /// Adds a directory with the given path and all its ancestors, so they
+    /// can be listed even if they contain no files.
+    pub fn create_dir(&mut self, path: &str) {
+        let path = normalize(path);
+        let mut end = path.len();
+
+        while end > 0 && self.dirs.insert(String::from(&path[..end])) {
+            end = path[..end].rfind('/').unwrap_or(0);
+        }
+    }
+
+    /// Removes the file with the given path, returning its content if it
+    /// existed.
+    pub fn remove(&mut self, path: &str) -> Option<Vec<u8>> {
+        self.files.remove(&normalize(path))
+    }
+
+    /// Reads all files from a zip archive.
+    #[cfg(feature = "zip")]
+    pub fn from_zip(reader: impl Read + Seek)
+            -> OptolithDataResult<MemorySource> {
+        let mut archive = zip::ZipArchive::new(reader)?;
+        let mut source = MemorySource::new();
+
+        for i in 0..archive.len() {
+            let mut file = archive.by_index(i)?;
+
+            if file.is_dir() {
+                source.create_dir(file.name());
+                continue;
+            }
+
+            let mut content = Vec::new();
+            file.read_to_end(&mut content)?;
+            source.insert(file.name(), content);
+        }
+
+        Ok(source)
+    }
+
+    /// Reads all regular files and directories from a tar archive. Compressed
+    /// archives need to be decompressed by the given reader.
+    #[cfg(feature = "tar")]
+    pub fn from_tar(reader: impl Read) -> OptolithDataResult<MemorySource> {
+        let mut archive = tar::Archive::new(reader);
+        let mut source = MemorySource::new();
+
+        for entry in archive.entries()? {
+            let mut entry = entry?;
+            let entry_type = entry.header().entry_type();
+            let path = entry.path()?.to_string_lossy().into_owned();
+
+            if entry_type.is_dir() {
+                source.create_dir(&path);
+                continue;
+            }
+            else if !entry_type.is_file() {
+                continue;
+            }
+
+            let mut content = Vec::new();
+            entry.read_to_end(&mut content)?;
+            source.insert(&path, content);
+        }
+
+        Ok(source)
+    }
+
+    /// Creates a memory source that contains all files of this one below the
+    /// directory with the given path, relative to that directory. This is
+    /// useful for archives that wrap the data in a top-level directory.
+    pub fn subdirectory(&self, dir: &str) -> MemorySource {
+        let prefix = join(&normalize(dir), "");
+        let files = self.files.iter()
+            .filter_map(|(path, content)| path.strip_prefix(&prefix)
+                .map(|path| (String::from(path), content.clone())))
+            .collect();
+        let dirs = self.dirs.iter()
+            .filter_map(|path| path.strip_prefix(&prefix))
+            .map(String::from)
+            .collect();
+
+        MemorySource {
+            files,
+            dirs
+        }
+    }
+}
+
+fn not_found(path: &str) -> OptolithDataResult<Vec<u8>> {
+    let mut res = Err(std::io::Error::from(std::io::ErrorKind::NotFound)
+        .into());
+    error::set_file(&mut res, Path::new(path));
+    res
+}
+
+impl DataSource for MemorySource {
+    fn list_files(&self, dir: &str) -> OptolithDataResult<Vec<String>> {
+        let normalized = normalize(dir);
+
+        if !normalized.is_empty() && !self.dirs.contains(&normalized) {
+            not_found(dir)?;
+        }
+
+        let prefix = join(&normalized, "");
+        let files = self.files.keys()
+            .filter_map(|path| path.strip_prefix(&prefix))
+            .filter(|name| !name.contains('/'))
+            .map(String::from)
+            .collect();
+
+        Ok(files)
+    }
+
+    fn read_file(&self, path: &str) -> OptolithDataResult<Vec<u8>> {
+        match self.files.get(&normalize(path)) {
+            Some(content) => Ok(content.clone()),
+            None => not_found(path)
+        }
+    }
+}
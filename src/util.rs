@@ -1,22 +1,23 @@
-use crate::error::{self, OptolithDataResult};
+use crate::error::{self, OptolithDataError, OptolithDataResult};
 
 use serde::{Deserialize, Serialize};
 
 use std::fs::{self, DirEntry, File, ReadDir};
 use std::io::{Read, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-fn deserialize_yaml_file_do<T>(file: &PathBuf) -> OptolithDataResult<T>
+fn deserialize_yaml_file_do<T>(file: &Path) -> OptolithDataResult<T>
 where
     for<'de> T : Deserialize<'de>
 {
-    let yaml = fs::read_to_string(file.as_path())?;
-    Ok(serde_yaml::from_str::<T>(&yaml)?)
+    let yaml = fs::read_to_string(file)?;
+    let deserializer = serde_yaml::Deserializer::from_str(&yaml);
+    Ok(serde_path_to_error::deserialize(deserializer)?)
 }
 
 /// Reads the file with the given path and parses its content as YAML into an
 /// instance of type `T`.
-pub fn deserialize_yaml_file<T>(file: &PathBuf) -> OptolithDataResult<T>
+pub fn deserialize_yaml_file<T>(file: &Path) -> OptolithDataResult<T>
 where
     for<'de> T : Deserialize<'de>
 {
@@ -25,9 +26,56 @@ where
     res
 }
 
+fn serialize_yaml_file_do<T>(file: &Path, t: &T) -> OptolithDataResult<()>
+where
+    T : Serialize
+{
+    let yaml = serde_yaml::to_string(t)?;
+    fs::write(file, yaml)?;
+    Ok(())
+}
+
+/// Serializes the given value as YAML into the file with the given path,
+/// replacing any existing file.
+pub fn serialize_yaml_file<T>(file: &Path, t: &T) -> OptolithDataResult<()>
+where
+    T : Serialize
+{
+    let mut res = serialize_yaml_file_do(file, t);
+    error::set_file(&mut res, file);
+    res
+}
+
+fn serialize_json_file_do<T>(file: &Path, t: &T) -> OptolithDataResult<()>
+where
+    T : Serialize
+{
+    let json = serde_json::to_string_pretty(t)?;
+    fs::write(file, json)?;
+    Ok(())
+}
+
+/// Serializes the given value as human-readable JSON into the file with the
+/// given path, replacing any existing file.
+pub fn serialize_json_file<T>(file: &Path, t: &T) -> OptolithDataResult<()>
+where
+    T : Serialize
+{
+    let mut res = serialize_json_file_do(file, t);
+    error::set_file(&mut res, file);
+    res
+}
+
+/// Creates the directory with the given path and all its missing parents.
+pub fn create_dir_all(path: &Path) -> OptolithDataResult<()> {
+    let mut res = fs::create_dir_all(path).map_err(Into::into);
+    error::set_file(&mut res, path);
+    res
+}
+
 pub struct UtilReadDir<'a> {
     read_dir: ReadDir,
-    file: &'a PathBuf
+    file: &'a Path
 }
 
 impl<'a> Iterator for UtilReadDir<'a> {
@@ -46,14 +94,14 @@ impl<'a> Iterator for UtilReadDir<'a> {
     }
 }
 
-fn read_dir_do(path: &PathBuf) -> OptolithDataResult<UtilReadDir> {
+fn read_dir_do(path: &Path) -> OptolithDataResult<UtilReadDir<'_>> {
     Ok(UtilReadDir {
         read_dir: fs::read_dir(path)?,
         file: path
     })
 }
 
-pub fn read_dir(path: &PathBuf) -> OptolithDataResult<UtilReadDir> {
+pub fn read_dir(path: &Path) -> OptolithDataResult<UtilReadDir<'_>> {
     let mut res = read_dir_do(path);
     error::set_file(&mut res, path);
     res
@@ -66,6 +114,89 @@ pub fn join(p1: &str, p2: &str) -> PathBuf {
     path_buf
 }
 
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+fn fnv_1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, &b| (hash ^ b as u64).wrapping_mul(FNV_PRIME))
+}
+
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>)
+        -> OptolithDataResult<()> {
+    for entry in read_dir(dir)? {
+        let path = entry?.path();
+
+        if path.is_dir() {
+            collect_files(&path, files)?;
+        }
+        else {
+            files.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+/// Computes a hash over the relative paths and contents of all files in the
+/// given directory and its subdirectories. It changes whenever a file is
+/// added, removed, renamed or modified and is stable across platforms and
+/// program runs.
+pub fn hash_directory(path: &Path) -> OptolithDataResult<u64> {
+    let mut files = Vec::new();
+    collect_files(path, &mut files)?;
+    files.sort();
+
+    let mut hash = FNV_OFFSET_BASIS;
+
+    for file in files {
+        let relative = file.strip_prefix(path).unwrap_or(&file);
+        let relative = relative.to_string_lossy().replace('\\', "/");
+        let mut res = fs::read(&file).map_err(OptolithDataError::from);
+        error::set_file(&mut res, &file);
+        let content = res?;
+
+        hash = fnv_1a(hash, relative.as_bytes());
+        hash = fnv_1a(hash, &(content.len() as u64).to_le_bytes());
+        hash = fnv_1a(hash, &content);
+    }
+
+    Ok(hash)
+}
+
+/// Checks whether the given name matches a glob pattern, in which `*` matches
+/// any sequence of characters and `?` matches any single character.
+pub fn matches_glob(pattern: &str, name: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let name = name.chars().collect::<Vec<_>>();
+    let mut p = 0;
+    let mut n = 0;
+
+    // The position of the last `*` in the pattern and the position in the
+    // name where matching continued after it, to backtrack to.
+    let mut star: Option<(usize, usize)> = None;
+
+    while n < name.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == name[n]) {
+            p += 1;
+            n += 1;
+        }
+        else if p < pattern.len() && pattern[p] == '*' {
+            star = Some((p, n));
+            p += 1;
+        }
+        else if let Some((star_p, star_n)) = star {
+            p = star_p + 1;
+            n = star_n + 1;
+            star = Some((star_p, n));
+        }
+        else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
 pub fn to_file<T>(t: &T, path: &str) -> OptolithDataResult<()>
 where
     T : Serialize
@@ -0,0 +1,217 @@
+//! Hot reloading of the Optolith data while its files are edited. A
+//! [DataWatcher] observes the data directory and applies changes of single
+//! files to a loaded [OptolithData] without reloading everything else. This
+//! module requires the `watch` feature.
+
+use crate::data::{self, DataChange, OptolithData};
+use crate::data::options::LoadOptions;
+use crate::error::OptolithDataResult;
+use crate::id::Id;
+use crate::source::{self, DataSource, FileSystem};
+
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, TryRecvError};
+use std::time::Duration;
+
+/// Watches the Optolith data directory for files that are created, modified
+/// or deleted and applies these changes to an [OptolithData] on request.
+///
+/// Entities are stored in files named after their ID, such as
+/// `12_Example.yml`. For files that exist when the watcher is created, this
+/// name is used to determine which entity to remove when the file is deleted
+/// or its content changes the ID. Afterwards, the ID found in the content is
+/// used.
+pub struct DataWatcher {
+    root: PathBuf,
+    options: LoadOptions,
+
+    /// The IDs of the entities stored in the files of the data directory.
+    files: HashMap<PathBuf, Id>,
+
+    /// The files with changes that have not yet been applied, in the order
+    /// of their first change.
+    pending: Vec<PathBuf>,
+    pending_set: HashSet<PathBuf>,
+    errors: Vec<notify::Error>,
+    receiver: Receiver<notify::Result<Event>>,
+
+    // Kept to continue watching, since the watcher stops once dropped.
+    _watcher: RecommendedWatcher
+}
+
+fn file_name_id(file_name: &str) -> Option<u32> {
+    file_name.split('_').next()?.parse().ok()
+}
+
+impl DataWatcher {
+
+    /// Starts watching the data directory with the given path. Changes are
+    /// applied with the default [LoadOptions].
+    pub fn new(path: &str) -> OptolithDataResult<DataWatcher> {
+        DataWatcher::with_options(path, LoadOptions::default())
+    }
+
+    /// Starts watching the data directory with the given path. Only changes
+    /// of files that are selected by the given [LoadOptions] are applied.
+    pub fn with_options(path: &str, options: LoadOptions)
+            -> OptolithDataResult<DataWatcher> {
+        let root = fs::canonicalize(path)?;
+        let (sender, receiver) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(sender)?;
+        watcher.watch(&root, RecursiveMode::Recursive)?;
+
+        let mut files = HashMap::new();
+        let root_str = root.to_string_lossy();
+
+        for &(category, dir_name) in data::CATEGORY_DIRS {
+            let dir = source::join(&root_str, dir_name);
+            let file_names = match FileSystem.list_files(&dir) {
+                Ok(file_names) => file_names,
+                Err(_) => continue
+            };
+
+            for file_name in file_names {
+                if !options.is_loaded(category, &file_name) {
+                    continue;
+                }
+
+                if let Some(internal_id) = file_name_id(&file_name) {
+                    let file = root.join(dir_name).join(&file_name);
+                    files.insert(file, Id::new(category, internal_id));
+                }
+            }
+        }
+
+        Ok(DataWatcher {
+            root,
+            options,
+            files,
+            pending: Vec::new(),
+            pending_set: HashSet::new(),
+            errors: Vec::new(),
+            receiver,
+            _watcher: watcher
+        })
+    }
+
+    fn handle_event(&mut self, event: notify::Result<Event>) {
+        match event {
+            Ok(event) => {
+                if let EventKind::Access(_) = event.kind {
+                    return;
+                }
+
+                for path in event.paths {
+                    if self.pending_set.insert(path.clone()) {
+                        self.pending.push(path);
+                    }
+                }
+            },
+            Err(e) => self.errors.push(e)
+        }
+    }
+
+    /// Blocks until a change is detected or the given timeout elapses.
+    /// Returns `true` if there are changes that have not been applied yet.
+    /// This allows waiting for changes without locking the data.
+    pub fn wait(&mut self, timeout: Duration) -> bool {
+        if self.pending.is_empty() && self.errors.is_empty() {
+            match self.receiver.recv_timeout(timeout) {
+                Ok(event) => self.handle_event(event),
+                Err(RecvTimeoutError::Timeout) => {},
+                Err(RecvTimeoutError::Disconnected) => {}
+            }
+        }
+
+        !self.pending.is_empty() || !self.errors.is_empty()
+    }
+
+    /// Applies all changes detected since the last call to the given data,
+    /// without blocking. Returns the resulting changes of the data in the
+    /// order the files were changed. Files that cannot be loaded leave the
+    /// data unchanged and are reported as errors.
+    pub fn apply_pending(&mut self, data: &mut OptolithData)
+            -> Vec<OptolithDataResult<DataChange>> {
+        loop {
+            match self.receiver.try_recv() {
+                Ok(event) => self.handle_event(event),
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => break
+            }
+        }
+
+        let mut changes = self.errors.drain(..)
+            .map(|e| Err(e.into()))
+            .collect::<Vec<_>>();
+
+        self.pending_set.clear();
+
+        for file in std::mem::take(&mut self.pending) {
+            match self.apply(data, &file) {
+                Ok(file_changes) =>
+                    changes.extend(file_changes.into_iter().map(Ok)),
+                Err(e) => changes.push(Err(e))
+            }
+        }
+
+        changes
+    }
+
+    fn apply(&mut self, data: &mut OptolithData, file: &Path)
+            -> OptolithDataResult<Vec<DataChange>> {
+        let relative = match file.strip_prefix(&self.root) {
+            Ok(relative) => relative,
+            Err(_) => return Ok(Vec::new())
+        };
+        let components = relative.iter()
+            .map(|c| c.to_str())
+            .collect::<Option<Vec<_>>>();
+        let (dir_name, file_name) = match components.as_deref() {
+            Some(&[dir_name, file_name]) => (dir_name, file_name),
+            _ => return Ok(Vec::new())
+        };
+
+        if !file.is_file() {
+            return Ok(self.apply_deletion(data, file, dir_name, file_name));
+        }
+
+        let root = self.root.to_string_lossy();
+        let change = data.reload_file(&FileSystem, &root, &self.options,
+            dir_name, file_name)?;
+        let mut changes = Vec::new();
+
+        if let Some(DataChange::Created(id)) | Some(DataChange::Updated(id)) =
+                &change {
+            if let Some(old_id) = self.files.insert(file.to_owned(), *id) {
+                if old_id != *id && data.remove(old_id) {
+                    changes.push(DataChange::Deleted(old_id));
+                }
+            }
+        }
+
+        changes.extend(change);
+        Ok(changes)
+    }
+
+    fn apply_deletion(&mut self, data: &mut OptolithData, file: &Path,
+            dir_name: &str, file_name: &str) -> Vec<DataChange> {
+        if dir_name == data::UI_DIR {
+            let locale = data::locale_name(file_name);
+
+            if data.remove_ui(&locale) {
+                return vec![DataChange::UIDeleted(locale)];
+            }
+        }
+        else if let Some(id) = self.files.remove(file) {
+            if data.remove(id) {
+                return vec![DataChange::Deleted(id)];
+            }
+        }
+
+        Vec::new()
+    }
+}
@@ -0,0 +1,107 @@
+mod common;
+
+use optolith_rs::cache;
+use optolith_rs::compatibility::Compatibility;
+use optolith_rs::data::{Localization, OptolithData, Translatable};
+use optolith_rs::data::attribute::Attribute;
+use optolith_rs::error::OptolithDataErrorKind;
+use optolith_rs::id::{Category, Id};
+use optolith_rs::util;
+
+use std::path::Path;
+
+#[test]
+fn snapshot_is_read_instead_of_the_directory() {
+    let dir = tempfile::tempdir().unwrap();
+    let cache = dir.path().join("data.cache");
+    let cache = cache.to_str().unwrap();
+    let built = OptolithData::load_cached_or_build(common::DATA_DIR, cache)
+        .unwrap();
+    let (_, read) = cache::read::<OptolithData>(cache).unwrap();
+
+    assert!(common::load().diff(&built).is_empty());
+    assert!(built.diff(&read).is_empty());
+
+    // A snapshot that differs from the directory, but claims to be up to date
+    // with it, shows that the directory is not loaded again.
+    let spell = Id::new(Category::Spells, 1);
+    let mut modified = common::load();
+    modified.remove(spell);
+    let content_hash =
+        util::hash_directory(Path::new(common::DATA_DIR)).unwrap();
+    cache::write(&modified, content_hash, cache).unwrap();
+    let cached = OptolithData::load_cached_or_build(common::DATA_DIR, cache)
+        .unwrap();
+
+    assert!(modified.diff(&cached).is_empty());
+    assert!(cached.get_as_entity(spell).is_none());
+}
+
+#[test]
+fn changed_file_invalidates_the_snapshot() {
+    let data_dir = common::copy_data_dir();
+    let data_path = data_dir.path().to_str().unwrap();
+    let dir = tempfile::tempdir().unwrap();
+    let cache = dir.path().join("data.cache");
+    let cache = cache.to_str().unwrap();
+    OptolithData::load_cached_or_build(data_path, cache).unwrap();
+    let old_hash = cache::read_header(cache).unwrap().content_hash();
+
+    common::copy_dir(&common::fixture("cache_edit"), data_dir.path());
+    let new_hash = util::hash_directory(data_dir.path()).unwrap();
+
+    assert_ne!(old_hash, new_hash);
+    assert!(!cache::read_header(cache).unwrap().is_up_to_date(new_hash));
+
+    let data = OptolithData::load_cached_or_build(data_path, cache).unwrap();
+    let name = data.get::<Attribute>(1).unwrap()
+        .translate("en-US").unwrap()
+        .name();
+
+    assert_eq!("Courage", name);
+    assert_eq!(new_hash, cache::read_header(cache).unwrap().content_hash());
+}
+
+#[test]
+fn undecodable_up_to_date_snapshot_is_invalid() {
+    let dir = tempfile::tempdir().unwrap();
+    let cache = dir.path().join("data.cache");
+    let cache = cache.to_str().unwrap();
+    let content_hash =
+        util::hash_directory(Path::new(common::DATA_DIR)).unwrap();
+    cache::write(&"no data", content_hash, cache).unwrap();
+    let error = OptolithData::load_cached_or_build(common::DATA_DIR, cache)
+        .err().unwrap();
+
+    assert!(matches!(error.kind(), OptolithDataErrorKind::InvalidCache));
+    assert_eq!(Some(cache), error.file().as_ref().and_then(|f| f.to_str()));
+}
+
+#[test]
+fn compatibility_snapshot_is_read_instead_of_the_directory() {
+    let dir = tempfile::tempdir().unwrap();
+    let cache = dir.path().join("compatibility.cache");
+    let cache = cache.to_str().unwrap();
+    let built =
+        Compatibility::load_cached_or_build(common::COMPATIBILITY_DIR, cache)
+            .unwrap();
+    let (header, read) = cache::read::<Compatibility>(cache).unwrap();
+
+    common::assert_maps_the_compatibility_dir(&built);
+    common::assert_maps_the_compatibility_dir(&read);
+
+    // As above, a differing snapshot that claims to be up to date shows that
+    // the directory is not loaded again.
+    let mut source =
+        common::read_source(Path::new(common::COMPATIBILITY_DIR));
+    source.insert("AdvantagesIdMap.yml", "- [ADV_2, 2]");
+    let modified = Compatibility::from_source(&source, "").unwrap();
+    cache::write(&modified, header.content_hash(), cache).unwrap();
+    let cached =
+        Compatibility::load_cached_or_build(common::COMPATIBILITY_DIR, cache)
+            .unwrap();
+
+    assert_eq!(None, cached.get_str_id("ADV_1"));
+    assert_eq!(Some(Id::new(Category::Advantages, 2)),
+        cached.get_str_id("ADV_2"));
+}
@@ -0,0 +1,25 @@
+mod common;
+
+use optolith_rs::id::{Category, Id, Identifiable};
+
+#[test]
+fn every_category_resolves() {
+    let data = common::load();
+
+    for &category in Category::ALL {
+        let id = Id::new(category, 1);
+        let entity = data.get_as_entity(id)
+            .unwrap_or_else(|| panic!("{:?} does not resolve", category));
+        let entity_ref = data.get_as_entity_ref(id).unwrap();
+
+        assert_eq!(id, entity.id());
+        assert_eq!(id, entity_ref.id());
+    }
+}
+
+#[test]
+fn test_data_has_no_dangling_references() {
+    let data = common::load();
+
+    assert!(data.validate().is_empty());
+}
@@ -0,0 +1,50 @@
+mod common;
+
+use optolith_rs::data::locale::LocaleChain;
+use optolith_rs::data::src::{Occurrence, Occurrences, SourceRef};
+use optolith_rs::data::view::DataView;
+
+use std::collections::BTreeMap;
+
+fn source(pages: &[(u32, Option<u32>)]) -> SourceRef {
+    let occurrences = pages.iter()
+        .map(|&(first_page, last_page)| Occurrence {
+            first_page,
+            last_page
+        })
+        .collect();
+    let mut occurrences_by_locale = BTreeMap::new();
+    occurrences_by_locale.insert(String::from("en-US"),
+        Occurrences::Many(occurrences));
+
+    SourceRef {
+        id: 1,
+        since: None,
+        deprecated: None,
+        occurrences: occurrences_by_locale
+    }
+}
+
+#[test]
+fn overlapping_and_adjacent_pages_are_merged() {
+    let data = common::load();
+    let src = source(&[(113, None), (112, Some(113)), (114, None),
+        (120, None)]);
+    let text = data.citations(LocaleChain::new(vec!["en-US"])).format(&[src]);
+
+    assert_eq!("Example pp. 112–114, 120", text);
+}
+
+#[test]
+fn views_exclude_sources_deprecated_in_the_printing() {
+    let data = common::load();
+    let view = DataView::all(&data).with_printing(1);
+    let current = source(&[(1, None)]);
+    let deprecated = SourceRef {
+        deprecated: Some(1),
+        ..source(&[(1, None)])
+    };
+
+    assert!(view.includes_source(&current));
+    assert!(!view.includes_source(&deprecated));
+}
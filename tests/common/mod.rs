@@ -0,0 +1,139 @@
+//! Helpers shared by the integration tests.
+
+#![allow(dead_code)]
+
+use optolith_rs::compatibility::Compatibility;
+use optolith_rs::data::OptolithData;
+use optolith_rs::id::{Category, Id};
+use optolith_rs::source::MemorySource;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use tempfile::TempDir;
+
+/// The path of the test data directory, which contains one entity with the
+/// ID 1 per loaded category. All names are "Beispiel" in German and
+/// "Example" in English, and all other texts contain Markdown, embedded HTML,
+/// a `<` sign and a link to the spell with the ID 1.
+pub const DATA_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data");
+
+/// The path of the test compatibility directory. It maps `ADV_1`, `TRAD_1`,
+/// the skills `TAL_1` to `TAL_59`, disease 1 and poison 2 to the entities with
+/// the ID 1 of their categories, and contains no other mappings.
+pub const COMPATIBILITY_DIR: &str =
+    concat!(env!("CARGO_MANIFEST_DIR"), "/tests/compatibility");
+
+/// The path of the directory that contains the fixtures of single tests. Each
+/// fixture is a directory laid out like the test data directory, which only
+/// contains the files the test adds to or replaces in the test data.
+pub const FIXTURES_DIR: &str =
+    concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures");
+
+/// Loads the test data directory with the default options.
+pub fn load() -> OptolithData {
+    OptolithData::from_directory(DATA_DIR).unwrap()
+}
+
+/// Gets the path of the fixture with the given name.
+pub fn fixture(name: &str) -> PathBuf {
+    Path::new(FIXTURES_DIR).join(name)
+}
+
+/// Copies all files and directories within `from` into `to`, replacing files
+/// which already exist.
+pub fn copy_dir(from: &Path, to: &Path) {
+    fs::create_dir_all(to).unwrap();
+
+    for entry in fs::read_dir(from).unwrap() {
+        let entry = entry.unwrap();
+        let target = to.join(entry.file_name());
+
+        if entry.file_type().unwrap().is_dir() {
+            copy_dir(&entry.path(), &target);
+        }
+        else {
+            fs::copy(entry.path(), target).unwrap();
+        }
+    }
+}
+
+/// Copies the test data directory to a new temporary directory, which is
+/// deleted once the returned [TempDir] is dropped.
+pub fn copy_data_dir() -> TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    copy_dir(Path::new(DATA_DIR), dir.path());
+    dir
+}
+
+/// Copies the test data directory to a new temporary directory like
+/// [copy_data_dir] and adds the files of the given fixtures in the given
+/// order.
+pub fn copy_data_dir_with(fixtures: &[&str]) -> TempDir {
+    let dir = copy_data_dir();
+
+    for name in fixtures {
+        copy_dir(&fixture(name), dir.path());
+    }
+
+    dir
+}
+
+fn insert_dir(source: &mut MemorySource, dir: &Path, path: &str) {
+    source.create_dir(path);
+
+    for entry in fs::read_dir(dir).unwrap() {
+        let entry = entry.unwrap();
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let path = format!("{}/{}", path, name);
+
+        if entry.file_type().unwrap().is_dir() {
+            insert_dir(source, &entry.path(), &path);
+        }
+        else {
+            source.insert(&path, fs::read(entry.path()).unwrap());
+        }
+    }
+}
+
+/// Reads the directory with the given path into a [MemorySource], in which it
+/// is the root directory.
+pub fn read_source(dir: &Path) -> MemorySource {
+    let mut source = MemorySource::new();
+    insert_dir(&mut source, dir, "");
+    source
+}
+
+/// Reads the test data directory into a [MemorySource] and adds the files of
+/// the given fixtures in the given order.
+pub fn source_with(fixtures: &[&str]) -> MemorySource {
+    let mut source = read_source(Path::new(DATA_DIR));
+
+    for name in fixtures {
+        insert_dir(&mut source, &fixture(name), "");
+    }
+
+    source
+}
+
+/// Loads the test data directory together with the files of the given
+/// fixtures with the default options.
+pub fn load_with(fixtures: &[&str]) -> OptolithData {
+    OptolithData::from_source(&source_with(fixtures), "").unwrap()
+}
+
+/// Asserts that the given mappings are those of the test compatibility
+/// directory.
+pub fn assert_maps_the_compatibility_dir(compat: &Compatibility) {
+    let id = |category| Some(Id::new(category, 1));
+
+    assert_eq!(id(Category::Advantages), compat.get_str_id("ADV_1"));
+    assert_eq!(id(Category::MagicalTraditions), compat.get_str_id("TRAD_1"));
+    assert_eq!(Some(Id::new(Category::Skills, 59)),
+        compat.get_str_id("TAL_59"));
+    assert_eq!(id(Category::Diseases), compat.get_disease_id(1));
+    assert_eq!(id(Category::Poisons), compat.get_poison_id(2));
+    assert_eq!(None, compat.get_str_id("ADV_2"));
+    assert_eq!(None, compat.get_str_id("TAL_60"));
+    assert_eq!(None, compat.get_poison_id(1));
+}
@@ -0,0 +1,35 @@
+mod common;
+
+use optolith_rs::data::validation::{Requirement, RequirementKind};
+use optolith_rs::id::{Category, Id};
+
+#[test]
+fn dependent_index_is_cached_until_the_data_changes() {
+    let mut data = common::load();
+    let advantage = Id::new(Category::Advantages, 1);
+    let index = data.dependent_index();
+
+    assert!(std::ptr::eq(index, data.dependent_index()));
+    assert!(index.dependents(advantage).iter().any(|d| d.id == advantage));
+
+    data.remove(advantage);
+
+    assert!(data.dependent_index().dependents(advantage).iter()
+        .all(|d| d.id != advantage));
+}
+
+#[test]
+fn dependent_paths_use_file_keys() {
+    let data = common::load();
+    let advantage = Id::new(Category::Advantages, 1);
+    let cantrip = Id::new(Category::Cantrips, 1);
+    let dependent = data.dependent_index().dependents(advantage).iter()
+        .find(|d| d.id == cantrip)
+        .unwrap();
+
+    assert_eq!("prerequisites.value[0].value.id.value", dependent.path);
+    assert_eq!(Requirement {
+        kind: RequirementKind::Inactive { level: Some(1) },
+        from_level: None
+    }, dependent.requirement);
+}
@@ -0,0 +1,85 @@
+mod common;
+
+use optolith_rs::data::OptolithData;
+use optolith_rs::data::diff::{ChangeSummary, EntityChange, FieldChange};
+use optolith_rs::data::locale::LocaleChain;
+use optolith_rs::id::{Category, Id};
+
+use serde_json::{json, Value};
+
+/// Loads the entities of the `cross_references` fixture before and after
+/// applying the `changed_entities` fixture, which changes the name, AP value
+/// and source of advantage 2 and the prerequisites of cantrip 2 and adds
+/// skill 3.
+fn versions() -> (OptolithData, OptolithData) {
+    (common::load_with(&["cross_references"]),
+        common::load_with(&["cross_references", "changed_entities"]))
+}
+
+fn field(path: &str, old: Value, new: Value) -> FieldChange {
+    FieldChange {
+        path: path.to_owned(),
+        old: Some(old),
+        new: Some(new)
+    }
+}
+
+#[test]
+fn changed_fields_are_listed_by_path() {
+    let (old, new) = versions();
+    let diff = old.diff(&new);
+
+    assert_eq!(vec![
+        &EntityChange::Changed {
+            id: Id::new(Category::Advantages, 2),
+            fields: vec![
+                field("apValue.value", json!(8), json!(10)),
+                field("src[0].occurrences.en-US.firstPage", json!(2),
+                    json!(3)),
+                field("translations.en-US.name", json!("Nimble"),
+                    json!("Agile"))
+            ]
+        }
+    ], diff.category_changes(Category::Advantages).collect::<Vec<_>>());
+    assert_eq!(vec![
+        &EntityChange::Changed {
+            id: Id::new(Category::Cantrips, 2),
+            fields: vec![
+                field("prerequisites.value[0].value.active", json!(true),
+                    json!(false))
+            ]
+        }
+    ], diff.category_changes(Category::Cantrips).collect::<Vec<_>>());
+}
+
+#[test]
+fn added_and_removed_entities_are_counted() {
+    let (old, mut new) = versions();
+    new.remove(Id::new(Category::Spells, 1));
+    let diff = old.diff(&new);
+    let summary = diff.summary();
+    let count = |added, removed, changed|
+        ChangeSummary { added, removed, changed };
+
+    assert_eq!(vec![&EntityChange::Added(Id::new(Category::Skills, 3))],
+        diff.category_changes(Category::Skills).collect::<Vec<_>>());
+    assert_eq!(vec![
+        (Category::Advantages, count(0, 0, 1)),
+        (Category::Cantrips, count(0, 0, 1)),
+        (Category::Skills, count(1, 0, 0)),
+        (Category::Spells, count(0, 1, 0))
+    ], summary.into_iter().collect::<Vec<_>>());
+    assert!(old.diff(&old).is_empty());
+}
+
+#[test]
+fn reports_name_entities_in_the_best_available_locale() {
+    let old = common::load();
+    let mut new = common::load();
+    new.remove(Id::new(Category::Spells, 1));
+    let chain = LocaleChain::new(vec!["fr-FR", "en-US"]);
+    let report = old.diff(&new).report(&old, &new, &chain);
+
+    assert_eq!("Spells: 0 added, 1 removed, 0 changed\n  - 1 Example\n",
+        report);
+}
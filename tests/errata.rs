@@ -0,0 +1,115 @@
+mod common;
+
+use optolith_rs::data::errata::{Date, ErrataFeed, Erratum};
+use optolith_rs::id::{Category, Id};
+
+use serde_json::Value;
+
+/// Collects the English errata of the equipment packages, which are those of
+/// the test data (2021-01-01), the `invalid_erratum_date` fixture
+/// (2021-13-01) and the `errata_dates` fixture (2021-03-01, 2021-06-30,
+/// 2021-06-30 and 2022-01-15).
+fn feed() -> ErrataFeed {
+    common::load_with(&["invalid_erratum_date", "errata_dates"])
+        .errata("en-US")
+        .in_categories(&[Category::EquipmentPackages])
+}
+
+fn date(year: u16, month: u8, day: u8) -> Date {
+    Date::new(year, month, day).unwrap()
+}
+
+fn entries(feed: &ErrataFeed) -> Vec<(u32, &str)> {
+    feed.entries().iter()
+        .map(|entry| (entry.id.internal_id(), entry.raw_date.as_str()))
+        .collect()
+}
+
+#[test]
+fn invalid_erratum_dates_are_kept_without_a_date() {
+    let data = common::load_with(&["invalid_erratum_date"]);
+    let undated = data.errata("en-US").undated();
+    let entry = &undated.entries()[0];
+
+    assert_eq!(1, undated.len());
+    assert_eq!(Id::new(Category::EquipmentPackages, 2), entry.id);
+    assert_eq!("2021-13-01", entry.raw_date);
+    assert_eq!("## 2021-13-01\n\n\
+        - **Travel Pack** (EquipmentPackages): The pack contains a rope.\n",
+        undated.to_markdown());
+}
+
+#[test]
+fn dates_of_errata_are_parsed_from_the_written_date() {
+    let valid = Erratum::new("2021-03-01", "A rope was added.");
+    let invalid = Erratum::new("2021-13-01", "A rope was added.");
+
+    assert_eq!(Some(date(2021, 3, 1)), valid.date());
+    assert_eq!(None, invalid.date());
+    assert_eq!("2021-13-01", invalid.raw_date());
+}
+
+#[test]
+fn feeds_are_filtered_by_category() {
+    let all = common::load_with(&["invalid_erratum_date", "errata_dates"])
+        .errata("en-US");
+    let feed = feed();
+
+    assert!(all.len() > feed.len());
+    assert_eq!(vec![
+        (1, "2021-01-01"),
+        (2, "2021-13-01"),
+        (3, "2021-03-01"),
+        (3, "2021-06-30"),
+        (4, "2021-06-30"),
+        (4, "2022-01-15")
+    ], entries(&feed));
+    assert!(feed.in_categories(&[Category::Spells]).is_empty());
+}
+
+#[test]
+fn feeds_are_filtered_by_date() {
+    let since = feed().since(date(2021, 6, 30));
+    let between = feed().between(date(2021, 3, 1), date(2021, 6, 30));
+
+    assert_eq!(vec![(3, "2021-06-30"), (4, "2021-06-30"), (4, "2022-01-15")],
+        entries(&since));
+    assert_eq!(vec![(3, "2021-03-01"), (3, "2021-06-30"), (4, "2021-06-30")],
+        entries(&between));
+}
+
+#[test]
+fn feeds_are_sorted_by_date_keeping_the_order_of_equal_dates() {
+    let oldest_first = feed().oldest_first();
+    let newest_first = feed().newest_first();
+
+    assert_eq!(vec![
+        (2, "2021-13-01"),
+        (1, "2021-01-01"),
+        (3, "2021-03-01"),
+        (3, "2021-06-30"),
+        (4, "2021-06-30"),
+        (4, "2022-01-15")
+    ], entries(&oldest_first));
+    assert_eq!(vec![
+        (4, "2022-01-15"),
+        (3, "2021-06-30"),
+        (4, "2021-06-30"),
+        (3, "2021-03-01"),
+        (1, "2021-01-01"),
+        (2, "2021-13-01")
+    ], entries(&newest_first));
+}
+
+#[test]
+fn json_contains_null_for_invalid_dates() {
+    let feed = feed().filter(|entry| entry.id.internal_id() <= 2);
+    let json = serde_json::from_str::<Value>(&feed.to_json().unwrap())
+        .unwrap();
+
+    assert_eq!(Value::from("2021-01-01"), json[0]["date"]);
+    assert_eq!(Value::from("2021-01-01"), json[0]["raw_date"]);
+    assert_eq!(Value::Null, json[1]["date"]);
+    assert_eq!(Value::from("2021-13-01"), json[1]["raw_date"]);
+    assert_eq!(Value::from("Travel Pack"), json[1]["name"]);
+}
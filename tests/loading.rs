@@ -0,0 +1,118 @@
+mod common;
+
+use optolith_rs::data::{MissingLocales, OptolithData, Translatable};
+use optolith_rs::data::academy::Curriculum;
+use optolith_rs::data::attribute::Attribute;
+use optolith_rs::data::options::LoadOptions;
+use optolith_rs::data::package::profession::Profession;
+use optolith_rs::data::skill::non_profane::magical::Spell;
+use optolith_rs::error::OptolithDataError;
+use optolith_rs::id::{Category, Id};
+
+use std::collections::HashSet;
+use std::path::Path;
+
+fn file_and_path(error: &OptolithDataError) -> (Option<&str>, Option<&str>) {
+    (error.file().as_ref().and_then(|f| f.to_str()), error.path())
+}
+
+#[test]
+fn strict_professions_and_curricula_name_file_and_field() {
+    let profession = common::source_with(&["mismatching_profession"]);
+    let curriculum = common::source_with(&["mismatching_curriculum"]);
+    let options = LoadOptions {
+        lenient_categories: HashSet::new(),
+        ..LoadOptions::default()
+    };
+    let load = |source| OptolithData::from_source_with_options(source, "",
+        &options);
+    let profession_error = load(&profession).err().unwrap();
+    let curriculum_error = load(&curriculum).err().unwrap();
+
+    assert_eq!((Some("Professions/2_Mismatch.yml"), Some("instances[0].gr")),
+        file_and_path(&profession_error));
+    assert_eq!((Some("Curricula/2_Mismatch.yml"),
+            Some("lessonPackages[0].translations.en-US.name")),
+        file_and_path(&curriculum_error));
+}
+
+#[test]
+fn mismatching_professions_and_curricula_are_reported_by_default() {
+    let source = common::source_with(
+        &["mismatching_profession", "mismatching_curriculum"]);
+    let report = OptolithData::from_source_with_options(&source, "",
+        &LoadOptions::default()).unwrap();
+    let failures = report.failures().iter()
+        .map(file_and_path)
+        .collect::<Vec<_>>();
+
+    assert_eq!(vec![
+        (Some("Curricula/2_Mismatch.yml"),
+            Some("lessonPackages[0].translations.en-US.name")),
+        (Some("Professions/2_Mismatch.yml"), Some("instances[0].gr"))
+    ], failures);
+    assert!(report.data().get::<Curriculum>(1).is_some());
+    assert!(report.data().get::<Profession>(1).is_some());
+    assert!(report.data().get::<Profession>(2).is_none());
+    assert!(report.data().get::<Attribute>(1).is_some());
+}
+
+#[test]
+fn mismatching_profession_is_skipped_by_default() {
+    let source = common::source_with(&["mismatching_profession"]);
+    let data = OptolithData::from_source(&source, "").unwrap();
+
+    assert!(data.get::<Profession>(1).is_some());
+    assert!(data.get::<Profession>(2).is_none());
+}
+
+#[test]
+fn lenient_loading_reports_all_broken_files() {
+    let dir = common::copy_data_dir_with(&["broken_files"]);
+    let report =
+        OptolithData::from_directory_lenient(dir.path().to_str().unwrap())
+            .unwrap();
+    let failures = report.failures().iter()
+        .map(|e| {
+            let file = e.file().as_ref().unwrap();
+            let file = Path::new(file).strip_prefix(dir.path()).unwrap();
+            (file.to_path_buf(), e.location().map(|l| (l.line, l.column)))
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(vec![
+        (Path::new("Attributes").join("2_Broken.yml"), Some((5, 1))),
+        (Path::new("Spells").join("2_Broken.yml"), Some((2, 8)))
+    ], failures);
+    assert!(!report.is_complete());
+    assert!(report.data().get::<Attribute>(1).is_some());
+    assert!(report.data().get::<Spell>(1).is_some());
+    assert!(report.data().get::<Spell>(2).is_none());
+}
+
+#[test]
+fn only_selected_locales_are_kept() {
+    let source = common::source_with(&["german_only_attribute"]);
+    let options = LoadOptions {
+        locales: Some(vec![String::from("en-US")].into_iter().collect()),
+        ..LoadOptions::default()
+    };
+    let report = OptolithData::from_source_with_options(&source, "", &options)
+        .unwrap();
+    let data = report.data();
+    let example = data.get::<Attribute>(1).unwrap();
+    let german_only = data.get::<Attribute>(2).unwrap();
+    let expected_missing = vec![
+        MissingLocales {
+            id: Id::new(Category::Attributes, 2),
+            locales: vec![String::from("en-US")]
+        }
+    ];
+
+    assert_eq!(vec!["en-US"], example.locales());
+    assert!(example.translate("de-DE").is_none());
+    assert!(german_only.locales().is_empty());
+    assert!(data.get_ui_string("en-US", "header.title").is_some());
+    assert!(data.get_ui_string("de-DE", "header.title").is_none());
+    assert_eq!(expected_missing, report.missing_locales());
+}
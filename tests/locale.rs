@@ -0,0 +1,55 @@
+mod common;
+
+use optolith_rs::data::Translatable;
+use optolith_rs::data::attribute::Attribute;
+use optolith_rs::data::locale::LocaleChain;
+
+#[test]
+fn exact_matches_are_preferred() {
+    let chain = LocaleChain::new(vec!["de-DE"]);
+
+    assert_eq!(Some("de-DE"), chain.negotiate(vec!["de", "de-AT", "de-DE"]));
+    assert_eq!(Some("DE-de"), chain.negotiate(vec!["de", "DE-de"]));
+}
+
+#[test]
+fn prefixes_match_more_specific_locales() {
+    let chain = LocaleChain::new(vec!["de-DE"]);
+
+    assert_eq!(Some("de"), chain.negotiate(vec!["de-AT", "de", "en-US"]));
+}
+
+#[test]
+fn primary_languages_match_other_regions() {
+    let chain = LocaleChain::new(vec!["en"]);
+
+    assert_eq!(Some("en-US"), chain.negotiate(vec!["de-DE", "en-US", "en-GB"]));
+}
+
+#[test]
+fn next_locales_of_the_chain_are_only_tried_without_a_match() {
+    let chain = LocaleChain::new(vec!["nl-BE", "fr", "en-US"]);
+
+    assert_eq!(Some("en-US"), chain.negotiate(vec!["de-DE", "en-US"]));
+    assert_eq!(Some("fr-FR"), chain.negotiate(vec!["en-US", "fr-FR"]));
+    assert_eq!(None, chain.negotiate(vec!["de-DE", "it-IT"]));
+    assert_eq!(None, LocaleChain::new(Vec::<String>::new())
+        .negotiate(vec!["en-US"]));
+}
+
+#[test]
+fn translations_in_other_languages_are_fallbacks() {
+    let data = common::load();
+    let attribute = data.get::<Attribute>(1).unwrap();
+    let regional = attribute
+        .translate_negotiated(&LocaleChain::new(vec!["de-AT", "en-US"]))
+        .unwrap();
+    let fallback = attribute
+        .translate_negotiated(&LocaleChain::new(vec!["fr-FR", "en-US"]))
+        .unwrap();
+
+    assert_eq!("de-DE", regional.locale);
+    assert!(!regional.is_fallback);
+    assert_eq!("en-US", fallback.locale);
+    assert!(fallback.is_fallback);
+}
@@ -0,0 +1,81 @@
+#![cfg(feature = "markdown")]
+
+mod common;
+
+use optolith_rs::data::locale::LocaleChain;
+
+use serde_json::Value;
+
+use std::fs;
+use std::path::Path;
+
+/// The Markdown which all texts of the test data consist of.
+const SAMPLE: &str = "Some *text*, <b>tags</b>, a < sign and [](Spells/1).";
+
+/// The tags the HTML of [SAMPLE] may contain.
+const TAGS: &[&str] =
+    &["<p>", "</p>", "<em>", "</em>", "<a class=\"entity\" ", "</a>"];
+
+/// Collects the strings within the translations of the given value at any
+/// depth together with their locales.
+fn collect_texts<'a>(value: &'a Value, locale: Option<&'a str>,
+        texts: &mut Vec<(&'a str, &'a str)>) {
+    match value {
+        Value::String(text) => texts.extend(locale.map(|l| (l, &text[..]))),
+        Value::Array(values) => for value in values {
+            collect_texts(value, locale, texts);
+        },
+        Value::Object(map) => for (key, value) in map {
+            match (key.as_str(), value) {
+                ("translations", Value::Object(translations)) =>
+                    for (locale, value) in translations {
+                        collect_texts(value, Some(locale), texts);
+                    },
+                _ => collect_texts(value, locale, texts)
+            }
+        },
+        _ => { }
+    }
+}
+
+fn count_samples(dir: &Path) -> usize {
+    fs::read_dir(dir).unwrap()
+        .map(|entry| entry.unwrap().path())
+        .map(|path| if path.is_dir() {
+            count_samples(&path)
+        }
+        else {
+            fs::read_to_string(path).unwrap().matches(SAMPLE).count()
+        })
+        .sum()
+}
+
+#[test]
+fn all_texts_render_to_html_and_plain_text() {
+    let data = common::load();
+    let value = serde_json::to_value(&data).unwrap();
+    let mut texts = Vec::new();
+    collect_texts(&value, None, &mut texts);
+    let samples = texts.iter()
+        .filter(|(_, text)| *text == SAMPLE)
+        .collect::<Vec<_>>();
+
+    assert_eq!(count_samples(Path::new(common::DATA_DIR)), samples.len());
+
+    for (locale, text) in samples {
+        let name = if *locale == "de-DE" { "Beispiel" } else { "Example" };
+        let markdown = data.markdown(LocaleChain::new(vec![*locale]));
+        let html = markdown.to_html(text);
+        let plain_text = markdown.to_plain_text(text);
+        let link = format!("<a class=\"entity\" href=\"#Spells/1\">{}</a>",
+            name);
+
+        assert!(html.match_indices('<')
+                .all(|(i, _)| TAGS.iter().any(|t| html[i..].starts_with(t))),
+            "{}", html);
+        assert!(html.contains("&lt;b&gt;tags&lt;/b&gt;, a &lt; sign"));
+        assert!(html.contains(&link), "{}", html);
+        assert_eq!(format!("Some text, <b>tags</b>, a < sign and {}.", name),
+            plain_text);
+    }
+}
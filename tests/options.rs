@@ -0,0 +1,102 @@
+mod common;
+
+use optolith_rs::data::OptolithData;
+use optolith_rs::data::attribute::Attribute;
+use optolith_rs::data::options::{FileFilter, LoadOptions};
+use optolith_rs::data::skill::non_profane::magical::Spell;
+use optolith_rs::data::status_effect::Poison;
+use optolith_rs::id::Category;
+use optolith_rs::util;
+
+fn load(fixtures: &[&str], options: &LoadOptions) -> OptolithData {
+    let source = common::source_with(fixtures);
+    OptolithData::from_source_with_options(&source, "", options).unwrap()
+        .into_data()
+}
+
+fn patterns(patterns: &[&str]) -> Vec<String> {
+    patterns.iter().map(|&p| String::from(p)).collect()
+}
+
+#[test]
+fn globs_match_any_sequence_and_single_characters() {
+    assert!(util::matches_glob("1_Example.yml", "1_Example.yml"));
+    assert!(!util::matches_glob("1_Example.yml", "1_Example.yaml"));
+    assert!(util::matches_glob("*.yml", "1_Example.yml"));
+    assert!(util::matches_glob("*", ""));
+    assert!(util::matches_glob("1*_*.yml", "128_Keulenweihe.yml"));
+    assert!(util::matches_glob("?_*", "5_Toad-Poison.yml"));
+    assert!(!util::matches_glob("?_*", "53_Mond.yml"));
+    assert!(util::matches_glob("*a*b*", "xaxxbx"));
+    assert!(!util::matches_glob("*a*b", "xaxxbx"));
+}
+
+#[test]
+fn exclude_patterns_override_include_patterns() {
+    let filter = FileFilter {
+        include: patterns(&["1*"]),
+        exclude: patterns(&["*_Draft.yml"])
+    };
+
+    assert!(filter.matches("12_Example.yml"));
+    assert!(!filter.matches("2_Example.yml"));
+    assert!(!filter.matches("12_Draft.yml"));
+    assert!(FileFilter::default().matches("2_Draft.yml"));
+}
+
+#[test]
+fn files_not_matching_the_model_are_excluded_by_default() {
+    let fixtures = ["excluded_poison"];
+    let default = LoadOptions::default();
+    let unfiltered = LoadOptions {
+        filter: FileFilter::default(),
+        ..LoadOptions::default()
+    };
+
+    for file_name in ["25_Waldschrate.yml", "5_Toad-Poison.yml",
+            "53_Mond.yml", "128_Keulenweihe.yml", "129_Keulenwurf.yml"] {
+        assert!(!default.is_selected(Category::Poisons, file_name));
+        assert!(unfiltered.is_selected(Category::Poisons, file_name));
+    }
+
+    assert!(default.is_selected(Category::Poisons, "1_Example.yml"));
+    assert!(load(&fixtures, &default).get::<Poison>(5).is_none());
+    assert!(load(&fixtures, &unfiltered).get::<Poison>(5).is_some());
+}
+
+#[test]
+fn category_filters_only_apply_to_their_category() {
+    let mut options = LoadOptions::default();
+    options.category_filters.insert(Category::Spells, FileFilter {
+        include: Vec::new(),
+        exclude: patterns(&["1_*"])
+    });
+    let data = load(&[], &options);
+
+    assert!(!options.is_selected(Category::Spells, "1_Example.yml"));
+    assert!(options.is_selected(Category::Attributes, "1_Example.yml"));
+    assert!(data.get::<Spell>(1).is_none());
+    assert!(data.get::<Attribute>(1).is_some());
+}
+
+#[test]
+fn placeholder_threshold_selects_placeholders() {
+    let fixtures = ["work_in_progress_attribute"];
+    let lowered = LoadOptions {
+        placeholder_threshold: 50,
+        ..LoadOptions::default()
+    };
+    let included = LoadOptions {
+        load_placeholders: true,
+        ..lowered.clone()
+    };
+
+    assert!(lowered.is_placeholder("50_Work_In_Progress.yml"));
+    assert!(!lowered.is_placeholder("49_Example.yml"));
+    assert!(lowered.is_placeholder("Notes.yml"));
+    assert!(lowered.is_placeholder("Example_1.yml"));
+    assert!(load(&fixtures, &LoadOptions::default())
+        .get::<Attribute>(50).is_some());
+    assert!(load(&fixtures, &lowered).get::<Attribute>(50).is_none());
+    assert!(load(&fixtures, &included).get::<Attribute>(50).is_some());
+}
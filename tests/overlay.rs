@@ -0,0 +1,36 @@
+mod common;
+
+use optolith_rs::data::{Localization, Translatable};
+use optolith_rs::data::attribute::Attribute;
+use optolith_rs::data::overlay::OverlayConflict;
+use optolith_rs::id::{Category, Id};
+
+#[test]
+fn overlays_record_their_origins_and_conflicts() {
+    // The definitions pack redefines the attribute 1 and defines the
+    // attribute 2, which the patches pack renames.
+    let mut data = common::load();
+    let base_id = Id::new(Category::Attributes, 1);
+    let new_id = Id::new(Category::Attributes, 2);
+    let definitions = common::fixture("overlay_definitions");
+    let definitions = definitions.to_str().unwrap();
+    let patches = common::fixture("overlay_patches");
+    let patches = patches.to_str().unwrap();
+    let report = data.apply_overlays(&[definitions, patches]).unwrap();
+    let expected_conflicts = vec![
+        OverlayConflict::Overridden {
+            id: base_id,
+            pack: String::from(definitions)
+        }
+    ];
+    let name = data.get::<Attribute>(2).unwrap()
+        .translate("en-US").unwrap()
+        .name();
+
+    assert_eq!(expected_conflicts, report.conflicts());
+    assert_eq!(Some(definitions), data.pack(base_id));
+    assert_eq!(Some(definitions), data.pack(new_id));
+    assert!(data.patches(base_id).is_empty());
+    assert_eq!(&[String::from(patches)], data.patches(new_id));
+    assert_eq!("Courage", name);
+}
@@ -0,0 +1,26 @@
+#![cfg(feature = "parallel")]
+
+mod common;
+
+use optolith_rs::data::OptolithData;
+
+#[test]
+fn parallel_loader_loads_like_the_sequential_one() {
+    let sequential = common::load();
+    let parallel = OptolithData::from_directory_parallel(common::DATA_DIR)
+        .unwrap();
+
+    assert!(parallel.diff(&sequential).is_empty());
+}
+
+#[test]
+fn parallel_loader_reports_the_same_error() {
+    let dir = common::copy_data_dir_with(&["broken_files"]);
+    let path = dir.path().to_str().unwrap();
+    let sequential = OptolithData::from_directory(path).err().unwrap();
+    let parallel = OptolithData::from_directory_parallel(path).err().unwrap();
+
+    assert_eq!(sequential.to_string(), parallel.to_string());
+    assert_eq!(sequential.path(), parallel.path());
+    assert_eq!(sequential.location(), parallel.location());
+}
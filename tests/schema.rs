@@ -0,0 +1,63 @@
+#![cfg(feature = "schema")]
+
+mod common;
+
+use optolith_rs::data::OptolithData;
+use optolith_rs::data::schema;
+
+use serde_json::Value;
+
+use std::fs;
+use std::path::Path;
+
+fn read_yaml(file: &Path) -> Value {
+    let content = fs::read_to_string(file).unwrap();
+    serde_yaml::from_str(&content).unwrap()
+}
+
+#[test]
+fn test_data_matches_the_schemas() {
+    for directory in schema::schemas() {
+        let validator = jsonschema::validator_for(directory.schema.as_value())
+            .unwrap();
+        let dir = Path::new(common::DATA_DIR).join(directory.dir);
+        let files = fs::read_dir(&dir)
+            .unwrap_or_else(|e| panic!("{}: {}", dir.display(), e));
+
+        for file in files {
+            let file = file.unwrap().path();
+            let value = read_yaml(&file);
+            let errors = validator.iter_errors(&value)
+                .map(|e| format!("{} at {}", e, e.instance_path))
+                .collect::<Vec<_>>();
+
+            assert!(errors.is_empty(), "{}: {:#?}", file.display(), errors);
+        }
+    }
+
+    // the default options load strictly, so this fails on any invalid file
+    common::load();
+}
+
+#[test]
+fn files_rejected_by_the_loader_do_not_match_the_schemas() {
+    let directory = schema::schemas().into_iter()
+        .find(|directory| directory.dir == "EquipmentPackages")
+        .unwrap();
+    let validator = jsonschema::validator_for(directory.schema.as_value())
+        .unwrap();
+
+    for (fixture, path) in [
+        ("unknown_erratum_field", "translations.en-US.errata[0].author"),
+        ("non_string_erratum_date", "translations.en-US.errata[0].date")
+    ] {
+        let file = common::fixture(fixture)
+            .join("EquipmentPackages")
+            .join("2_Travel_Pack.yml");
+        let source = common::source_with(&[fixture]);
+        let error = OptolithData::from_source(&source, "").err().unwrap();
+
+        assert_eq!(Some(path), error.path());
+        assert!(!validator.is_valid(&read_yaml(&file)), "{}", fixture);
+    }
+}
@@ -0,0 +1,125 @@
+mod common;
+
+use optolith_rs::data::OptolithData;
+use optolith_rs::data::search::{SearchField, SearchIndex, SearchOptions};
+use optolith_rs::id::{Category, Id};
+
+fn index(options: &SearchOptions) -> SearchIndex {
+    common::load_with(&["search"]).search_index(options)
+}
+
+fn ids(index: &SearchIndex, locale: &str, query: &str) -> Vec<Id> {
+    index.search(locale, query).into_iter().map(|hit| hit.id).collect()
+}
+
+fn advantage(id: u32) -> Id {
+    Id::new(Category::Advantages, id)
+}
+
+#[test]
+fn professions_are_found_by_all_their_names() {
+    let data = common::load_with(&["profession_names"]);
+    let index = data.search_index(&SearchOptions::default());
+    let best = |query| index.search("en-US", query).remove(0);
+    let id = Id::new(Category::Professions, 2);
+    let warrior = best("Warrior");
+    let warrioress = best("Warrioress");
+    let subname = best("Punin");
+    let variant = best("Baburin");
+
+    assert_eq!((id, SearchField::Name), (warrior.id, warrior.field));
+    assert_eq!(warrior, warrioress);
+    assert_eq!((id, SearchField::AlternativeName), (subname.id, subname.field));
+    assert_eq!((id, SearchField::AlternativeName), (variant.id, variant.field));
+}
+
+#[test]
+fn umlauts_and_diacritics_are_normalized() {
+    let index = index(&SearchOptions::default());
+    let best = |query| {
+        let hit = index.search("de-DE", query).remove(0);
+        (hit.id, hit.score)
+    };
+
+    assert_eq!((advantage(2), 1.0), best("uebermut"));
+    assert_eq!((advantage(3), 1.0), best("BAERENKRAFT"));
+    assert_eq!(vec![advantage(2)], ids(&index, "de-DE", "Ubermut"));
+    assert_eq!(vec![advantage(4), advantage(5), advantage(6)],
+        ids(&index, "en-US", "Nimblé"));
+}
+
+#[test]
+fn small_typos_are_tolerated() {
+    let index = index(&SearchOptions::default());
+
+    assert_eq!(vec![advantage(2)], ids(&index, "en-US", "Cockyness"));
+    assert_eq!(vec![advantage(4), advantage(5)],
+        ids(&index, "en-US", "Nimbke"));
+    assert!(ids(&index, "en-US", "Nimbkes Fongers").is_empty());
+}
+
+#[test]
+fn texts_are_only_indexed_if_included() {
+    let without_texts = index(&SearchOptions::default());
+    let with_texts = index(&SearchOptions {
+        include_texts: true,
+        ..SearchOptions::default()
+    });
+    let hits = with_texts.search("en-US", "tags");
+
+    assert!(without_texts.search("en-US", "tags").is_empty());
+    assert!(!hits.is_empty());
+    assert!(hits.iter().all(|hit| hit.field == SearchField::Text));
+}
+
+#[test]
+fn alternative_names_of_poisons_and_scripts_are_indexed() {
+    let index = index(&SearchOptions::default());
+    let best = |query| index.search("en-US", query).remove(0);
+    let poison = Id::new(Category::Poisons, 2);
+    let script = Id::new(Category::Scripts, 2);
+
+    for (id, query) in [
+        (poison, "Widow's Kiss"),
+        (poison, "Spider Venom"),
+        (script, "Common Script")
+    ] {
+        let hit = best(query);
+
+        assert_eq!((id, SearchField::AlternativeName), (hit.id, hit.field));
+        assert_eq!(0.9, hit.score);
+    }
+}
+
+#[test]
+fn exact_matches_rank_before_prefixes_and_fuzzy_matches() {
+    let index = index(&SearchOptions::default());
+    let hits = index.search("en-US", "Nimble");
+    let scores = hits.iter().map(|hit| hit.score).collect::<Vec<_>>();
+
+    assert_eq!(vec![advantage(4), advantage(5), advantage(6)],
+        hits.iter().map(|hit| hit.id).collect::<Vec<_>>());
+    assert_eq!(&[1.0, 0.9], &scores[..2]);
+    assert!(scores[2] > 0.0 && scores[2] < 0.9);
+    assert!(hits.iter().all(|hit| hit.field == SearchField::Name));
+    assert_eq!(1, index.search_limited("en-US", "Nimble", 1).len());
+}
+
+#[test]
+fn locales_are_taken_from_translations() {
+    let mut source = common::source_with(&["search"]);
+    source.remove("UI/de-DE.yml");
+    source.remove("UI/en-US.yml");
+    let data = OptolithData::from_source(&source, "").unwrap();
+    let all = data.search_index(&SearchOptions::default());
+    let english = data.search_index(&SearchOptions {
+        locales: Some(vec![String::from("en-US")].into_iter().collect()),
+        ..SearchOptions::default()
+    });
+
+    assert_eq!(0, data.locales().count());
+    assert!(all.contains_locale("de-DE"));
+    assert_eq!(vec![advantage(2)], ids(&all, "de-DE", "Übermut"));
+    assert!(english.contains_locale("en-US"));
+    assert!(!english.contains_locale("de-DE"));
+}
@@ -0,0 +1,80 @@
+mod common;
+
+use optolith_rs::compatibility::Compatibility;
+use optolith_rs::data::OptolithData;
+use optolith_rs::source::MemorySource;
+
+use std::path::Path;
+
+fn assert_loads_like_the_directory(source: &MemorySource, path: &str) {
+    let expected = common::load();
+    let data = OptolithData::from_source(source, path).unwrap();
+
+    assert!(expected.diff(&data).is_empty());
+}
+
+#[test]
+fn memory_source_loads_like_the_directory() {
+    let source = common::read_source(Path::new(common::DATA_DIR));
+
+    assert_loads_like_the_directory(&source, "");
+}
+
+#[test]
+fn memory_source_loads_compatibility_like_the_directory() {
+    let source = common::read_source(Path::new(common::COMPATIBILITY_DIR));
+    let from_directory =
+        Compatibility::from_directory(common::COMPATIBILITY_DIR).unwrap();
+    let from_source = Compatibility::from_source(&source, "").unwrap();
+
+    common::assert_maps_the_compatibility_dir(&from_directory);
+    common::assert_maps_the_compatibility_dir(&from_source);
+}
+
+#[cfg(feature = "zip")]
+#[test]
+fn zip_archive_loads_like_the_directory() {
+    use std::fs;
+    use std::io::{Cursor, Write};
+
+    use zip::ZipWriter;
+    use zip::write::FileOptions;
+
+    fn add_dir(zip: &mut ZipWriter<Cursor<Vec<u8>>>, dir: &Path, path: &str) {
+        zip.add_directory(path, FileOptions::default()).unwrap();
+
+        for entry in fs::read_dir(dir).unwrap() {
+            let entry = entry.unwrap();
+            let path = format!("{}/{}", path,
+                entry.file_name().to_string_lossy());
+
+            if entry.file_type().unwrap().is_dir() {
+                add_dir(zip, &entry.path(), &path);
+            }
+            else {
+                zip.start_file(path, FileOptions::default()).unwrap();
+                zip.write_all(&fs::read(entry.path()).unwrap()).unwrap();
+            }
+        }
+    }
+
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    add_dir(&mut zip, Path::new(common::DATA_DIR), "data");
+    let archive = zip.finish().unwrap();
+    let source = MemorySource::from_zip(archive).unwrap();
+
+    assert_loads_like_the_directory(&source, "data");
+    assert_loads_like_the_directory(&source.subdirectory("data"), "");
+}
+
+#[cfg(feature = "tar")]
+#[test]
+fn tar_archive_loads_like_the_directory() {
+    let mut tar = tar::Builder::new(Vec::new());
+    tar.append_dir_all("data", common::DATA_DIR).unwrap();
+    let archive = tar.into_inner().unwrap();
+    let source = MemorySource::from_tar(&archive[..]).unwrap();
+
+    assert_loads_like_the_directory(&source, "data");
+    assert_loads_like_the_directory(&source.subdirectory("data"), "");
+}
@@ -0,0 +1,91 @@
+mod common;
+
+use optolith_rs::data::OptolithData;
+use optolith_rs::data::locale::LocaleChain;
+use optolith_rs::data::ui::{Message, MessageArgs};
+
+fn load() -> OptolithData {
+    common::load_with(&["messages"])
+}
+
+fn format(data: &OptolithData, locale: &str, key: &str, args: &MessageArgs)
+        -> Option<String> {
+    data.messages(LocaleChain::new(vec![locale]))
+        .format(key, args)
+        .map(|message| message.text)
+}
+
+fn count(data: &OptolithData, locale: &str, count: i64) -> String {
+    format(data, locale, "items", &MessageArgs::new().count(count)).unwrap()
+}
+
+#[test]
+fn nested_keys_refer_to_strings_in_maps() {
+    let data = load();
+    let messages = data.messages(LocaleChain::new(vec!["en-US"]));
+    let text = |key| messages.get(key).map(|m| m.text);
+
+    assert_eq!(Some("Optolith"), text("header.title").as_deref());
+    assert_eq!(Some("Nested title"), text("nested.title").as_deref());
+    assert_eq!(Some("One item"), text("items.one").as_deref());
+    assert_eq!(Some("{count} items"), text("items").as_deref());
+
+    // a map with other keys than plural categories has no plural forms
+    assert_eq!(None, text("nested"));
+    assert_eq!(None, text("nested.missing"));
+}
+
+#[test]
+fn arguments_replace_their_placeholders() {
+    let data = load();
+    let args = MessageArgs::new().arg(3).arg(5).named("name", "Ann");
+    let message = data.messages(LocaleChain::new(vec!["de-AT", "en-US"]))
+        .format("position", &args);
+    let expected = Message {
+        text: String::from("3 of 5"),
+        locale: String::from("en-US"),
+        is_fallback: true
+    };
+
+    assert_eq!(Some(expected), message);
+    assert_eq!(Some("Hallo, Ann!"),
+        format(&data, "de-AT", "greeting", &args).as_deref());
+    assert_eq!(Some("3 of {1}"), format(&data, "en-US", "position",
+        &MessageArgs::new().arg(3)).as_deref());
+}
+
+#[test]
+fn escaped_braces_are_kept_literally() {
+    let data = load();
+    let args = MessageArgs::new().named("name", "Ann");
+
+    assert_eq!(Some("{name} is replaced by Ann, a } is kept"),
+        format(&data, "en-US", "escaped", &args).as_deref());
+}
+
+#[test]
+fn plural_forms_follow_the_rules_of_the_language() {
+    let data = load();
+
+    assert_eq!("No items", count(&data, "en-US", 0));
+    assert_eq!("One item", count(&data, "en-US", 1));
+    assert_eq!("2 items", count(&data, "en-US", 2));
+    assert_eq!("0 Gegenstände", count(&data, "de-DE", 0));
+    assert_eq!("Ein Gegenstand", count(&data, "de-DE", 1));
+    assert_eq!("2 Gegenstände", count(&data, "de-DE", 2));
+    assert_eq!("0 objet", count(&data, "fr-FR", 0));
+    assert_eq!("1 objet", count(&data, "fr-FR", 1));
+    assert_eq!("2 objets", count(&data, "fr-FR", 2));
+}
+
+#[test]
+fn missing_ui_keys_are_listed_per_locale() {
+    let data = common::load_with(&["ui_keys"]);
+    let missing = data.missing_ui_keys();
+
+    assert_eq!(vec![
+        (String::from("de-DE"),
+            vec![String::from("greeting"), String::from("nested.title")])
+    ], missing.into_iter().collect::<Vec<_>>());
+    assert!(common::load().missing_ui_keys().is_empty());
+}
@@ -0,0 +1,138 @@
+mod common;
+
+use optolith_rs::data::OptolithData;
+use optolith_rs::data::validation::{
+    DanglingReference,
+    Requirement,
+    RequirementKind
+};
+use optolith_rs::id::{Category, Id};
+
+use serde_yaml::Value;
+
+use std::fs;
+use std::path::Path;
+
+/// Gets the value at the given path, such as `prerequisites[2].id`.
+fn get<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.').try_fold(value, |value, segment| {
+        let mut parts = segment.split('[');
+        let value = value.get(parts.next()?)?;
+
+        parts.try_fold(value, |value, index| {
+            value.get(index.trim_end_matches(']').parse::<usize>().ok()?)
+        })
+    })
+}
+
+/// Validates the test data together with the entities of the
+/// `cross_references` fixture, some of which are replaced by the given
+/// fixture.
+fn dangling(fixture: &str) -> Vec<DanglingReference> {
+    common::load_with(&["cross_references", fixture]).validate()
+}
+
+#[test]
+fn references_to_other_ids_and_categories_are_resolved() {
+    let mut data = common::load_with(&["cross_references"]);
+    let skill = Id::new(Category::Skills, 2);
+    let cantrip = Id::new(Category::Cantrips, 2);
+    let culture = Id::new(Category::Cultures, 2);
+    let profession = Id::new(Category::Professions, 2);
+
+    assert!(data.validate().is_empty());
+    assert!(data.dependent_index()
+        .dependents(Id::new(Category::Advantages, 2)).iter()
+        .any(|d| d.id == cantrip));
+
+    data.remove(skill);
+
+    let reference = |source, path: &str| DanglingReference {
+        source,
+        path: path.to_owned(),
+        target: skill
+    };
+
+    assert_eq!(vec![
+        reference(cantrip, "prerequisites.value[0].value.options[0].value"),
+        reference(culture, "commonSkills[0]"),
+        reference(culture, "culturalPackageSkills[0].id"),
+        reference(profession, "instances[0].skills[0].id")
+    ], data.validate());
+}
+
+#[test]
+fn select_options_of_prerequisites_are_dependents() {
+    let data = common::load_with(&["cross_references"]);
+    let dependents =
+        data.dependent_index().dependents(Id::new(Category::Skills, 2));
+
+    assert_eq!(1, dependents.len());
+    assert_eq!(Id::new(Category::Cantrips, 2), dependents[0].id);
+    assert_eq!("prerequisites.value[0].value.options[0].value",
+        dependents[0].path);
+    assert_eq!(Requirement {
+        kind: RequirementKind::SelectOption,
+        from_level: None
+    }, dependents[0].requirement);
+}
+
+#[test]
+fn dangling_select_option_is_found() {
+    assert_eq!(vec![DanglingReference {
+        source: Id::new(Category::Cantrips, 2),
+        path: "prerequisites.value[0].value.options[0].value".to_owned(),
+        target: Id::new(Category::Skills, 3)
+    }], dangling("dangling_select_option"));
+}
+
+#[test]
+fn dangling_reference_of_profession_is_found() {
+    assert_eq!(vec![DanglingReference {
+        source: Id::new(Category::Professions, 2),
+        path: "instances[0].skills[0].id".to_owned(),
+        target: Id::new(Category::Skills, 3)
+    }], dangling("dangling_profession_reference"));
+}
+
+#[test]
+fn dangling_professions_exception_is_found() {
+    assert_eq!(vec![DanglingReference {
+        source: Id::new(Category::Cultures, 2),
+        path: "commonMundaneProfessionsExceptions[0].value".to_owned(),
+        target: Id::new(Category::Professions, 3)
+    }], dangling("dangling_professions_exception"));
+}
+
+#[test]
+fn dangling_reference_paths_use_file_keys() {
+    // All entities are moved to the ID 2, so all references to ID 1 dangle.
+    let mut source = common::read_source(Path::new(common::DATA_DIR));
+    let mut files = Vec::new();
+
+    for entry in fs::read_dir(common::DATA_DIR).unwrap() {
+        let dir = entry.unwrap().file_name().to_string_lossy().into_owned();
+        let file = format!("{}/1_Example.yml", dir);
+
+        if let Some(content) = source.remove(&file) {
+            let mut value = serde_yaml::from_slice::<Value>(&content).unwrap();
+            value["id"] = Value::from(2);
+            source.insert(&format!("{}/2_Example.yml", dir),
+                serde_yaml::to_string(&value).unwrap());
+            files.push((dir, value));
+        }
+    }
+
+    let data = OptolithData::from_source(&source, "").unwrap();
+    let dangling = data.validate();
+
+    assert!(!dangling.is_empty());
+
+    for reference in dangling {
+        let dir = format!("{:?}", reference.source.category());
+        let (_, value) = files.iter().find(|(d, _)| *d == dir).unwrap();
+
+        assert_eq!(get(value, &reference.path), Some(&Value::from(1)),
+            "{:?} {}", reference.source, reference.path);
+    }
+}
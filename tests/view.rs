@@ -0,0 +1,70 @@
+mod common;
+
+use optolith_rs::character::{HigherParadeValues, Rules};
+use optolith_rs::data::OptolithData;
+use optolith_rs::data::service::Service;
+use optolith_rs::data::view::DataView;
+
+/// Loads the test data with an expansion whose second printing adds the
+/// service 2 and removes the service 3, and an adult content publication
+/// with the service 4. The service 1 is from the core rules.
+fn load() -> OptolithData {
+    common::load_with(&["publications"])
+}
+
+fn services(view: &DataView<'_>) -> Vec<u32> {
+    view.iter::<Service>().map(|s| s.id).collect()
+}
+
+fn rules(enable_all: bool, enabled: &[&str]) -> Rules {
+    Rules {
+        higher_parade_values: HigherParadeValues::Inactive,
+        attribute_value_limit: false,
+        enable_all_rule_books: Some(enable_all),
+        enabled_rule_books:
+            Some(enabled.iter().map(|&id| String::from(id)).collect()),
+        enable_language_specializations: false
+    }
+}
+
+#[test]
+fn rules_enable_rule_books_by_their_publisher_ids() {
+    let data = load();
+    let view = |enable_all, enabled| {
+        services(&DataView::from_rules(&data, &rules(enable_all, enabled)))
+    };
+
+    assert_eq!(vec![1], view(false, &[]));
+    assert_eq!(vec![1, 2, 3], view(false, &["EXP"]));
+    assert_eq!(vec![1, 4], view(false, &["ADULT"]));
+    assert_eq!(vec![1, 2, 3, 4], view(true, &[]));
+}
+
+#[test]
+fn printings_limit_the_entries_of_their_publication() {
+    let data = load();
+    let first = DataView::new(&data, vec![1]).with_printing(21);
+    let second = DataView::all(&data).with_printing(22);
+    let unknown = DataView::new(&data, vec![1]).with_printing(23);
+
+    assert_eq!(vec![1, 3], services(&first));
+    assert_eq!(vec![1, 2, 4], services(&second));
+    assert_eq!(vec![1], services(&unknown));
+}
+
+#[test]
+fn presets_keep_core_rules_and_non_adult_content() {
+    let data = load();
+    let core_rules = DataView::core_rules(&data);
+    let without_adult_content = DataView::all(&data).without_adult_content();
+    let core_rules_printing = DataView::core_rules(&data).with_printing(22);
+    let adult_rules = rules(false, &["ADULT"]);
+    let rules_without_adult_content =
+        DataView::from_rules(&data, &adult_rules).without_adult_content();
+
+    assert_eq!(vec![1], services(&core_rules));
+    assert_eq!(vec![1, 2, 3], services(&without_adult_content));
+    assert_eq!(vec![1, 2], services(&core_rules_printing));
+    assert_eq!(vec![1], services(&rules_without_adult_content));
+    assert!(!without_adult_content.is_publication_enabled(3));
+}
@@ -0,0 +1,119 @@
+#![cfg(feature = "watch")]
+
+mod common;
+
+use optolith_rs::data::{DataChange, Localization, OptolithData, Translatable};
+use optolith_rs::data::attribute::Attribute;
+use optolith_rs::error::OptolithDataResult;
+use optolith_rs::id::{Category, Id};
+use optolith_rs::watch::DataWatcher;
+
+use std::fs;
+use std::time::{Duration, Instant};
+
+fn name(data: &OptolithData) -> &str {
+    data.get::<Attribute>(1).unwrap().translate("en-US").unwrap().name()
+}
+
+/// Applies the changes detected by the watcher to the data until the given
+/// condition holds or ten seconds have passed, and returns all changes.
+fn apply_until(watcher: &mut DataWatcher, data: &mut OptolithData,
+        condition: impl Fn(&OptolithData) -> bool)
+        -> Vec<OptolithDataResult<DataChange>> {
+    // A change may be reported by several events, of which the first ones
+    // can see an incomplete file.
+    let deadline = Instant::now() + Duration::from_secs(10);
+    let mut changes = Vec::new();
+
+    while !condition(data) && Instant::now() < deadline {
+        watcher.wait(Duration::from_millis(100));
+        changes.extend(watcher.apply_pending(data));
+    }
+
+    changes
+}
+
+fn contains(changes: &[OptolithDataResult<DataChange>], change: DataChange)
+        -> bool {
+    changes.iter().any(|c| matches!(c, Ok(c) if *c == change))
+}
+
+#[test]
+fn edited_file_is_reloaded() {
+    let dir = common::copy_data_dir();
+    let path = dir.path().to_str().unwrap();
+    let pack = common::fixture("watch_pack");
+    let pack_path = pack.to_str().unwrap();
+    let mut data = OptolithData::from_directory(path).unwrap();
+    data.apply_overlay(pack_path).unwrap();
+    let mut watcher = DataWatcher::new(path).unwrap();
+    let id = Id::new(Category::Attributes, 1);
+
+    assert_eq!(Some(pack_path), data.pack(id));
+
+    common::copy_dir(&common::fixture("watch_edit"), dir.path());
+
+    let changes = apply_until(&mut watcher, &mut data,
+        |data| name(data) == "Courage");
+
+    assert_eq!("Courage", name(&data));
+    assert_eq!(None, data.pack(id));
+    assert!(contains(&changes, DataChange::Updated(id)));
+}
+
+#[test]
+fn created_file_is_loaded() {
+    let dir = common::copy_data_dir();
+    let path = dir.path().to_str().unwrap();
+    let mut data = OptolithData::from_directory(path).unwrap();
+    let mut watcher = DataWatcher::new(path).unwrap();
+
+    common::copy_dir(&common::fixture("german_only_attribute"), dir.path());
+
+    let changes = apply_until(&mut watcher, &mut data,
+        |data| data.get::<Attribute>(2).is_some());
+
+    assert!(data.get::<Attribute>(2).is_some());
+    assert!(contains(&changes,
+        DataChange::Created(Id::new(Category::Attributes, 2))));
+}
+
+#[test]
+fn deleted_entity_file_is_removed() {
+    let dir = common::copy_data_dir();
+    let path = dir.path().to_str().unwrap();
+    let mut data = OptolithData::from_directory(path).unwrap();
+    let mut watcher = DataWatcher::new(path).unwrap();
+    let id = Id::new(Category::Attributes, 1);
+
+    fs::remove_file(dir.path().join("Attributes").join("1_Example.yml"))
+        .unwrap();
+
+    let changes = apply_until(&mut watcher, &mut data,
+        |data| data.get::<Attribute>(1).is_none());
+
+    assert!(data.get::<Attribute>(1).is_none());
+    assert!(data.get_as_entity(id).is_none());
+    assert!(contains(&changes, DataChange::Deleted(id)));
+}
+
+#[test]
+fn deleted_ui_file_is_removed() {
+    let dir = common::copy_data_dir();
+    let path = dir.path().to_str().unwrap();
+    let mut data = OptolithData::from_directory(path).unwrap();
+    let mut watcher = DataWatcher::new(path).unwrap();
+    let has_german = |data: &OptolithData| data.locales().any(|l| l == "de-DE");
+
+    assert!(has_german(&data));
+
+    fs::remove_file(dir.path().join("UI").join("de-DE.yml")).unwrap();
+
+    let changes = apply_until(&mut watcher, &mut data,
+        |data| !has_german(data));
+
+    assert!(!has_german(&data));
+    assert!(data.get_ui_string("de-DE", "header.title").is_none());
+    assert!(data.get_ui_string("en-US", "header.title").is_some());
+    assert!(contains(&changes, DataChange::UIDeleted(String::from("de-DE"))));
+}
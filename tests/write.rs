@@ -0,0 +1,33 @@
+mod common;
+
+use optolith_rs::data::OptolithData;
+use optolith_rs::data::attribute::Attribute;
+use optolith_rs::data::options::LoadOptions;
+
+fn load(path: &str, options: &LoadOptions) -> OptolithData {
+    OptolithData::from_directory_with_options(path, options).unwrap()
+        .into_data()
+}
+
+#[test]
+fn written_directories_load_again() {
+    // The fixture contains an attribute without a usable name and a
+    // placeholder.
+    let options = LoadOptions {
+        load_placeholders: true,
+        ..LoadOptions::default()
+    };
+    let source = common::source_with(&["write_attributes"]);
+    let data = OptolithData::from_source_with_options(&source, "", &options)
+        .unwrap()
+        .into_data();
+    let written = tempfile::tempdir().unwrap();
+    let written = written.path().to_str().unwrap();
+    data.write_directory(written).unwrap();
+    let reloaded = load(written, &options);
+    let without_placeholders = load(written, &LoadOptions::default());
+
+    assert!(data.diff(&reloaded).is_empty());
+    assert!(without_placeholders.get::<Attribute>(2).is_some());
+    assert!(without_placeholders.get::<Attribute>(1000).is_none());
+}